@@ -1,6 +1,15 @@
-use anyhow::{Context, Result};
+use crate::csv::read_rows;
+use anyhow::{anyhow, bail, ensure, Context, Result};
 use clap::Parser;
-use livestock_rs::calculators::growth::adg::calculate_adg;
+use livestock_rs::calculators::growth::adg::{
+    calculate_adg, calculate_adg_from_weigh_ins, calculate_series_adg, calculate_weight_change,
+    WeighIn, WeightTrend, OUTLIER_THRESHOLD,
+};
+use livestock_rs::types::Date;
+use livestock_rs::units::{Duration, Mass, MassRate, UnitSystem};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -20,8 +29,8 @@ use livestock_rs::calculators::growth::adg::calculate_adg;
         where:
 
         - `ADG` is the average daily gain in the same unit as the weight.
-        - `final_weight` is the ending weight of the animal (in kg or lbs).
-        - `initial_weight` is the starting weight of the animal (in kg or lbs).
+        - `final_weight` is the ending weight of the animal (in kg, or lb with `--units imperial`).
+        - `initial_weight` is the starting weight of the animal (in kg, or lb with `--units imperial`).
         - `days` is the number of days in the measurement period.
 
         # Example
@@ -32,28 +41,260 @@ use livestock_rs::calculators::growth::adg::calculate_adg;
         stocktools adg -i 100 -f 150 -d 50
         ```
 
-        The result will be `1.0`, which means the animal gained 1 kg per day.        
+        The result will be `1.00 kg/day`, which means the animal gained 1 kg per day.
+
+        Instead of counting days, the dates the animal was weighed can be given:
+
+        ```
+        stocktools adg -i 300 -f 406 --start-date 2026-03-01 --end-date 2026-06-15
+        ```
+
+        Two-point ADG is noisy because of gut fill. To fit a least-squares line through every
+        weigh-in instead, give a CSV file of `date,weight` rows:
+
+        ```
+        stocktools adg --series weights.csv
+        ```
+
+        Weights are read and printed in pounds with `--units imperial`.
+
+        By default a final weight at or below the initial weight is an error. To track animals
+        that are losing weight, e.g. in a sick pen or through drought, use `--allow-loss` to
+        report the signed daily change with a warning instead:
+
+        ```
+        stocktools adg -i 450 -f 420 -d 60 --allow-loss
+        ```
+
+        Changes within `--maintenance-tolerance` of zero are reported as maintenance.
     "
 )]
 pub struct AdgSubcommand {
-    #[arg(help = "Initial weight of livestock (in kg or lbs)", long, short = 'i')]
-    initial_weight: f64,
-    #[arg(help = "Final weight of livestock (in kg or lbs)", long, short = 'f')]
-    final_weight: f64,
+    #[arg(
+        help = "Initial weight of livestock (in kg, or lb with --units imperial)",
+        long,
+        short = 'i',
+        required_unless_present = "series"
+    )]
+    initial_weight: Option<f64>,
+    #[arg(
+        help = "Final weight of livestock (in kg, or lb with --units imperial)",
+        long,
+        short = 'f',
+        required_unless_present = "series"
+    )]
+    final_weight: Option<f64>,
     #[arg(
         long,
         help = "The number of days in the measurement period.",
-        short = 'd'
+        short = 'd',
+        conflicts_with_all = ["start_date", "end_date"]
+    )]
+    days: Option<usize>,
+    #[arg(
+        help = "Date of the initial weigh-in (YYYY-MM-DD)",
+        long,
+        requires = "end_date"
+    )]
+    start_date: Option<Date>,
+    #[arg(
+        help = "Date of the final weigh-in (YYYY-MM-DD)",
+        long,
+        requires = "start_date"
+    )]
+    end_date: Option<Date>,
+    #[arg(
+        help = "CSV file of date,weight weigh-ins to fit a regression through",
+        long,
+        conflicts_with_all = ["initial_weight", "final_weight", "days", "start_date", "end_date"]
     )]
-    days: usize,
+    series: Option<PathBuf>,
+    #[arg(
+        help = "Confidence level of the ADG interval for --series",
+        long,
+        default_value_t = 0.95
+    )]
+    confidence: f64,
+    #[arg(
+        help = "Report weight loss as a negative daily change with a warning instead of failing",
+        long,
+        conflicts_with = "series"
+    )]
+    allow_loss: bool,
+    #[arg(
+        help = "Largest daily change treated as maintenance with --allow-loss (in kg, or lb with --units imperial)",
+        long,
+        default_value_t = 0.05,
+        requires = "allow_loss"
+    )]
+    maintenance_tolerance: f64,
 }
 
 impl AdgSubcommand {
-    pub fn run(&self) -> Result<()> {
-        let adg = calculate_adg(self.initial_weight, self.final_weight, self.days)
-            .context("Failed to calculate ADG.")?;
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
+        if let Some(path) = &self.series {
+            return self.run_series(path, units);
+        }
+
+        let initial_weight = units.mass(self.initial_weight.unwrap_or_default());
+        let final_weight = units.mass(self.final_weight.unwrap_or_default());
+        let period = match (self.days, self.start_date, self.end_date) {
+            (Some(days), _, _) => Duration::days(days as f64),
+            (None, Some(start_date), Some(end_date)) => {
+                ensure!(start_date < end_date, "Start date must be before end date.");
+                end_date - start_date
+            }
+            _ => {
+                return Err(anyhow!(
+                    "Either days or a start and end date must be provided."
+                ))
+            }
+        };
+
+        if self.allow_loss {
+            return self.run_weight_change(initial_weight, final_weight, period, units);
+        }
+        ensure!(
+            final_weight > initial_weight,
+            "Final weight must be greater than initial weight. Use --allow-loss to record weight loss."
+        );
+
+        let adg = match (self.start_date, self.end_date) {
+            (Some(start_date), Some(end_date)) => calculate_adg_from_weigh_ins(&[
+                WeighIn::new(start_date, initial_weight),
+                WeighIn::new(end_date, final_weight),
+            ]),
+            _ => calculate_adg(initial_weight, final_weight, period),
+        }
+        .context("Failed to calculate ADG.")?;
+
+        let unit = units.mass_unit();
+        println!(
+            "Average Daily Gain (ADG): {:.2} {}/day",
+            adg.in_unit(unit),
+            unit.symbol()
+        );
+        Ok(())
+    }
+
+    fn run_weight_change(
+        &self,
+        initial_weight: Mass,
+        final_weight: Mass,
+        period: Duration,
+        units: UnitSystem,
+    ) -> Result<()> {
+        let unit = units.mass_unit();
+        let tolerance = MassRate::per_day(self.maintenance_tolerance, unit);
+        let change = calculate_weight_change(initial_weight, final_weight, period, tolerance)
+            .context("Failed to calculate daily weight change.")?;
+
+        let trend = match change.trend {
+            WeightTrend::Gain => "gain",
+            WeightTrend::Maintenance => "maintenance",
+            WeightTrend::Loss => "loss",
+        };
+        println!(
+            "Daily Weight Change: {:+.2} {}/day ({})",
+            change.daily_change.in_unit(unit),
+            unit.symbol(),
+            trend
+        );
+        if change.trend == WeightTrend::Loss {
+            eprintln!(
+                "Warning: lost {:.1} {} over {:.0} days.",
+                (initial_weight - final_weight).in_unit(unit),
+                unit.symbol(),
+                period.as_days()
+            );
+        }
+        Ok(())
+    }
+
+    fn run_series(&self, path: &Path, units: UnitSystem) -> Result<()> {
+        let weigh_ins = read_weigh_ins(path, units)?;
+        let series = calculate_series_adg(&weigh_ins, self.confidence)
+            .context("Failed to calculate ADG over the weigh-in series.")?;
+
+        let unit = units.mass_unit();
+        let (low, high) = series.confidence_interval;
+        println!(" ");
+        println!("Weigh-ins: {}", series.residuals.len());
+        println!(
+            "Average Daily Gain (ADG): {:.2} {}/day",
+            series.adg.in_unit(unit),
+            unit.symbol()
+        );
+        println!(
+            "{:.0}% Confidence Interval: {:.2} to {:.2} {}/day",
+            series.confidence * 100.0,
+            low.in_unit(unit),
+            high.in_unit(unit),
+            unit.symbol()
+        );
+        println!(
+            "Standard Error: {:.3} {}/day",
+            series.standard_error.in_unit(unit),
+            unit.symbol()
+        );
+        println!("R²: {:.3}", series.r_squared);
+        println!(
+            "Fitted weight on {}: {:.1} {}",
+            series.start_date,
+            series.intercept.in_unit(unit),
+            unit.symbol()
+        );
+
+        let outliers: Vec<_> = series
+            .residuals
+            .iter()
+            .filter(|residual| residual.outlier)
+            .collect();
+        if !outliers.is_empty() {
+            println!(" ");
+            println!(
+                "Outliers (more than {} standard deviations from the fitted line):",
+                OUTLIER_THRESHOLD
+            );
+            for outlier in outliers {
+                println!(
+                    "  {}: {:.1} {} ({:+.1} {})",
+                    outlier.weigh_in.date,
+                    outlier.weigh_in.weight.in_unit(unit),
+                    unit.symbol(),
+                    outlier.residual.in_unit(unit),
+                    unit.symbol()
+                );
+            }
+        }
+        println!(" ");
 
-        println!("Average Daily Gain (ADG): {:.2}", adg);
         Ok(())
     }
 }
+
+/// Reads `date,weight` weigh-ins from a CSV file, skipping blank lines, `#` comments and a
+/// header row such as `date,weight`.
+pub fn read_weigh_ins(path: &Path, units: UnitSystem) -> Result<Vec<WeighIn>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read weigh-ins from {}.", path.display()))?;
+
+    let mut weigh_ins = Vec::new();
+    for row in read_rows(&contents, |fields| Date::from_str(fields[0]).is_err()) {
+        let [date, weight] = row.fields[..] else {
+            bail!(
+                "Line {} must be a date and a weight separated by a comma.",
+                row.line
+            );
+        };
+        let date =
+            Date::from_str(date).with_context(|| format!("Invalid date on line {}.", row.line))?;
+        let weight: f64 = weight
+            .parse()
+            .with_context(|| format!("Invalid weight on line {}: \"{}\"", row.line, weight))?;
+
+        weigh_ins.push(WeighIn::new(date, units.mass(weight)));
+    }
+
+    Ok(weigh_ins)
+}
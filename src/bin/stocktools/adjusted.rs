@@ -0,0 +1,327 @@
+use crate::csv::read_rows;
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use livestock_rs::calculators::growth::adjusted::{
+    calculate_adjusted_205_day_weight, calculate_adjusted_365_day_weight,
+    calculate_contemporary_ratios, CalfSex,
+};
+use livestock_rs::units::{Duration, Mass, UnitSystem};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Args, Debug)]
+struct WeaningArgs {
+    #[arg(
+        help = "Weight of the calf at weaning (in kg, or lb with --units imperial)",
+        long,
+        short = 'w'
+    )]
+    weaning_weight: f64,
+    #[arg(
+        help = "Age of the calf at weaning, in days (160 to 250)",
+        long,
+        short = 'a'
+    )]
+    weaning_age: f64,
+    #[arg(
+        help = "Birth weight of the calf (in kg, or lb with --units imperial). Defaults to a standard 70 lb",
+        long,
+        short = 'b'
+    )]
+    birth_weight: Option<f64>,
+    #[arg(help = "Age of the dam in years when the calf was born", long)]
+    dam_age: u32,
+    #[arg(
+        help = "Sex of the calf",
+        long,
+        short = 's',
+        value_enum,
+        ignore_case = true
+    )]
+    sex: CalfSex,
+}
+
+impl WeaningArgs {
+    fn adjusted_205_day_weight(&self, units: UnitSystem) -> Result<Mass> {
+        calculate_adjusted_205_day_weight(
+            units.mass(self.weaning_weight),
+            Duration::days(self.weaning_age),
+            self.birth_weight.map(|weight| units.mass(weight)),
+            self.dam_age,
+            self.sex,
+        )
+        .context("Failed to calculate adjusted 205-day weight.")
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum AdjustedWeightCommands {
+    #[command(about = "Calculate a calf's adjusted 205-day weaning weight.")]
+    Weaning(WeaningArgs),
+    #[command(about = "Calculate a calf's adjusted 205-day and 365-day weights.")]
+    Yearling {
+        #[command(flatten)]
+        weaning: WeaningArgs,
+        #[arg(
+            help = "Weight of the animal as a yearling (in kg, or lb with --units imperial)",
+            long,
+            short = 'y'
+        )]
+        yearling_weight: f64,
+        #[arg(
+            help = "Age of the animal when weighed as a yearling, in days (320 to 410)",
+            long
+        )]
+        yearling_age: f64,
+    },
+    #[command(about = "Calculate adjusted weights and ratios for a contemporary group.")]
+    Group {
+        #[arg(
+            help = "CSV file of id,sex,dam_age,birth_weight,weaning_age,weaning_weight[,yearling_age,yearling_weight] rows"
+        )]
+        path: PathBuf,
+    },
+}
+
+#[derive(Parser, Debug)]
+#[command(
+    arg_required_else_help(true),
+    about = "Calculate BIF adjusted 205-day weaning and 365-day yearling weights for beef calves.",
+    long_about = "
+        Calculate BIF adjusted 205-day weaning and 365-day yearling weights for beef calves.
+
+        Adjusted weights, from the Beef Improvement Federation (BIF) guidelines, standardize weights
+        to a common age and to a mature dam so calves can be compared fairly.
+
+        The formulas are:
+
+        adjusted_205 = (weaning_weight - birth_weight) / weaning_age * 205 + birth_weight + dam_adjustment
+        adjusted_365 = (yearling_weight - weaning_weight) / (yearling_age - weaning_age) * 160 + adjusted_205
+
+        where `dam_adjustment` depends on the age of the dam and the sex of the calf:
+
+        - 2-year-old dams: 60 lb for bulls and steers, 54 lb for heifers.
+        - 3-year-old dams: 40 lb for bulls and steers, 36 lb for heifers.
+        - 4-year-old dams: 20 lb for bulls and steers, 18 lb for heifers.
+        - 5 to 10-year-old dams: none.
+        - 11-year-old and older dams: 20 lb for bulls and steers, 18 lb for heifers.
+
+        # Examples
+
+        Adjust the weaning weight of a 550 lb bull calf weaned at 190 days out of a 3-year-old cow:
+
+        ```
+        stocktools --units imperial adjusted-weight weaning -w 550 -a 190 -b 80 --dam-age 3 -s bull
+        ```
+
+        Adjust the same calf's yearling weight of 1000 lb at 370 days:
+
+        ```
+        stocktools --units imperial adjusted-weight yearling -w 550 -a 190 -b 80 --dam-age 3 -s bull -y 1000 --yearling-age 370
+        ```
+
+        Adjust a whole contemporary group from a CSV file, with ratios calculated within each sex:
+
+        ```
+        stocktools adjusted-weight group calves.csv
+        ```
+
+        Leave the birth weight, or the yearling age and weight, of a row blank if they are unknown.
+    "
+)]
+pub struct AdjustedWeightSubcommand {
+    #[command(subcommand)]
+    command: AdjustedWeightCommands,
+}
+
+impl AdjustedWeightSubcommand {
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
+        let unit = units.mass_unit();
+
+        match &self.command {
+            AdjustedWeightCommands::Weaning(weaning) => {
+                let adjusted = weaning.adjusted_205_day_weight(units)?;
+                println!(
+                    "Adjusted 205-Day Weight: {:.1} {}",
+                    adjusted.in_unit(unit),
+                    unit.symbol()
+                );
+            }
+            AdjustedWeightCommands::Yearling {
+                weaning,
+                yearling_weight,
+                yearling_age,
+            } => {
+                let adjusted_205 = weaning.adjusted_205_day_weight(units)?;
+                let adjusted_365 = calculate_adjusted_365_day_weight(
+                    units.mass(*yearling_weight),
+                    Duration::days(*yearling_age),
+                    units.mass(weaning.weaning_weight),
+                    Duration::days(weaning.weaning_age),
+                    adjusted_205,
+                )
+                .context("Failed to calculate adjusted 365-day weight.")?;
+
+                println!(
+                    "Adjusted 205-Day Weight: {:.1} {}",
+                    adjusted_205.in_unit(unit),
+                    unit.symbol()
+                );
+                println!(
+                    "Adjusted 365-Day Weight: {:.1} {}",
+                    adjusted_365.in_unit(unit),
+                    unit.symbol()
+                );
+            }
+            AdjustedWeightCommands::Group { path } => {
+                let calves = read_calves(path, units)?;
+                print_group(&calves, units)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A calf from a contemporary group file, with its adjusted weights.
+#[derive(Debug)]
+struct Calf {
+    id: String,
+    sex: CalfSex,
+    adjusted_205: Mass,
+    adjusted_365: Option<Mass>,
+}
+
+/// Reads calves from a CSV file and calculates their adjusted weights, skipping blank lines,
+/// `#` comments and a header row such as `id,sex,dam_age,...`.
+fn read_calves(path: &Path, units: UnitSystem) -> Result<Vec<Calf>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read calves from {}.", path.display()))?;
+
+    let is_header = |fields: &[&str]| {
+        CalfSex::from_str(fields.get(1).copied().unwrap_or_default(), true).is_err()
+    };
+    let mut calves = Vec::new();
+    for row in read_rows(&contents, is_header) {
+        ensure!(
+            row.fields.len() == 6 || row.fields.len() == 8,
+            "Line {} must have 6 or 8 fields, found {}.",
+            row.line,
+            row.fields.len()
+        );
+
+        let calf = parse_calf(&row.fields, units)
+            .with_context(|| format!("Invalid calf on line {}.", row.line))?;
+        calves.push(calf);
+    }
+    ensure!(!calves.is_empty(), "No calves found in {}.", path.display());
+
+    Ok(calves)
+}
+
+fn parse_calf(fields: &[&str], units: UnitSystem) -> Result<Calf> {
+    let number = |field: &str, name: &str| -> Result<Option<f64>> {
+        match field {
+            "" => Ok(None),
+            _ => field
+                .parse()
+                .map(Some)
+                .with_context(|| format!("Invalid {}: \"{}\"", name, field)),
+        }
+    };
+    let required = |field: &str, name: &str| -> Result<f64> {
+        number(field, name)?.with_context(|| format!("Missing {}.", name))
+    };
+
+    let sex = CalfSex::from_str(fields[1], true).map_err(|err| anyhow!("Invalid sex: {}", err))?;
+    let dam_age = fields[2]
+        .parse()
+        .with_context(|| format!("Invalid dam age: \"{}\"", fields[2]))?;
+    let birth_weight = number(fields[3], "birth weight")?.map(|weight| units.mass(weight));
+    let weaning_age = Duration::days(required(fields[4], "weaning age")?);
+    let weaning_weight = units.mass(required(fields[5], "weaning weight")?);
+
+    let adjusted_205 =
+        calculate_adjusted_205_day_weight(weaning_weight, weaning_age, birth_weight, dam_age, sex)?;
+    let adjusted_365 = match fields.get(6..8) {
+        Some([yearling_age, yearling_weight]) => match (
+            number(yearling_age, "yearling age")?,
+            number(yearling_weight, "yearling weight")?,
+        ) {
+            (Some(yearling_age), Some(yearling_weight)) => Some(calculate_adjusted_365_day_weight(
+                units.mass(yearling_weight),
+                Duration::days(yearling_age),
+                weaning_weight,
+                weaning_age,
+                adjusted_205,
+            )?),
+            (None, None) => None,
+            _ => bail!("Yearling age and weight must be given together."),
+        },
+        _ => None,
+    };
+
+    Ok(Calf {
+        id: fields[0].to_string(),
+        sex,
+        adjusted_205,
+        adjusted_365,
+    })
+}
+
+/// Prints each calf's adjusted weights and ratios, grouped by sex.
+fn print_group(calves: &[Calf], units: UnitSystem) -> Result<()> {
+    let unit = units.mass_unit();
+    let mut groups: BTreeMap<CalfSex, Vec<&Calf>> = BTreeMap::new();
+    for calf in calves {
+        groups.entry(calf.sex).or_default().push(calf);
+    }
+
+    for (sex, calves) in groups {
+        let weaning_ratios = calculate_contemporary_ratios(
+            &calves
+                .iter()
+                .map(|calf| calf.adjusted_205)
+                .collect::<Vec<_>>(),
+        )?;
+        let yearlings: Vec<Mass> = calves.iter().filter_map(|calf| calf.adjusted_365).collect();
+        let mut yearling_ratios = if yearlings.is_empty() {
+            Vec::new()
+        } else {
+            calculate_contemporary_ratios(&yearlings)?
+        }
+        .into_iter();
+
+        println!(" ");
+        println!("{:?} ({})", sex, calves.len());
+        println!(
+            "{:<12} {:>12} {:>6} {:>12} {:>6}",
+            "ID",
+            format!("205-Day ({})", unit.symbol()),
+            "Ratio",
+            format!("365-Day ({})", unit.symbol()),
+            "Ratio"
+        );
+        for (calf, weaning_ratio) in calves.iter().zip(weaning_ratios) {
+            let (adjusted_365, yearling_ratio) = match calf.adjusted_365 {
+                Some(weight) => (
+                    format!("{:.1}", weight.in_unit(unit)),
+                    format!("{:.0}", yearling_ratios.next().unwrap_or_default()),
+                ),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            println!(
+                "{:<12} {:>12.1} {:>6.0} {:>12} {:>6}",
+                calf.id,
+                calf.adjusted_205.in_unit(unit),
+                weaning_ratio,
+                adjusted_365,
+                yearling_ratio
+            );
+        }
+    }
+    println!(" ");
+
+    Ok(())
+}
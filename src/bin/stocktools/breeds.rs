@@ -0,0 +1,342 @@
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use livestock_rs::{
+    breeds::{metadata::BreedMetadata, AnyBreed, Breed},
+    types::LivestockType,
+    units::{Mass, UnitSystem},
+};
+use serde::Serialize;
+use std::str::FromStr;
+
+/// The number of suggestions shown when a breed cannot be found.
+const MAX_SUGGESTIONS: usize = 3;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum OutputFormat {
+    Table,
+    #[cfg(feature = "json")]
+    Json,
+}
+
+#[derive(Subcommand, Debug)]
+enum BreedsCommands {
+    #[command(about = "List breeds, optionally for a single species.")]
+    List {
+        #[arg(
+            long,
+            help = "Only list breeds of this type of livestock.",
+            short = 's',
+            ignore_case = true
+        )]
+        species: Option<LivestockType>,
+    },
+    #[command(about = "Search breeds by name, tolerating typos and missing accents.")]
+    Search {
+        #[arg(help = "The breed name to search for.")]
+        query: String,
+        #[arg(
+            long,
+            help = "Only search breeds of this type of livestock.",
+            short = 's',
+            ignore_case = true
+        )]
+        species: Option<LivestockType>,
+    },
+    #[command(about = "Show the names and metadata of a breed.")]
+    Show {
+        #[arg(help = "The breed name, alias or id, e.g. \"Angus\" or \"cattle:angus\".")]
+        breed: String,
+        #[arg(
+            long,
+            help = "The type of livestock, for names shared by several species.",
+            short = 's',
+            ignore_case = true
+        )]
+        species: Option<LivestockType>,
+    },
+}
+
+#[derive(Parser, Debug)]
+#[command(
+    arg_required_else_help(true),
+    about = "List, search and show livestock breeds.",
+    long_about = "
+        List, search and show livestock breeds.
+
+        Breeds are identified by an id of the form `<species>:<breed>`, e.g. `cattle:angus`, and
+        can also be looked up by their name or any of their aliases.
+
+        # Examples
+
+        List every goat breed:
+
+        ```
+        stocktools breeds list --species goat
+        ```
+
+        Search for a breed when unsure of the spelling:
+
+        ```
+        stocktools breeds search \"blond d aquitaine\"
+        ```
+
+        Show the details of a breed as JSON, when built with the `json` feature:
+
+        ```
+        stocktools breeds --format json show angus
+        ```
+    "
+)]
+pub struct BreedsSubcommand {
+    #[command(subcommand)]
+    command: BreedsCommands,
+    #[arg(
+        long,
+        help = "The output format.",
+        short = 'o',
+        value_enum,
+        default_value_t = OutputFormat::Table,
+        global = true,
+    )]
+    format: OutputFormat,
+}
+
+/// Everything known about a breed, as printed by the subcommands.
+#[derive(Debug, Serialize)]
+struct BreedDetails {
+    id: String,
+    name: String,
+    species: LivestockType,
+    abbreviation: Option<&'static str>,
+    aliases: Vec<&'static str>,
+    metadata: Option<BreedMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<f64>,
+}
+
+impl From<AnyBreed> for BreedDetails {
+    fn from(breed: AnyBreed) -> Self {
+        BreedDetails {
+            id: breed.id(),
+            name: breed.name(),
+            species: breed.species(),
+            abbreviation: breed.abbreviation(),
+            aliases: breed.aliases(),
+            metadata: breed.metadata(),
+            score: None,
+        }
+    }
+}
+
+impl BreedsSubcommand {
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
+        match &self.command {
+            BreedsCommands::List { species } => {
+                let breeds: Vec<BreedDetails> = AnyBreed::iter()
+                    .filter(|breed| species.is_none_or(|species| breed.species() == species))
+                    .map(BreedDetails::from)
+                    .collect();
+
+                self.print_list(&breeds)
+            }
+            BreedsCommands::Search { query, species } => {
+                let breeds: Vec<BreedDetails> = AnyBreed::fuzzy_search(query)
+                    .into_iter()
+                    .filter(|m| species.is_none_or(|species| m.breed.species() == species))
+                    .map(|m| BreedDetails {
+                        score: Some(m.score),
+                        ..BreedDetails::from(m.breed)
+                    })
+                    .collect();
+
+                if breeds.is_empty() {
+                    bail!("No breeds closely match \"{}\".", query);
+                }
+                self.print_list(&breeds)
+            }
+            BreedsCommands::Show { breed, species } => {
+                let breed = find_breed(breed, *species)?;
+                self.print_details(&BreedDetails::from(breed), units)
+            }
+        }
+    }
+
+    fn print_list(&self, breeds: &[BreedDetails]) -> Result<()> {
+        match self.format {
+            #[cfg(feature = "json")]
+            OutputFormat::Json => print_json(&breeds),
+            OutputFormat::Table => {
+                let with_score = breeds.iter().any(|breed| breed.score.is_some());
+                let mut headers = vec!["ID", "Name", "Origin", "Purpose", "Size"];
+                if with_score {
+                    headers.insert(0, "Score");
+                }
+
+                let rows: Vec<Vec<String>> = breeds
+                    .iter()
+                    .map(|breed| {
+                        let mut row = vec![breed.id.clone(), breed.name.clone()];
+                        match &breed.metadata {
+                            Some(metadata) => row.extend([
+                                metadata.origin.to_string(),
+                                format!("{:?}", metadata.purpose),
+                                format!("{:?}", metadata.size_class),
+                            ]),
+                            None => row.extend(["-".to_string(), "-".to_string(), "-".to_string()]),
+                        }
+                        if let Some(score) = breed.score {
+                            row.insert(0, format!("{:.2}", score));
+                        }
+                        row
+                    })
+                    .collect();
+
+                print_table(&headers, &rows);
+                println!(" ");
+                println!("{} breeds", breeds.len());
+                Ok(())
+            }
+        }
+    }
+
+    fn print_details(&self, breed: &BreedDetails, units: UnitSystem) -> Result<()> {
+        match self.format {
+            #[cfg(feature = "json")]
+            OutputFormat::Json => print_json(breed),
+            OutputFormat::Table => {
+                let mut rows = vec![
+                    ("Name", breed.name.clone()),
+                    ("ID", breed.id.clone()),
+                    ("Species", format!("{:?}", breed.species)),
+                ];
+                if let Some(abbreviation) = breed.abbreviation {
+                    rows.push(("Abbreviation", abbreviation.to_string()));
+                }
+                if !breed.aliases.is_empty() {
+                    rows.push(("Aliases", breed.aliases.join(", ")));
+                }
+                if let Some(metadata) = &breed.metadata {
+                    rows.push(("Origin", metadata.origin.to_string()));
+                    rows.push(("Region", format!("{:?}", metadata.region)));
+                    rows.push(("Purpose", format!("{:?}", metadata.purpose)));
+                    rows.push(("Size Class", format!("{:?}", metadata.size_class)));
+                    if let Some(weight) = metadata.male_weight_kg {
+                        rows.push(("Male Weight", format_weight(weight, units)));
+                    }
+                    if let Some(weight) = metadata.female_weight_kg {
+                        rows.push(("Female Weight", format_weight(weight, units)));
+                    }
+                    if let Some(horns) = metadata.horns {
+                        rows.push(("Horns", format!("{:?}", horns)));
+                    }
+                } else {
+                    rows.push(("Metadata", "Not yet compiled for this breed".to_string()));
+                }
+
+                println!(" ");
+                for (label, value) in rows {
+                    println!("{:<16}{}", format!("{}:", label), value);
+                }
+                println!(" ");
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Finds a breed by id, or by name or alias when the name belongs to a single breed.
+fn find_breed(query: &str, species: Option<LivestockType>) -> Result<AnyBreed> {
+    if let Some(species) = species {
+        return AnyBreed::from_str(&format!("{:?}:{}", species, query))
+            .with_context(|| format!("Failed to find {:?} breed \"{}\".", species, query));
+    }
+    if query.contains(':') {
+        return AnyBreed::from_str(query)
+            .with_context(|| format!("Failed to find breed \"{}\".", query));
+    }
+
+    let matches = AnyBreed::fuzzy_search(query);
+    let exact: Vec<AnyBreed> = matches
+        .iter()
+        .filter(|m| m.score >= 1.0)
+        .map(|m| m.breed)
+        .collect();
+
+    match exact.as_slice() {
+        [breed] => Ok(*breed),
+        [] if matches.is_empty() => bail!("No breed named \"{}\".", query),
+        [] => bail!(
+            "No breed named \"{}\". Did you mean {}?",
+            query,
+            matches
+                .iter()
+                .take(MAX_SUGGESTIONS)
+                .map(|m| m.breed.id())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        _ => bail!(
+            "\"{}\" names breeds of several species: {}. Use --species or a breed id.",
+            query,
+            exact
+                .iter()
+                .map(|breed| breed.id())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+#[cfg(feature = "json")]
+fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<()> {
+    let json =
+        serde_json::to_string_pretty(value).context("Failed to serialize breeds as JSON.")?;
+    println!("{}", json);
+    Ok(())
+}
+
+fn print_table(headers: &[&str], rows: &[Vec<String>]) {
+    let widths: Vec<usize> = headers
+        .iter()
+        .enumerate()
+        .map(|(i, header)| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([header.len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let format_row = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let divider: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+
+    println!("{}", format_row(headers.to_vec()));
+    println!(
+        "{}",
+        format_row(divider.iter().map(String::as_str).collect())
+    );
+    for row in rows {
+        println!("{}", format_row(row.iter().map(String::as_str).collect()));
+    }
+}
+
+fn format_weight((min, max): (f64, f64), units: UnitSystem) -> String {
+    let unit = units.mass_unit();
+    format!(
+        "{:.0}-{:.0} {}",
+        Mass::kilograms(min).in_unit(unit),
+        Mass::kilograms(max).in_unit(unit),
+        unit.symbol()
+    )
+}
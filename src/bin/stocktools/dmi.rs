@@ -0,0 +1,157 @@
+use crate::feeds::FeedArgs;
+use anyhow::{Context, Result};
+use clap::Parser;
+use livestock_rs::calculators::feed::intake::{predict_dmi, IntakeInputs};
+use livestock_rs::types::{LivestockType, ProductionStage};
+use livestock_rs::units::{MassRate, UnitSystem};
+
+#[derive(Parser, Debug)]
+#[command(
+    arg_required_else_help(true),
+    about = "Predict daily dry matter intake (DMI) for ration planning.",
+    long_about = "
+        Predict daily dry matter intake (DMI) for ration planning.
+
+        Before animals are on feed their actual intake is unknown, so it is predicted from body
+        weight, stage of production and the energy density of the diet. The equation is chosen
+        by the type of livestock and stage of production:
+
+        - Growing cattle (NASEM, 2016): DMI = SBW^0.75 × (0.2435 × NEm - 0.0466 × NEm² - 0.1128) / NEm
+        - Beef cows (NASEM, 2016): DMI = SBW^0.75 × (0.04997 × NEm² + 0.04631) / NEm + 0.2 × milk
+          for pregnant cows, with 0.03840 in place of 0.04631 for open cows
+        - Lactating dairy cows (NRC, 2001), with `--week-of-lactation`:
+          DMI = (0.372 × FCM + 0.0968 × BW^0.75) × (1 - e^(-0.192 × (WOL + 3.67)))
+        - Growing pigs (NRC, 1998): DMI = 13.162 × (1 - e^(-0.0176 × BW)) / DE
+        - Sheep (NRC, 1985): the dry matter of the requirement tables for 50 to 90 kg ewes and
+          30 to 50 kg finishing lambs
+        - Goats (NRC, 1981): DMI = ME / diet ME, where maintenance needs ME = 0.10138 × BW^0.75,
+          plus 0.36 Mcal per 50 g/day of `--adg`, 1.42 Mcal in gestation and 1.20 Mcal per kg of
+          milk, on a 2.0 Mcal/kg ME diet unless `--energy-density` is given
+        - Horses and donkeys: a percentage of body weight for the stage.
+
+        where:
+
+        - `BW` is the body weight in kg, and `SBW` the shrunk body weight, `0.96 × BW`.
+        - `NEm` is the diet's net energy for maintenance, in Mcal/kg of dry matter.
+        - `DE` is the diet's digestible energy, in Mcal/kg of dry matter. NRC (1998) lists diets
+          as fed, so divide an as-fed DE by the diet's dry matter proportion first.
+        - `milk` and `FCM` are the daily milk yield, 4% fat-corrected for dairy cows, in kg/day.
+        - `ME` is metabolizable energy, in Mcal/day, or Mcal/kg of dry matter for the diet.
+        - `WOL` is the week of lactation.
+
+        # Examples
+
+        Predict the intake of a 770 lb steer on a diet with 2.0 Mcal/kg NEm:
+
+        ```
+        stocktools --units imperial dmi -t cattle -s growing -w 770 --energy-density 2.0
+        ```
+
+        Predict the intake of a 1200 lb beef cow nursing a calf, rebred, on a 1.2 Mcal/kg NEm diet:
+
+        ```
+        stocktools --units imperial dmi -t cattle -s lactation -w 1200 -e 1.2 --milk-yield 18 --pregnant
+        ```
+
+        Predict the intake of a 650 kg dairy cow giving 35 kg/day of milk in week 20:
+
+        ```
+        stocktools dmi -t cattle -s lactation -w 650 --milk-yield 35 --week-of-lactation 20
+        ```
+
+        Predict the intake of a 20 kg kid gaining 100 g/day:
+
+        ```
+        stocktools dmi -t goat -s growing -w 20 --adg 0.1
+        ```
+
+        With `--feed`, the intake is also given as fed, and for cattle the feed's NEm is used
+        when `--energy-density` is not given:
+
+        ```
+        stocktools dmi -t cattle -s growing -w 350 --feed \"corn silage\"
+        ```
+    "
+)]
+pub struct DmiSubcommand {
+    #[arg(long, help = "The type of livestock.", short = 't', ignore_case = true)]
+    livestock_type: LivestockType,
+    #[arg(
+        help = "Stage of production",
+        long,
+        short = 's',
+        value_enum,
+        ignore_case = true
+    )]
+    stage: ProductionStage,
+    #[arg(
+        help = "Body weight (in kg, or lb with --units imperial)",
+        long,
+        short = 'w'
+    )]
+    body_weight: f64,
+    #[arg(
+        help = "Diet energy density in Mcal/kg DM: NEm for cattle, DE for swine, ME for goats",
+        long,
+        short = 'e'
+    )]
+    energy_density: Option<f64>,
+    #[arg(
+        help = "Daily milk yield of lactating cattle and goats (in kg/day, or lb/day with --units imperial)",
+        long
+    )]
+    milk_yield: Option<f64>,
+    #[arg(
+        help = "ADG of growing goats (in kg/day, or lb/day with --units imperial)",
+        long,
+        short = 'a'
+    )]
+    adg: Option<f64>,
+    #[arg(help = "Weeks since calving, to use the dairy cow equation", long)]
+    week_of_lactation: Option<f64>,
+    #[arg(help = "The animal is pregnant (implied by the gestation stage)", long)]
+    pregnant: bool,
+    #[command(flatten)]
+    feed: FeedArgs,
+}
+
+impl DmiSubcommand {
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
+        let unit = units.mass_unit();
+        let feed = self.feed.feed()?;
+        let energy_density = match (self.energy_density, &feed, self.livestock_type) {
+            (None, Some(feed), LivestockType::Cattle) => Some(feed.nem),
+            (energy_density, _, _) => energy_density,
+        };
+        let inputs = IntakeInputs {
+            energy_density,
+            milk_yield: self
+                .milk_yield
+                .map(|milk_yield| MassRate::per_day(milk_yield, unit)),
+            adg: self.adg.map(|adg| MassRate::per_day(adg, unit)),
+            week_of_lactation: self.week_of_lactation,
+            pregnant: self.pregnant || self.stage == ProductionStage::Gestation,
+            ..IntakeInputs::new(units.mass(self.body_weight), self.stage)
+        };
+        let prediction =
+            predict_dmi(self.livestock_type, &inputs).context("Failed to predict DMI.")?;
+
+        println!(
+            "Predicted DMI: {:.2} {}/day ({:.2}% of body weight, {:?} equation)",
+            prediction.dmi.in_unit(unit),
+            unit.symbol(),
+            prediction.percent_of_body_weight,
+            prediction.equation
+        );
+        if let Some(feed) = feed {
+            println!(
+                "As Fed: {:.2} {}/day of {} ({:.0}% DM)",
+                feed.to_as_fed(prediction.dmi).in_unit(unit),
+                unit.symbol(),
+                feed.name,
+                feed.dry_matter
+            );
+        }
+        Ok(())
+    }
+}
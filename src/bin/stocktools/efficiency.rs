@@ -1,6 +1,15 @@
 use anyhow::{anyhow, Context, Result};
 use clap::Parser;
-use livestock_rs::{calculators::feed::{efficiency::calculate_feed_efficiency, fcr::calculate_fcr}, types::LivestockType};
+use livestock_rs::{
+    calculators::feed::{
+        efficiency::{rate_feed_efficiency, FcrBenchmarks},
+        fcr::calculate_fcr,
+    },
+    types::{FeedingPhase, LivestockType},
+    units::{Duration, UnitSystem},
+};
+use std::fs;
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -21,6 +30,24 @@ use livestock_rs::{calculators::feed::{efficiency::calculate_feed_efficiency, fc
         - `Feed Efficiency` is the efficiency of feed conversion.
         - `FCR` is the Feed Conversion Ratio of the animal.
 
+        The FCR is rated against a benchmark range for the type of livestock. FCR rises as
+        animals grow, so with `--phase` it is rated against the range for that feeding phase
+        instead. Built-in phase ranges are:
+
+        - Swine, from the NRC (1998) growing pig tables: starter below 10 kg, grower from 10 to
+          50 kg and finisher from 50 kg. `--body-weight` picks the phase from the weight.
+        - Backgrounding and finishing cattle, from the intake NASEM (2016) predicts for
+          steers of 250 to 350 kg and 400 to 550 kg. These are dry matter FCRs.
+        - Broilers, from the cumulative FCR of the Ross 308 Performance Objectives (Aviagen,
+          2022): starter to 10 days, grower from 11 to 24 days and finisher from 25 days.
+          `--age` picks the phase from the age in days.
+
+        Phases of other species need benchmarks from a file.
+
+        The benchmarks can be overridden with `--benchmarks`, a TOML file of `[[benchmark]]`
+        tables with `livestock_type`, an optional `phase`, `min_fcr` and `max_fcr`. There is no
+        built-in benchmark for reindeer, so they need one from a file.
+
         # Example
 
         Calculate the Feed Efficiency for an animal with a FCR of 2.0:
@@ -30,29 +57,77 @@ use livestock_rs::{calculators::feed::{efficiency::calculate_feed_efficiency, fc
         ```
 
         The result will be `0.5`, which means the animal converted feed with an efficiency of 0.5. This is very good for cattle.      
+
+        Rate a 30 kg pig against grower pigs:
+
+        ```
+        stocktools feed-efficiency --fcr 2.4 --livestock-type Swine --body-weight 30
+        ```
+
+        Rate a 3 week old broiler flock:
+
+        ```
+        stocktools feed-efficiency --fcr 1.25 --livestock-type Chicken --age 21
+        ```
+
+        Rate a feeder steer against backgrounding cattle, with our own benchmarks:
+
+        ```
+        stocktools feed-efficiency --fcr 7.5 --livestock-type Cattle --phase backgrounding --benchmarks benchmarks.toml
+        ```
     "
 )]
 pub struct FeedEfficiencySubcommand {
-    #[arg(help = "Amount of feed intake (in kg or lbs)", long, short = 'i')]
+    #[arg(
+        help = "Amount of feed intake (in kg, or lb with --units imperial)",
+        long,
+        short = 'i'
+    )]
     feed_intake: Option<f64>,
-    #[arg(help = "Weight gain of livestock (in kg or lbs)", long, short = 'g')]
+    #[arg(
+        help = "Weight gain of livestock (in kg, or lb with --units imperial)",
+        long,
+        short = 'g'
+    )]
     weight_gain: Option<f64>,
     #[arg(help = "Feed efficiency ratio (FCR)", long)]
     fcr: Option<f64>,
+    #[arg(long, help = "The type of livestock.", short = 't', ignore_case = true)]
+    livestock_type: LivestockType,
     #[arg(
+        help = "Feeding phase to rate against",
         long,
-        help = "The type of livestock.",
-        short = 't',
+        short = 'p',
+        value_enum,
+        ignore_case = true
     )]
-    livestock_type: LivestockType,
+    phase: Option<FeedingPhase>,
+    #[arg(
+        help = "Body weight, to rate against its feeding phase (in kg, or lb with --units imperial)",
+        long,
+        short = 'w',
+        conflicts_with = "phase"
+    )]
+    body_weight: Option<f64>,
+    #[arg(
+        help = "Age in days, to rate against its feeding phase",
+        long,
+        conflicts_with_all = ["phase", "body_weight"]
+    )]
+    age: Option<f64>,
+    #[arg(
+        help = "TOML file of FCR benchmarks to override the built-in ones",
+        long
+    )]
+    benchmarks: Option<PathBuf>,
 }
 
 impl FeedEfficiencySubcommand {
-    pub fn run(&self) -> Result<()> {
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
         // ensure that we either have feed intake and weight gain or FCR
         let fcr = match (self.feed_intake, self.weight_gain, self.fcr) {
             (Some(feed_intake), Some(weight_gain), None) => {
-                calculate_fcr(feed_intake, weight_gain).context(
+                calculate_fcr(units.mass(feed_intake), units.mass(weight_gain)).context(
                     "Failed to calculate FCR, which is required if feed intake and weight gain are provided.
                     FCR is needed to calculate feed efficiency.",
                 )?
@@ -65,15 +140,59 @@ impl FeedEfficiencySubcommand {
             }
         };
 
-        let feed_efficiency = calculate_feed_efficiency(fcr, self.livestock_type.clone()).with_context(|| format!(
-            "Failed to calculate feed efficiency with FCR: {} and livestock type: {:?}.",
-            fcr, self.livestock_type
-        ))?;
-    
+        let phase = match (self.phase, self.body_weight, self.age) {
+            (None, Some(body_weight), _) => Some(
+                FeedingPhase::for_weight(self.livestock_type, units.mass(body_weight)).ok_or_else(
+                    || {
+                        anyhow!(
+                            "There are no weight classes for {:?} phases; give the phase instead.",
+                            self.livestock_type
+                        )
+                    },
+                )?,
+            ),
+            (None, _, Some(age)) => Some(
+                FeedingPhase::for_age(self.livestock_type, Duration::days(age)).ok_or_else(
+                    || {
+                        anyhow!(
+                            "{:?} phases are not set by age; give the phase instead.",
+                            self.livestock_type
+                        )
+                    },
+                )?,
+            ),
+            (phase, _, _) => phase,
+        };
+
+        let mut benchmarks = FcrBenchmarks::builtin();
+        if let Some(path) = &self.benchmarks {
+            let contents = fs::read_to_string(path).with_context(|| {
+                format!("Failed to read FCR benchmarks from {}.", path.display())
+            })?;
+            benchmarks.extend_from_toml(&contents).with_context(|| {
+                format!("Failed to read FCR benchmarks from {}.", path.display())
+            })?;
+        }
+
+        let feed_efficiency = rate_feed_efficiency(fcr, self.livestock_type, phase, &benchmarks)
+            .with_context(|| {
+                format!(
+                    "Failed to calculate feed efficiency with FCR: {} and livestock type: {:?}.",
+                    fcr, self.livestock_type
+                )
+            })?;
+        let group = match feed_efficiency.phase {
+            Some(phase) => format!("{:?} in the {:?} phase", self.livestock_type, phase),
+            None => format!("{:?}", self.livestock_type),
+        };
+
         println!(" ");
         println!("Feed Efficiency Rating: {:?}", feed_efficiency.rating);
         println!("FCR: {:.2}", feed_efficiency.value);
-        println!("{:?} should aim for a FCR between {:.2} and {:.2}.", self.livestock_type, feed_efficiency.avg_min_fcr, feed_efficiency.avg_max_fcr);
+        println!(
+            "{} should aim for a FCR between {:.2} and {:.2}.",
+            group, feed_efficiency.avg_min_fcr, feed_efficiency.avg_max_fcr
+        );
         println!(" ");
 
         Ok(())
@@ -0,0 +1,107 @@
+use anyhow::{ensure, Context, Result};
+use clap::Parser;
+use livestock_rs::calculators::growth::estimate::{estimate_weight, TapeFormula};
+use livestock_rs::types::LivestockType;
+use livestock_rs::units::UnitSystem;
+
+#[derive(Parser, Debug)]
+#[command(
+    arg_required_else_help(true),
+    about = "Estimate body weight from heart girth and body length measurements.",
+    long_about = "
+        Estimate body weight from heart girth and body length measurements.
+
+        Without a scale, body weight can be estimated from tape measurements. The heart girth is
+        measured around the chest just behind the front legs, and the body length from the point
+        of the shoulder to the pin bone.
+
+        The formula is chosen by the type of livestock:
+
+        - Cattle: Schaeffer's formula with a body length, otherwise heart girth alone
+          (Heinrichs et al., 1992). The heart girth formula was fitted to Holstein heifers, so
+          measure the body length for beef cattle, mature cows and bulls.
+        - Sheep: Schaeffer's formula, `weight (lb) = girth² × length / 300` in inches. There is
+          no heart-girth-only formula for sheep yet.
+        - Goats: Schaeffer's formula with a body length, otherwise
+          `weight (kg) = 1.06 × girth - 39.87` in cm (Nsoso et al., 2003), fitted to Tswana goats.
+        - Horses: `weight (kg) = girth² × length / 11880` in cm (Carroll and Huntington, 1988).
+        - Swine: `weight (lb) = girth² × length / 400` in inches, from US extension guides.
+
+        Measurements are in cm, or inches with `--units imperial`. The estimate is printed with
+        its expected error band when a published error is known for the formula: ±5% for the
+        cattle heart girth formula and ±10% for horses.
+
+        # Example
+
+        Estimate the weight of a steer with a 75 in heart girth and a 64 in body length:
+
+        ```
+        stocktools --units imperial estimate-weight -t cattle -g 75 -l 64
+        ```
+
+        The result will be `1200 lb`.
+    "
+)]
+pub struct EstimateWeightSubcommand {
+    #[arg(long, help = "The type of livestock.", short = 't', ignore_case = true)]
+    livestock_type: LivestockType,
+    #[arg(
+        help = "Heart girth (in cm, or inches with --units imperial)",
+        long,
+        short = 'g'
+    )]
+    heart_girth: f64,
+    #[arg(
+        help = "Body length (in cm, or inches with --units imperial)",
+        long,
+        short = 'l'
+    )]
+    body_length: Option<f64>,
+    #[arg(
+        help = "Use this formula instead of the default for the type of livestock",
+        long,
+        value_enum
+    )]
+    formula: Option<TapeFormula>,
+}
+
+impl EstimateWeightSubcommand {
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
+        let heart_girth = units.length(self.heart_girth);
+        let body_length = self.body_length.map(|length| units.length(length));
+
+        let estimate = match self.formula {
+            Some(formula) => {
+                ensure!(
+                    formula.livestock_types().contains(&self.livestock_type),
+                    "The {:?} formula is not for {:?}.",
+                    formula,
+                    self.livestock_type
+                );
+                formula.estimate(heart_girth, body_length)
+            }
+            None => estimate_weight(self.livestock_type, heart_girth, body_length),
+        }
+        .context("Failed to estimate weight.")?;
+
+        let unit = units.mass_unit();
+        match (estimate.low, estimate.high) {
+            (Some(low), Some(high)) => println!(
+                "Estimated Weight: {:.0} {} ({:.0} to {:.0} {}, {:?} formula)",
+                estimate.weight.in_unit(unit),
+                unit.symbol(),
+                low.in_unit(unit),
+                high.in_unit(unit),
+                unit.symbol(),
+                estimate.formula
+            ),
+            _ => println!(
+                "Estimated Weight: {:.0} {} ({:?} formula)",
+                estimate.weight.in_unit(unit),
+                unit.symbol(),
+                estimate.formula
+            ),
+        }
+        Ok(())
+    }
+}
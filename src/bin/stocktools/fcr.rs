@@ -1,6 +1,8 @@
+use crate::feeds::FeedArgs;
 use anyhow::{Context, Result};
 use clap::Parser;
-use livestock_rs::calculators::feed::fcr::calculate_fcr;
+use livestock_rs::calculators::feed::fcr::{calculate_dry_matter_fcr, calculate_fcr};
+use livestock_rs::units::UnitSystem;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -20,8 +22,8 @@ use livestock_rs::calculators::feed::fcr::calculate_fcr;
         where:
 
         - `FCR` is the feed conversion ratio as a ratio of feed intake to weight gain.
-        - `feed_intake` is the amount of feed consumed by the animal (in kg or lbs).
-        - `weight_gain` is the weight gain of the animal (in kg or lbs).
+        - `feed_intake` is the amount of feed consumed by the animal (in kg, or lb with `--units imperial`).
+        - `weight_gain` is the weight gain of the animal (in kg, or lb with `--units imperial`).
 
         # Example
 
@@ -32,21 +34,56 @@ use livestock_rs::calculators::feed::fcr::calculate_fcr;
         ```
 
         The result will be `0.67`, which means the animal required 0.67 kg of feed per kg of weight gain.
+
+        With `--feed`, the intake is taken as fed and the FCR is also given on a dry matter basis,
+        using the feed's dry matter from the feed library:
+
+        ```
+        stocktools fcr -i 1000 -g 50 --feed \"corn silage\"
+        ```
     "
 )]
 pub struct FcrSubcommand {
-    #[arg(help = "Amount of feed intake (in kg or lbs)", long, short = 'i')]
+    #[arg(
+        help = "Amount of feed intake (in kg, or lb with --units imperial)",
+        long,
+        short = 'i'
+    )]
     feed_intake: f64,
-    #[arg(help = "Weight gain of livestock (in kg or lbs)", long, short = 'g')]
+    #[arg(
+        help = "Weight gain of livestock (in kg, or lb with --units imperial)",
+        long,
+        short = 'g'
+    )]
     weight_gain: f64,
+    #[command(flatten)]
+    feed: FeedArgs,
 }
 
 impl FcrSubcommand {
-    pub fn run(&self) -> Result<()> {
-        let fcr = calculate_fcr(self.feed_intake, self.weight_gain)
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
+        let feed = self.feed.feed()?;
+        let fcr = calculate_fcr(units.mass(self.feed_intake), units.mass(self.weight_gain))
             .context("Failed to calculate FCR.")?;
 
-        println!("Feed Conversion Ratio (FCR): {:.2}", fcr);
+        let unit = units.mass_unit().symbol();
+        println!(
+            "Feed Conversion Ratio (FCR): {:.2} {} of feed per {} of gain",
+            fcr, unit, unit
+        );
+
+        if let Some(feed) = feed {
+            let fcr = calculate_dry_matter_fcr(
+                units.mass(self.feed_intake),
+                &feed,
+                units.mass(self.weight_gain),
+            )
+            .context("Failed to calculate dry matter FCR.")?;
+            println!(
+                "Dry Matter FCR: {:.2} {} of dry matter per {} of gain ({}, {:.0}% DM)",
+                fcr, unit, unit, feed.name, feed.dry_matter
+            );
+        }
         Ok(())
     }
 }
@@ -0,0 +1,164 @@
+use anyhow::{anyhow, Context, Result};
+use clap::{Args, Parser};
+use livestock_rs::calculators::feed::library::{Feed, FeedLibrary};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser, Debug)]
+#[command(
+    about = "List and show feeds in the feed library.",
+    long_about = "
+        List and show feeds in the feed library.
+
+        The built-in library has typical nutrient profiles of common grains, protein meals,
+        forages and minerals. Dry matter (DM) is a percentage of the feed as fed; every other
+        nutrient is on a dry matter basis:
+
+        - `CP` is crude protein, `TDN` total digestible nutrients, `ADF` acid detergent fiber and
+          `NDF` neutral detergent fiber, all in % of DM.
+        - `NEm` and `NEg` are net energy for maintenance and gain, in Mcal/kg of DM.
+        - `Ca` and `P` are calcium and phosphorus, in % of DM.
+
+        Feeds can be added, or book values replaced by forage test results, with `--feed-file`.
+        CSV files have `name,dry_matter,crude_protein,tdn,nem,neg,adf,ndf,calcium,phosphorus`
+        rows, and TOML files a `[[feed]]` table with those fields for each feed.
+
+        # Examples
+
+        List every feed, including those in a file of forage tests:
+
+        ```
+        stocktools feeds --feed-file forage-tests.csv
+        ```
+
+        Show the nutrient profile of one feed:
+
+        ```
+        stocktools feeds \"corn silage\"
+        ```
+    "
+)]
+pub struct FeedsSubcommand {
+    #[arg(help = "The feed to show, e.g. \"corn silage\". Lists every feed if omitted.")]
+    name: Option<String>,
+    #[arg(
+        help = "CSV or TOML file of feeds to add to the library",
+        long = "feed-file"
+    )]
+    feed_files: Vec<PathBuf>,
+}
+
+impl FeedsSubcommand {
+    pub fn run(&self) -> Result<()> {
+        let library = load_library(&self.feed_files)?;
+
+        match &self.name {
+            Some(name) => {
+                let feed = find_feed(&library, name)?;
+                let rows = [
+                    ("Dry Matter", format!("{:.1}% as fed", feed.dry_matter)),
+                    ("Crude Protein", format!("{:.1}% of DM", feed.crude_protein)),
+                    ("TDN", format!("{:.1}% of DM", feed.tdn)),
+                    ("NEm", format!("{:.2} Mcal/kg DM", feed.nem)),
+                    ("NEg", format!("{:.2} Mcal/kg DM", feed.neg)),
+                    ("ADF", format!("{:.1}% of DM", feed.adf)),
+                    ("NDF", format!("{:.1}% of DM", feed.ndf)),
+                    ("Calcium", format!("{:.2}% of DM", feed.calcium)),
+                    ("Phosphorus", format!("{:.2}% of DM", feed.phosphorus)),
+                ];
+
+                println!("{}", feed.name);
+                for (label, value) in rows {
+                    println!("  {:<14} {}", label, value);
+                }
+            }
+            None => {
+                println!(
+                    "{:<22} {:>6} {:>6} {:>6} {:>6} {:>6} {:>6} {:>6} {:>6} {:>6}",
+                    "Feed", "DM %", "CP %", "TDN %", "NEm", "NEg", "ADF %", "NDF %", "Ca %", "P %"
+                );
+                for feed in library.feeds() {
+                    println!(
+                        "{:<22} {:>6.1} {:>6.1} {:>6.1} {:>6.2} {:>6.2} {:>6.1} {:>6.1} {:>6.2} {:>6.2}",
+                        feed.name,
+                        feed.dry_matter,
+                        feed.crude_protein,
+                        feed.tdn,
+                        feed.nem,
+                        feed.neg,
+                        feed.adf,
+                        feed.ndf,
+                        feed.calcium,
+                        feed.phosphorus
+                    );
+                }
+                println!(" ");
+                println!("{} feeds", library.feeds().len());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Arguments for subcommands that can work with a feed from the library.
+#[derive(Args, Debug)]
+pub struct FeedArgs {
+    #[arg(
+        help = "Feed from the feed library, e.g. \"corn silage\" (see `stocktools feeds`)",
+        long
+    )]
+    feed: Option<String>,
+    #[arg(
+        help = "CSV or TOML file of feeds to add to the library",
+        long = "feed-file",
+        requires = "feed"
+    )]
+    feed_files: Vec<PathBuf>,
+}
+
+impl FeedArgs {
+    /// The chosen feed, if any.
+    pub fn feed(&self) -> Result<Option<Feed>> {
+        match &self.feed {
+            Some(name) => {
+                let library = load_library(&self.feed_files)?;
+                find_feed(&library, name).cloned().map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// The built-in feed library, extended with feeds from CSV or TOML files.
+pub fn load_library(paths: &[PathBuf]) -> Result<FeedLibrary> {
+    let mut library = FeedLibrary::builtin();
+    for path in paths {
+        extend_from_file(&mut library, path)?;
+    }
+    Ok(library)
+}
+
+fn extend_from_file(library: &mut FeedLibrary, path: &Path) -> Result<usize> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read feeds from {}.", path.display()))?;
+
+    let is_toml = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"));
+    if is_toml {
+        library.extend_from_toml(&contents)
+    } else {
+        library.extend_from_csv(&contents)
+    }
+    .with_context(|| format!("Failed to read feeds from {}.", path.display()))
+}
+
+fn find_feed<'a>(library: &'a FeedLibrary, name: &str) -> Result<&'a Feed> {
+    library.get(name).ok_or_else(|| {
+        anyhow!(
+            "\"{}\" is not in the feed library. Run `stocktools feeds` to list feeds.",
+            name
+        )
+    })
+}
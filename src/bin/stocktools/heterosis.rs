@@ -0,0 +1,173 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use livestock_rs::{
+    breeds::{
+        Breed, BreedComposition, BreedParseError, CamelBreed, CattleBreed, ChickenBreed,
+        DonkeyBreed, GoatBreed, HorseBreed, RabbitBreed, ReindeerBreed, SheepBreed, SwineBreed,
+    },
+    calculators::genetics::heterosis::{
+        calculate_heterosis, individual_heterosis, rotational_retained_heterosis,
+    },
+    types::LivestockType,
+};
+use std::str::FromStr;
+
+#[derive(Parser, Debug)]
+#[command(
+    arg_required_else_help(true),
+    about = "Calculate expected heterosis (hybrid vigor) for a mating.",
+    long_about = "
+        Calculate expected heterosis (hybrid vigor) for a mating.
+
+        Heterosis is the advantage of crossbred animals over the average of their parent breeds.
+        Individual heterosis is expressed by the offspring, and maternal heterosis by a crossbred
+        dam. Both are given as a percentage of the heterosis of an F1 cross.
+
+        The formula for individual heterosis is:
+
+        Heterosis = 1 - Σ(sire_fraction × dam_fraction)
+
+        where:
+
+        - `sire_fraction` is the fraction of a breed in the sire.
+        - `dam_fraction` is the fraction of the same breed in the dam.
+
+        Compositions are written as fraction and breed pairs, using registry abbreviations
+        where they exist, e.g. `1/2 AN 1/4 HH 1/4 BR`.
+
+        Maternal heterosis depends on the dam's parents rather than her composition, so give
+        either the compositions of the dam's parents or the dam's heterosis directly, e.g. 0 for
+        a purebred dam.
+
+        # Example
+
+        Calculate heterosis for an Angus bull on F1 Hereford x Angus cows:
+
+        ```
+        stocktools heterosis -t cattle -s \"1 AN\" -d \"1/2 AN 1/2 HH\" --maternal-grandsire \"1 HH\" --maternal-granddam \"1 AN\"
+        ```
+
+        The calves will express 50% individual heterosis and their dams 100% maternal heterosis.
+    "
+)]
+pub struct HeterosisSubcommand {
+    #[arg(long, help = "The type of livestock.", short = 't', ignore_case = true)]
+    livestock_type: LivestockType,
+    #[arg(
+        help = "Breed composition of the sire, e.g. \"1 AN\"",
+        long,
+        short = 's'
+    )]
+    sire: String,
+    #[arg(
+        help = "Breed composition of the dam, e.g. \"1/2 AN 1/2 HH\"",
+        long,
+        short = 'd'
+    )]
+    dam: String,
+    #[arg(
+        help = "Breed composition of the dam's sire, used to calculate maternal heterosis",
+        long,
+        requires = "maternal_granddam",
+        conflicts_with = "dam_heterosis"
+    )]
+    maternal_grandsire: Option<String>,
+    #[arg(
+        help = "Breed composition of the dam's dam, used to calculate maternal heterosis",
+        long,
+        requires = "maternal_grandsire"
+    )]
+    maternal_granddam: Option<String>,
+    #[arg(
+        help = "The dam's own heterosis in percent, instead of her parents' compositions",
+        long,
+        required_unless_present = "maternal_grandsire"
+    )]
+    dam_heterosis: Option<f64>,
+    #[arg(
+        help = "Also show retained heterosis for a rotation with this many breeds",
+        long
+    )]
+    rotation_breeds: Option<u32>,
+}
+
+impl HeterosisSubcommand {
+    pub fn run(&self) -> Result<()> {
+        match self.livestock_type {
+            LivestockType::Cattle => self.run_for::<CattleBreed>(),
+            LivestockType::Swine => self.run_for::<SwineBreed>(),
+            LivestockType::Chicken => self.run_for::<ChickenBreed>(),
+            LivestockType::Rabbit => self.run_for::<RabbitBreed>(),
+            LivestockType::Sheep => self.run_for::<SheepBreed>(),
+            LivestockType::Goat => self.run_for::<GoatBreed>(),
+            LivestockType::Horse => self.run_for::<HorseBreed>(),
+            LivestockType::Donkey => self.run_for::<DonkeyBreed>(),
+            LivestockType::Camel => self.run_for::<CamelBreed>(),
+            LivestockType::Reindeer => self.run_for::<ReindeerBreed>(),
+        }
+    }
+
+    fn run_for<B>(&self) -> Result<()>
+    where
+        B: Breed + Copy + Ord + FromStr<Err = BreedParseError>,
+    {
+        let sire = parse_composition::<B>("sire", &self.sire)?;
+        let dam = parse_composition::<B>("dam", &self.dam)?;
+
+        let dam_heterosis = match (&self.maternal_grandsire, &self.maternal_granddam) {
+            (Some(grandsire), Some(granddam)) => individual_heterosis(
+                &parse_composition::<B>("maternal grandsire", grandsire)?,
+                &parse_composition::<B>("maternal granddam", granddam)?,
+            ),
+            _ => self
+                .dam_heterosis
+                .context("Provide --dam-heterosis or the dam's parents.")?,
+        };
+
+        let heterosis = calculate_heterosis(&sire, &dam, dam_heterosis)
+            .context("Failed to calculate heterosis.")?;
+
+        println!(" ");
+        println!("Sire: {}", sire);
+        println!("Dam: {}", dam);
+        println!("Individual Heterosis: {:.1}%", heterosis.individual);
+        println!("Maternal Heterosis: {:.1}%", heterosis.maternal);
+
+        if heterosis.traits.is_empty() {
+            println!(
+                "No published trait estimates are available for {:?}.",
+                self.livestock_type
+            );
+        } else {
+            println!(" ");
+            println!("Expected advantage over the parental average:");
+            for t in heterosis.traits.iter() {
+                println!(
+                    "  {:?}: {:.1}% (individual {:.1}%, maternal {:.1}%)",
+                    t.heterosis_trait, t.total, t.individual, t.maternal
+                );
+            }
+        }
+
+        if let Some(breeds) = self.rotation_breeds {
+            let retained = rotational_retained_heterosis(breeds)
+                .context("Failed to calculate rotational heterosis.")?;
+            println!(" ");
+            println!(
+                "Retained Heterosis in a {}-breed rotation: {:.1}%",
+                breeds, retained
+            );
+        }
+        println!(" ");
+
+        Ok(())
+    }
+}
+
+fn parse_composition<B>(parent: &str, s: &str) -> Result<BreedComposition<B>>
+where
+    B: Breed + Copy + Ord + FromStr<Err = BreedParseError>,
+{
+    BreedComposition::from_str(s)
+        .with_context(|| format!("Invalid breed composition for the {}: \"{}\"", parent, s))
+}
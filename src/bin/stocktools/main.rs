@@ -1,20 +1,69 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
+use livestock_rs::units::UnitSystem;
 
 mod adg;
 use adg::AdgSubcommand;
 
+mod adjusted;
+use adjusted::AdjustedWeightSubcommand;
+
+mod breeds;
+use breeds::BreedsSubcommand;
+
+// The library reads its feed files with the same reader, without making it public API.
+#[path = "../../csv.rs"]
+mod csv;
+
+mod dmi;
+use dmi::DmiSubcommand;
+
 mod efficiency;
 use efficiency::FeedEfficiencySubcommand;
 
+mod estimate;
+use estimate::EstimateWeightSubcommand;
+
+mod feeds;
+use feeds::FeedsSubcommand;
+
 mod fcr;
 use fcr::FcrSubcommand;
 
+mod heterosis;
+use heterosis::HeterosisSubcommand;
+
+mod project;
+use project::ProjectSubcommand;
+
+mod ration;
+use ration::RationSubcommand;
+
+mod requirements;
+use requirements::RequirementsSubcommand;
+
+mod rfi;
+use rfi::RfiSubcommand;
+
+mod wda;
+use wda::WdaSubcommand;
+
 #[derive(Subcommand, Debug)]
 enum Commands {
     Adg(AdgSubcommand),
+    AdjustedWeight(AdjustedWeightSubcommand),
+    Breeds(BreedsSubcommand),
+    Dmi(DmiSubcommand),
+    EstimateWeight(EstimateWeightSubcommand),
     Fcr(FcrSubcommand),
     FeedEfficiency(FeedEfficiencySubcommand),
+    Feeds(FeedsSubcommand),
+    Heterosis(HeterosisSubcommand),
+    Project(ProjectSubcommand),
+    Ration(RationSubcommand),
+    Requirements(RequirementsSubcommand),
+    Rfi(RfiSubcommand),
+    Wda(WdaSubcommand),
 }
 
 #[derive(Parser)]
@@ -29,14 +78,33 @@ struct Cli {
     command: Commands,
     #[arg(long, short, help = "Print extra detail in output logs")]
     verbose: bool,
+    #[arg(
+        long,
+        help = "The units weights are given and printed in.",
+        value_enum,
+        default_value_t = UnitSystem::Metric,
+        global = true
+    )]
+    units: UnitSystem,
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Adg(subcommand) => subcommand.run(),
-        Commands::Fcr(subcommand) => subcommand.run(),
-        Commands::FeedEfficiency(subcommand) => subcommand.run(),
+        Commands::Adg(subcommand) => subcommand.run(cli.units),
+        Commands::AdjustedWeight(subcommand) => subcommand.run(cli.units),
+        Commands::Breeds(subcommand) => subcommand.run(cli.units),
+        Commands::Dmi(subcommand) => subcommand.run(cli.units),
+        Commands::EstimateWeight(subcommand) => subcommand.run(cli.units),
+        Commands::Fcr(subcommand) => subcommand.run(cli.units),
+        Commands::FeedEfficiency(subcommand) => subcommand.run(cli.units),
+        Commands::Feeds(subcommand) => subcommand.run(),
+        Commands::Heterosis(subcommand) => subcommand.run(),
+        Commands::Project(subcommand) => subcommand.run(cli.units),
+        Commands::Ration(subcommand) => subcommand.run(cli.units),
+        Commands::Requirements(subcommand) => subcommand.run(cli.units),
+        Commands::Rfi(subcommand) => subcommand.run(cli.units),
+        Commands::Wda(subcommand) => subcommand.run(cli.units),
     }
 }
@@ -0,0 +1,197 @@
+use crate::adg::read_weigh_ins;
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use livestock_rs::calculators::growth::adg::calculate_series_adg;
+use livestock_rs::calculators::growth::projection::{
+    project_market_date, project_market_date_range, Projection,
+};
+use livestock_rs::types::Date;
+use livestock_rs::units::{MassRate, UnitSystem};
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+#[command(
+    arg_required_else_help(true),
+    about = "Project the ship date and days on feed to reach a target weight.",
+    long_about = "
+        Project the ship date and days on feed to reach a target weight.
+
+        The days on feed are the weight still to gain divided by the average daily gain (ADG),
+        rounded up to whole days:
+
+        days_on_feed = (target_weight - current_weight) / ADG
+
+        where:
+
+        - `target_weight` is the weight to ship at (in kg, or lb with `--units imperial`).
+        - `current_weight` is the weight on the start date (in kg, or lb with `--units imperial`).
+        - `ADG` is the expected average daily gain (in kg/day, or lb/day with `--units imperial`).
+
+        With a feed conversion ratio (FCR), the total feed required is also estimated:
+
+        feed_required = (target_weight - current_weight) * FCR
+
+        # Examples
+
+        Project when an 850 lb steer gaining 3.5 lb/day reaches 1350 lb, with a range for slower
+        and faster gains:
+
+        ```
+        stocktools --units imperial project -c 850 -t 1350 --start-date 2026-10-01 -a 3.5 --adg-low 3.0 --adg-high 4.0 --fcr 6
+        ```
+
+        Use the ADG fitted through a CSV file of `date,weight` weigh-ins instead, with its
+        confidence interval as the range:
+
+        ```
+        stocktools project -c 390 -t 615 --start-date 2026-10-01 --series weights.csv
+        ```
+
+        A short or noisy series can have a low ADG at or below zero; the low scenario is then
+        reported as never reaching the target.
+    "
+)]
+pub struct ProjectSubcommand {
+    #[arg(
+        help = "Current weight of livestock (in kg, or lb with --units imperial)",
+        long,
+        short = 'c'
+    )]
+    current_weight: f64,
+    #[arg(
+        help = "Target finish weight of livestock (in kg, or lb with --units imperial)",
+        long,
+        short = 't'
+    )]
+    target_weight: f64,
+    #[arg(help = "Date the current weight was taken (YYYY-MM-DD)", long)]
+    start_date: Date,
+    #[arg(
+        help = "Expected ADG (in kg/day, or lb/day with --units imperial)",
+        long,
+        short = 'a',
+        required_unless_present = "series"
+    )]
+    adg: Option<f64>,
+    #[arg(help = "ADG of the slow scenario", long, requires_all = ["adg", "adg_high"])]
+    adg_low: Option<f64>,
+    #[arg(help = "ADG of the fast scenario", long, requires_all = ["adg", "adg_low"])]
+    adg_high: Option<f64>,
+    #[arg(
+        help = "CSV file of date,weight weigh-ins to fit the expected ADG and its range from",
+        long,
+        conflicts_with = "adg"
+    )]
+    series: Option<PathBuf>,
+    #[arg(
+        help = "Confidence level of the ADG range for --series",
+        long,
+        default_value_t = 0.95
+    )]
+    confidence: f64,
+    #[arg(
+        help = "Feed conversion ratio, to estimate the total feed required",
+        long
+    )]
+    fcr: Option<f64>,
+}
+
+impl ProjectSubcommand {
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
+        let unit = units.mass_unit();
+        let rate = |adg| MassRate::per_day(adg, unit);
+
+        let adgs = match (&self.series, self.adg, self.adg_low, self.adg_high) {
+            (Some(path), _, _, _) => {
+                let series = calculate_series_adg(&read_weigh_ins(path, units)?, self.confidence)
+                    .context("Failed to calculate ADG over the weigh-in series.")?;
+                let (low, high) = series.confidence_interval;
+                Some((low, series.adg, high))
+            }
+            (None, Some(adg), Some(low), Some(high)) => Some((rate(low), rate(adg), rate(high))),
+            (None, Some(_), _, _) => None,
+            _ => bail!("Either an ADG or a weigh-in series must be provided."),
+        };
+
+        let current_weight = units.mass(self.current_weight);
+        let target_weight = units.mass(self.target_weight);
+        let projections = match adgs {
+            Some(adgs) => {
+                let range = project_market_date_range(
+                    current_weight,
+                    target_weight,
+                    self.start_date,
+                    adgs,
+                    self.fcr,
+                )
+                .context("Failed to project market date.")?;
+                vec![
+                    ("Low", adgs.0, range.low),
+                    ("Expected", adgs.1, Some(range.expected)),
+                    ("High", adgs.2, Some(range.high)),
+                ]
+            }
+            None => {
+                let projection = project_market_date(
+                    current_weight,
+                    target_weight,
+                    self.start_date,
+                    rate(self.adg.unwrap_or_default()),
+                    self.fcr,
+                )
+                .context("Failed to project market date.")?;
+                vec![("Expected", projection.adg, Some(projection))]
+            }
+        };
+
+        println!(" ");
+        println!(
+            "Gain to target: {:.1} {}",
+            (target_weight - current_weight).in_unit(unit),
+            unit.symbol()
+        );
+        let feed_required = projections
+            .iter()
+            .find_map(|(_, _, projection)| projection.and_then(|p| p.feed_required));
+        if let Some(feed_required) = feed_required {
+            println!(
+                "Feed required: {:.0} {}",
+                feed_required.in_unit(unit),
+                unit.symbol()
+            );
+        }
+        println!(" ");
+        println!(
+            "{:<10} {:>14} {:>14} {:>12}",
+            "Scenario",
+            format!("ADG ({}/day)", unit.symbol()),
+            "Days on Feed",
+            "Ship Date"
+        );
+        for (scenario, adg, projection) in projections {
+            match projection {
+                Some(projection) => print_projection(scenario, &projection, units),
+                None => println!(
+                    "{:<10} {:>14.2} {:>14} {:>12}",
+                    scenario,
+                    adg.in_unit(units.mass_unit()),
+                    "Never",
+                    "Not reached"
+                ),
+            }
+        }
+        println!(" ");
+
+        Ok(())
+    }
+}
+
+fn print_projection(scenario: &str, projection: &Projection, units: UnitSystem) {
+    println!(
+        "{:<10} {:>14.2} {:>14.0} {:>12}",
+        scenario,
+        projection.adg.in_unit(units.mass_unit()),
+        projection.days_on_feed.as_days(),
+        projection.ship_date
+    );
+}
@@ -0,0 +1,234 @@
+use crate::csv::read_rows;
+use crate::feeds::load_library;
+use crate::requirements::{energy_summary, AnimalArgs};
+use anyhow::{anyhow, ensure, Context, Result};
+use clap::{Parser, ValueEnum};
+use livestock_rs::calculators::feed::library::FeedLibrary;
+use livestock_rs::calculators::feed::ration::{
+    formulate_least_cost_ration, Nutrient, NutrientBound, RationFeed, RationTargets,
+};
+use livestock_rs::units::UnitSystem;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser, Debug)]
+#[command(
+    arg_required_else_help(true),
+    about = "Formulate the least-cost ration that meets an animal's requirements.",
+    long_about = "
+        Formulate the least-cost ration that meets an animal's requirements.
+
+        The animal's daily requirements for energy, protein, calcium and phosphorus are
+        calculated from its weight, target average daily gain (ADG), days pregnant and milk yield,
+        along with its predicted dry matter intake (see `stocktools requirements`). The share of
+        each feed in the ration is then chosen, by linear programming, to meet those requirements
+        at the lowest cost:
+
+        minimize   Σ price_i × as_fed_i
+        subject to Σ nutrient_i × share_i >= requirement, for each nutrient
+                   min_inclusion_i <= share_i <= max_inclusion_i
+                   Σ share_i = 100%
+
+        Feeds are listed in a CSV file of `name,price,min_inclusion,max_inclusion` rows, where:
+
+        - `name` is a feed in the feed library (see `stocktools feeds`).
+        - `price` is the price per kg as fed (or per lb with `--units imperial`).
+        - `min_inclusion` and `max_inclusion` are optional limits on the feed's share of the
+          ration's dry matter, in %.
+
+        Energy is met as NEm and NEg for cattle, and through TDN for species that use
+        metabolizable or digestible energy.
+
+        If no ration meets every constraint, the constraints that cannot be met are listed.
+
+        # Examples
+
+        Formulate a ration for a 300 kg steer gaining 1.3 kg/day:
+
+        ```
+        stocktools ration --feeds feeds.csv --animal cattle -w 300 --adg 1.3
+        ```
+
+        Formulate a ration for a 550 kg cow, 240 days pregnant:
+
+        ```
+        stocktools ration --feeds feeds.csv --animal cattle -w 550 --days-pregnant 240
+        ```
+
+        Also keep NDF at or below 35% of the dry matter, using this year's forage tests:
+
+        ```
+        stocktools ration --feeds feeds.csv --animal cattle -w 300 --adg 1.3 --max ndf=35 --feed-file forage-tests.csv
+        ```
+    "
+)]
+pub struct RationSubcommand {
+    #[arg(help = "CSV file of name,price,min_inclusion,max_inclusion rows", long)]
+    feeds: PathBuf,
+    #[command(flatten)]
+    animal: AnimalArgs,
+    #[arg(help = "Extra minimum nutrient concentration, e.g. tdn=70", long, value_parser = parse_bound)]
+    min: Vec<(Nutrient, f64)>,
+    #[arg(help = "Extra maximum nutrient concentration, e.g. ndf=35", long, value_parser = parse_bound)]
+    max: Vec<(Nutrient, f64)>,
+    #[arg(
+        help = "CSV or TOML file of feeds to add to the library",
+        long = "feed-file"
+    )]
+    feed_files: Vec<PathBuf>,
+}
+
+impl RationSubcommand {
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
+        let library = load_library(&self.feed_files)?;
+        let feeds = read_feeds(&self.feeds, &library, units)?;
+
+        let requirements = self.animal.requirements(units)?;
+
+        let mut targets = RationTargets::from_requirements(&requirements);
+        targets.bounds.extend(
+            self.min
+                .iter()
+                .map(|(nutrient, min)| NutrientBound::at_least(*nutrient, *min)),
+        );
+        targets.bounds.extend(
+            self.max
+                .iter()
+                .map(|(nutrient, max)| NutrientBound::at_most(*nutrient, *max)),
+        );
+        let ration =
+            formulate_least_cost_ration(&feeds, &targets).context("Failed to formulate ration.")?;
+
+        let unit = units.mass_unit();
+        let rate = format!("{}/day", unit.symbol());
+        println!(" ");
+        println!(
+            "Requirements: {:.2} {} DM, {}, {:.0} g/day CP, {:.0} g/day Ca, {:.0} g/day P",
+            requirements.dmi.in_unit(unit),
+            rate,
+            energy_summary(&requirements.energy),
+            requirements.crude_protein.as_kilograms_per_day() * 1000.0,
+            requirements.calcium.as_kilograms_per_day() * 1000.0,
+            requirements.phosphorus.as_kilograms_per_day() * 1000.0
+        );
+        println!(" ");
+        println!(
+            "{:<22} {:>8} {:>16} {:>16} {:>10}",
+            "Feed",
+            "% of DM",
+            format!("DM ({})", rate),
+            format!("As Fed ({})", rate),
+            "Cost/day"
+        );
+        for ingredient in &ration.ingredients {
+            println!(
+                "{:<22} {:>8.1} {:>16.2} {:>16.2} {:>10.2}",
+                ingredient.name,
+                ingredient.inclusion,
+                ingredient.dry_matter.in_unit(unit),
+                ingredient.as_fed.in_unit(unit),
+                ingredient.cost
+            );
+        }
+        println!(
+            "{:<22} {:>8.1} {:>16.2} {:>16.2} {:>10.2}",
+            "Total",
+            100.0,
+            ration.dmi.in_unit(unit),
+            ration.as_fed.in_unit(unit),
+            ration.cost_per_day
+        );
+        println!(" ");
+        println!(
+            "{:<24} {:>10} {:>10} {:>10}",
+            "Nutrient", "Supplied", "Minimum", "Maximum"
+        );
+        for (nutrient, supplied) in &ration.nutrients {
+            let limit = |limit: fn(&NutrientBound) -> Option<f64>| {
+                targets
+                    .bounds
+                    .iter()
+                    .filter(|bound| bound.nutrient == *nutrient)
+                    .find_map(limit)
+                    .map_or("-".to_string(), |value| format!("{:.2}", value))
+            };
+            println!(
+                "{:<24} {:>10.2} {:>10} {:>10}",
+                format!("{} ({})", nutrient.name(), nutrient.unit()),
+                supplied,
+                limit(|bound| bound.min),
+                limit(|bound| bound.max)
+            );
+        }
+        println!(" ");
+
+        Ok(())
+    }
+}
+
+/// Parses a nutrient bound of the form `nutrient=value`, e.g. `ndf=35`.
+fn parse_bound(value: &str) -> Result<(Nutrient, f64)> {
+    let (nutrient, concentration) = value
+        .split_once('=')
+        .ok_or_else(|| anyhow!("Expected nutrient=value, e.g. ndf=35."))?;
+    let nutrient = Nutrient::from_str(nutrient.trim(), true).map_err(|err| anyhow!(err))?;
+    let concentration = concentration
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid concentration for {}.", nutrient.name()))?;
+    Ok((nutrient, concentration))
+}
+
+/// Reads `name,price,min_inclusion,max_inclusion` rows from a CSV file, skipping blank lines,
+/// `#` comments and a header row such as `name,price,min_inclusion,max_inclusion`. The inclusion
+/// limits may be left empty.
+fn read_feeds(path: &Path, library: &FeedLibrary, units: UnitSystem) -> Result<Vec<RationFeed>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read feeds from {}.", path.display()))?;
+    // Prices are per unit of mass as fed, so convert them to per kg.
+    let kilograms_per_unit = units.mass(1.0).as_kilograms();
+
+    let is_header = |fields: &[&str]| {
+        fields
+            .get(1)
+            .is_none_or(|field| field.parse::<f64>().is_err())
+    };
+    let mut feeds = Vec::new();
+    for row in read_rows(&contents, is_header) {
+        let (fields, line) = (&row.fields, row.line);
+        ensure!(
+            (2..=4).contains(&fields.len()),
+            "Line {} must have 2 to 4 fields, found {}.",
+            line,
+            fields.len()
+        );
+        let price: f64 = fields[1]
+            .parse()
+            .with_context(|| format!("Invalid price on line {}.", line))?;
+        let limit = |position: usize| -> Result<Option<f64>> {
+            match fields.get(position).filter(|field| !field.is_empty()) {
+                Some(field) => {
+                    Ok(Some(field.parse().with_context(|| {
+                        format!("Invalid inclusion limit on line {}.", line)
+                    })?))
+                }
+                None => Ok(None),
+            }
+        };
+        let feed = library.get(fields[0]).ok_or_else(|| {
+            anyhow!(
+                "\"{}\" on line {} is not in the feed library. Run `stocktools feeds` to list feeds.",
+                fields[0],
+                line
+            )
+        })?;
+
+        feeds.push(RationFeed {
+            min_inclusion: limit(2)?,
+            max_inclusion: limit(3)?,
+            ..RationFeed::new(feed.clone(), price / kilograms_per_unit)
+        });
+    }
+
+    Ok(feeds)
+}
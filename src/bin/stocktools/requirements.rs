@@ -0,0 +1,274 @@
+use crate::csv::read_rows;
+use crate::feeds::load_library;
+use anyhow::{anyhow, ensure, Context, Result};
+use clap::{Args, Parser};
+use livestock_rs::calculators::feed::library::{Feed, FeedLibrary};
+use livestock_rs::calculators::feed::requirements::{
+    calculate_requirements, compare_ration, EnergyRequirement, RequirementInputs, Requirements,
+};
+use livestock_rs::types::LivestockType;
+use livestock_rs::units::{Duration, MassRate, UnitSystem};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser, Debug)]
+#[command(
+    arg_required_else_help(true),
+    about = "Calculate daily nutrient requirements and check a ration against them.",
+    long_about = "
+        Calculate daily nutrient requirements and check a ration against them.
+
+        Requirements for energy, protein, calcium and phosphorus are the sum of the needs for
+        maintenance, gain, pregnancy and milk:
+
+        - Cattle use net energy (NASEM, 2016; NRC, 1996): NEm for maintenance, pregnancy and
+          milk, and NEg for gain. The diet energy density is found at which the predicted dry
+          matter intake supplies both, and metabolizable protein is given as well as crude
+          protein. `--calf-birth-weight` sets the expected calf, 40 kg if not given.
+        - Sheep (NRC, 1985) use metabolizable energy (ME), from the tables for 50 to 90 kg ewes
+          and 30 to 50 kg finishing lambs. The milk yield and ADG only select the table.
+        - Goats (NRC, 1981) use ME, from the tables for 10 to 100 kg.
+        - Growing pigs of 3 to 120 kg (NRC, 1998) use digestible energy (DE), at the intake of
+          pigs fed ad libitum; the ADG is not used. Gestating sows of 125 to 200 kg at
+          breeding, and lactating sows giving 6 to 10 kg of milk, use the NRC (1998) sow tables.
+        - Horses use DE: NRC (2007) for maintenance, growth and lactation, and NRC (1989) for
+          the last three months of pregnancy. Growing horses need `--age`, from 4 months.
+
+        Pregnancy needs grow with the fetus, so they mostly fall in the last trimester.
+
+        With `--ration`, a CSV file of `name,amount` rows gives the amount of each feed fed
+        as fed per head per day (in kg, or lb with `--units imperial`). Each requirement is then
+        compared with what the ration supplies, and the surplus or deficit shown. The energy of
+        a ration is estimated from its feeds' TDN for species that use ME or DE.
+
+        # Examples
+
+        Requirements of a 550 kg beef cow, 240 days pregnant:
+
+        ```
+        stocktools requirements -t cattle -w 550 --days-pregnant 240
+        ```
+
+        Check a ration for a 50 kg doe giving 2 kg of milk a day:
+
+        ```
+        stocktools requirements -t goat -w 50 --milk-yield 2 --ration doe-ration.csv
+        ```
+    "
+)]
+pub struct RequirementsSubcommand {
+    #[command(flatten)]
+    animal: AnimalArgs,
+    #[arg(
+        help = "CSV file of name,amount rows, the feed fed as fed per head per day",
+        long
+    )]
+    ration: Option<PathBuf>,
+    #[arg(
+        help = "CSV or TOML file of feeds to add to the library",
+        long = "feed-file"
+    )]
+    feed_files: Vec<PathBuf>,
+}
+
+/// Arguments describing an animal, for subcommands that calculate its requirements.
+#[derive(Args, Debug)]
+pub struct AnimalArgs {
+    #[arg(
+        long,
+        help = "The type of livestock.",
+        short = 't',
+        visible_alias = "animal",
+        ignore_case = true
+    )]
+    livestock_type: LivestockType,
+    #[arg(
+        help = "Body weight (in kg, or lb with --units imperial)",
+        long,
+        short = 'w'
+    )]
+    body_weight: f64,
+    #[arg(
+        help = "Target ADG (in kg/day, or lb/day with --units imperial)",
+        long,
+        short = 'a',
+        default_value_t = 0.0
+    )]
+    adg: f64,
+    #[arg(help = "Days since conception, for pregnant females", long)]
+    days_pregnant: Option<u32>,
+    #[arg(
+        help = "Daily milk yield (in kg/day, or lb/day with --units imperial)",
+        long
+    )]
+    milk_yield: Option<f64>,
+    #[arg(
+        help = "Expected calf birth weight, for pregnant cattle (in kg, or lb with --units imperial)",
+        long
+    )]
+    calf_birth_weight: Option<f64>,
+    #[arg(help = "Age in months, for growing horses", long)]
+    age: Option<f64>,
+}
+
+impl AnimalArgs {
+    /// The animal's daily requirements.
+    pub fn requirements(&self, units: UnitSystem) -> Result<Requirements> {
+        let unit = units.mass_unit();
+        let inputs = RequirementInputs {
+            days_pregnant: self.days_pregnant,
+            milk_yield: self
+                .milk_yield
+                .map(|milk_yield| MassRate::per_day(milk_yield, unit)),
+            calf_birth_weight: self.calf_birth_weight.map(|weight| units.mass(weight)),
+            // Months of 30.4 days, as the NRC (2007) horse growth equations use.
+            age: self.age.map(|months| Duration::days(months * 30.4)),
+            ..RequirementInputs::new(
+                units.mass(self.body_weight),
+                MassRate::per_day(self.adg, unit),
+            )
+        };
+        calculate_requirements(self.livestock_type, &inputs)
+            .context("Failed to calculate requirements.")
+    }
+}
+
+impl RequirementsSubcommand {
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
+        let requirements = self.animal.requirements(units)?;
+        let ration = match &self.ration {
+            Some(path) => Some(read_ration(path, &load_library(&self.feed_files)?, units)?),
+            None => None,
+        };
+
+        let unit = units.mass_unit();
+        let grams = |amount: MassRate| amount.as_kilograms_per_day() * 1000.0;
+        println!(" ");
+        println!(
+            "Predicted DMI: {:.2} {}/day on a diet of {}",
+            requirements.dmi.in_unit(unit),
+            unit.symbol(),
+            diet_energy_summary(&requirements.energy)
+        );
+        println!("Energy: {}", energy_summary(&requirements.energy));
+        if let Some(metabolizable_protein) = requirements.metabolizable_protein {
+            println!(
+                "Metabolizable Protein: {:.0} g/day",
+                grams(metabolizable_protein)
+            );
+        }
+        println!(
+            "Crude Protein: {:.0} g/day",
+            grams(requirements.crude_protein)
+        );
+        println!("Calcium: {:.1} g/day", grams(requirements.calcium));
+        println!("Phosphorus: {:.1} g/day", grams(requirements.phosphorus));
+
+        if let Some(ration) = ration {
+            let dry_matter: f64 = ration
+                .iter()
+                .map(|(feed, as_fed)| feed.to_dry_matter(*as_fed).in_unit(unit))
+                .sum();
+            let balances =
+                compare_ration(&requirements, &ration).context("Failed to compare ration.")?;
+
+            println!(" ");
+            println!(
+                "Ration: {:.2} {}/day DM ({:.0}% of predicted DMI)",
+                dry_matter,
+                unit.symbol(),
+                dry_matter / requirements.dmi.in_unit(unit) * 100.0
+            );
+            println!(" ");
+            println!(
+                "{:<24} {:>10} {:>10} {:>10} {:>8}",
+                "Nutrient", "Required", "Supplied", "Balance", "% Req"
+            );
+            for balance in &balances {
+                println!(
+                    "{:<24} {:>10.2} {:>10.2} {:>+10.2} {:>7.0}%{}",
+                    format!("{} ({})", balance.name, balance.unit),
+                    balance.required,
+                    balance.supplied,
+                    balance.surplus(),
+                    balance.percent_of_requirement(),
+                    if balance.is_met() { "" } else { "  deficit" }
+                );
+            }
+        }
+        println!(" ");
+
+        Ok(())
+    }
+}
+
+/// The daily energy requirement, e.g. `5.38 Mcal/day NEm, 4.32 Mcal/day NEg`.
+pub fn energy_summary(energy: &EnergyRequirement) -> String {
+    match energy {
+        EnergyRequirement::Net {
+            maintenance, gain, ..
+        } => {
+            format!("{:.2} Mcal/day NEm, {:.2} Mcal/day NEg", maintenance, gain)
+        }
+        EnergyRequirement::Metabolizable { daily, .. } => format!("{:.2} Mcal/day ME", daily),
+        EnergyRequirement::Digestible { daily, .. } => format!("{:.2} Mcal/day DE", daily),
+    }
+}
+
+/// The diet energy density needed, e.g. `1.78 Mcal/kg NEm and 1.15 Mcal/kg NEg`.
+fn diet_energy_summary(energy: &EnergyRequirement) -> String {
+    match energy {
+        EnergyRequirement::Net {
+            diet_nem, diet_neg, ..
+        } => {
+            format!(
+                "{:.2} Mcal/kg NEm and {:.2} Mcal/kg NEg",
+                diet_nem, diet_neg
+            )
+        }
+        EnergyRequirement::Metabolizable { diet, .. } => format!("{:.2} Mcal/kg ME", diet),
+        EnergyRequirement::Digestible { diet, .. } => format!("{:.2} Mcal/kg DE", diet),
+    }
+}
+
+/// Reads `name,amount` rows from a CSV file, skipping blank lines, `#` comments and a header
+/// row such as `name,amount`.
+fn read_ration(
+    path: &Path,
+    library: &FeedLibrary,
+    units: UnitSystem,
+) -> Result<Vec<(Feed, MassRate)>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read ration from {}.", path.display()))?;
+    let unit = units.mass_unit();
+
+    let is_header = |fields: &[&str]| {
+        fields
+            .get(1)
+            .is_none_or(|field| field.parse::<f64>().is_err())
+    };
+    let mut ration = Vec::new();
+    for row in read_rows(&contents, is_header) {
+        let fields = &row.fields;
+        ensure!(
+            fields.len() == 2,
+            "Line {} must have 2 fields, found {}.",
+            row.line,
+            fields.len()
+        );
+        let amount: f64 = fields[1]
+            .parse()
+            .with_context(|| format!("Invalid amount on line {}.", row.line))?;
+        let feed = library.get(fields[0]).ok_or_else(|| {
+            anyhow!(
+                "\"{}\" on line {} is not in the feed library. Run `stocktools feeds` to list feeds.",
+                fields[0],
+                row.line
+            )
+        })?;
+
+        ration.push((feed.clone(), MassRate::per_day(amount, unit)));
+    }
+
+    Ok(ration)
+}
@@ -0,0 +1,124 @@
+use crate::csv::read_rows;
+use anyhow::{ensure, Context, Result};
+use clap::Parser;
+use livestock_rs::calculators::feed::rfi::{calculate_rfi, RfiRecord};
+use livestock_rs::units::{MassRate, UnitSystem};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser, Debug)]
+#[command(
+    arg_required_else_help(true),
+    about = "Calculate Residual Feed Intake (RFI) for a test group.",
+    long_about = "
+        Calculate Residual Feed Intake (RFI) for a test group.
+
+        Residual Feed Intake (RFI) is the difference between an animal's actual dry matter intake
+        and the intake expected for its size and gain. Unlike FCR, it does not penalize large,
+        fast-growing animals. The expected intake is fitted across the group:
+
+        DMI = b0 + b1 * MMWT + b2 * ADG + RFI
+
+        where:
+
+        - `DMI` is the average daily dry matter intake over the test.
+        - `MMWT` is the mid-test metabolic body weight, `mid_test_weight^0.75` in kg.
+        - `ADG` is the average daily gain over the test.
+
+        Animals with a negative RFI ate less than expected and are more efficient.
+
+        # Example
+
+        Calculate RFI for a group from a CSV file of `id,dmi,mid_test_weight,adg` rows, with
+        intake and gain in kg/day (or lb/day with `--units imperial`) and weight in kg (or lb):
+
+        ```
+        stocktools rfi test-group.csv
+        ```
+    "
+)]
+pub struct RfiSubcommand {
+    #[arg(help = "CSV file of id,dmi,mid_test_weight,adg rows")]
+    path: PathBuf,
+}
+
+impl RfiSubcommand {
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
+        let records = read_records(&self.path, units)?;
+        let group = calculate_rfi(&records).context("Failed to calculate RFI.")?;
+
+        let unit = units.mass_unit();
+        let rate = format!("{}/day", unit.symbol());
+        println!(" ");
+        println!(
+            "Expected DMI = {:.3} + {:.4} × MMWT + {:.3} × ADG (kg/day, R² {:.3})",
+            group.intercept,
+            group.metabolic_weight_coefficient,
+            group.adg_coefficient,
+            group.r_squared
+        );
+        println!(" ");
+        println!(
+            "{:>4} {:<12} {:>12} {:>12} {:>12}",
+            "Rank",
+            "ID",
+            format!("DMI ({})", rate),
+            "Expected",
+            "RFI"
+        );
+
+        let mut animals: Vec<_> = group.animals.iter().zip(&records).collect();
+        animals.sort_by_key(|(animal, _)| animal.rank);
+        for (animal, record) in animals {
+            println!(
+                "{:>4} {:<12} {:>12.2} {:>12.2} {:>+12.2}",
+                animal.rank,
+                animal.id,
+                record.dmi.in_unit(unit),
+                animal.expected_dmi.in_unit(unit),
+                animal.rfi.in_unit(unit)
+            );
+        }
+        println!(" ");
+
+        Ok(())
+    }
+}
+
+/// Reads `id,dmi,mid_test_weight,adg` rows from a CSV file, skipping blank lines, `#` comments
+/// and a header row such as `id,dmi,mid_test_weight,adg`.
+fn read_records(path: &Path, units: UnitSystem) -> Result<Vec<RfiRecord>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read test records from {}.", path.display()))?;
+
+    let is_header = |fields: &[&str]| {
+        fields
+            .get(1)
+            .is_none_or(|field| field.parse::<f64>().is_err())
+    };
+    let mut records = Vec::new();
+    for row in read_rows(&contents, is_header) {
+        let fields = &row.fields;
+        ensure!(
+            fields.len() == 4,
+            "Line {} must have 4 fields, found {}.",
+            row.line,
+            fields.len()
+        );
+        let values = fields[1..]
+            .iter()
+            .map(|field| field.parse::<f64>())
+            .collect::<Result<Vec<f64>, _>>()
+            .with_context(|| format!("Invalid number on line {}.", row.line))?;
+
+        let unit = units.mass_unit();
+        records.push(RfiRecord {
+            id: fields[0].to_string(),
+            dmi: MassRate::per_day(values[0], unit),
+            mid_test_weight: units.mass(values[1]),
+            adg: MassRate::per_day(values[2], unit),
+        });
+    }
+
+    Ok(records)
+}
@@ -0,0 +1,207 @@
+use crate::csv::read_rows;
+use anyhow::{bail, ensure, Context, Result};
+use clap::Parser;
+use livestock_rs::calculators::growth::wda::calculate_wda;
+use livestock_rs::types::Date;
+use livestock_rs::units::{Mass, UnitSystem};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Parser, Debug)]
+#[command(
+    arg_required_else_help(true),
+    about = "Calculate Weight per Day of Age (WDA) for livestock.",
+    long_about = "
+        Calculate Weight per Day of Age (WDA) for livestock.
+
+        Weight per Day of Age (WDA) is the weight of an animal divided by its age in days on the
+        day it was weighed. It is often listed alongside ADG in sale catalogs.
+
+        The formula is:
+
+        WDA = (weight - birth_weight) / (weigh_date - birth_date)
+
+        where:
+
+        - `WDA` is the weight per day of age in the same unit as the weight.
+        - `weight` is the weight of the animal (in kg, or lb with `--units imperial`).
+        - `birth_weight` is the optional weight of the animal at birth, otherwise zero.
+        - `weigh_date` and `birth_date` are the days the animal was weighed and born.
+
+        # Examples
+
+        Calculate the WDA of a bull born on 2025-03-01 that weighed 1050 lb on 2026-02-14:
+
+        ```
+        stocktools --units imperial wda -w 1050 --birth-date 2025-03-01 --weigh-date 2026-02-14
+        ```
+
+        The result will be `3.00 lb/day`.
+
+        Calculate the WDA of every animal in a sale catalog from a CSV file of
+        `id,birth_date,weigh_date,weight[,birth_weight]` rows:
+
+        ```
+        stocktools wda --batch catalog.csv
+        ```
+    "
+)]
+pub struct WdaSubcommand {
+    #[arg(
+        help = "Weight of livestock (in kg, or lb with --units imperial)",
+        long,
+        short = 'w',
+        required_unless_present = "batch"
+    )]
+    weight: Option<f64>,
+    #[arg(
+        help = "Date the animal was born (YYYY-MM-DD)",
+        long,
+        required_unless_present = "batch"
+    )]
+    birth_date: Option<Date>,
+    #[arg(
+        help = "Date the animal was weighed (YYYY-MM-DD)",
+        long,
+        required_unless_present = "batch"
+    )]
+    weigh_date: Option<Date>,
+    #[arg(
+        help = "Weight of the animal at birth (in kg, or lb with --units imperial)",
+        long,
+        short = 'b'
+    )]
+    birth_weight: Option<f64>,
+    #[arg(
+        help = "CSV file of id,birth_date,weigh_date,weight[,birth_weight] rows",
+        long,
+        conflicts_with_all = ["weight", "birth_date", "weigh_date", "birth_weight"]
+    )]
+    batch: Option<PathBuf>,
+}
+
+impl WdaSubcommand {
+    pub fn run(&self, units: UnitSystem) -> Result<()> {
+        if let Some(path) = &self.batch {
+            return run_batch(path, units);
+        }
+
+        let (Some(weight), Some(birth_date), Some(weigh_date)) =
+            (self.weight, self.birth_date, self.weigh_date)
+        else {
+            bail!("A weight, birth date and weigh date must be provided.");
+        };
+        let wda = calculate_wda(
+            birth_date,
+            weigh_date,
+            units.mass(weight),
+            self.birth_weight.map(|weight| units.mass(weight)),
+        )
+        .context("Failed to calculate WDA.")?;
+
+        let unit = units.mass_unit();
+        println!(
+            "Weight per Day of Age (WDA): {:.2} {}/day",
+            wda.in_unit(unit),
+            unit.symbol()
+        );
+        Ok(())
+    }
+}
+
+/// An animal read from a batch file.
+#[derive(Debug)]
+struct Animal {
+    id: String,
+    birth_date: Date,
+    weigh_date: Date,
+    weight: Mass,
+    birth_weight: Option<Mass>,
+}
+
+fn run_batch(path: &Path, units: UnitSystem) -> Result<()> {
+    let animals = read_animals(path, units)?;
+    let unit = units.mass_unit();
+
+    println!(" ");
+    println!(
+        "{:<12} {:<10} {:<10} {:>12} {:>12}",
+        "ID",
+        "Born",
+        "Weighed",
+        format!("Weight ({})", unit.symbol()),
+        format!("WDA ({}/d)", unit.symbol())
+    );
+    for animal in animals {
+        let wda = calculate_wda(
+            animal.birth_date,
+            animal.weigh_date,
+            animal.weight,
+            animal.birth_weight,
+        )
+        .with_context(|| format!("Failed to calculate WDA for {}.", animal.id))?;
+        println!(
+            "{:<12} {:<10} {:<10} {:>12.1} {:>12.2}",
+            animal.id,
+            animal.birth_date,
+            animal.weigh_date,
+            animal.weight.in_unit(unit),
+            wda.in_unit(unit)
+        );
+    }
+    println!(" ");
+
+    Ok(())
+}
+
+/// Reads `id,birth_date,weigh_date,weight[,birth_weight]` rows from a CSV file, skipping blank
+/// lines, `#` comments and a header row such as `id,birth_date,...`.
+fn read_animals(path: &Path, units: UnitSystem) -> Result<Vec<Animal>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read animals from {}.", path.display()))?;
+
+    let is_header = |fields: &[&str]| {
+        fields
+            .get(1)
+            .is_none_or(|field| Date::from_str(field).is_err())
+    };
+    let mut animals = Vec::new();
+    for row in read_rows(&contents, is_header) {
+        let (fields, line) = (&row.fields, row.line);
+        ensure!(
+            fields.len() == 4 || fields.len() == 5,
+            "Line {} must have 4 or 5 fields, found {}.",
+            line,
+            fields.len()
+        );
+        let birth_date = Date::from_str(fields[1])
+            .with_context(|| format!("Invalid birth date on line {}.", line))?;
+        let weigh_date = Date::from_str(fields[2])
+            .with_context(|| format!("Invalid weigh date on line {}.", line))?;
+        let weight: f64 = fields[3]
+            .parse()
+            .with_context(|| format!("Invalid weight on line {}: \"{}\"", line, fields[3]))?;
+        let birth_weight = match fields.get(4) {
+            Some(weight) if !weight.is_empty() => Some(weight.parse().with_context(|| {
+                format!("Invalid birth weight on line {}: \"{}\"", line, weight)
+            })?),
+            _ => None,
+        };
+
+        animals.push(Animal {
+            id: fields[0].to_string(),
+            birth_date,
+            weigh_date,
+            weight: units.mass(weight),
+            birth_weight: birth_weight.map(|weight| units.mass(weight)),
+        });
+    }
+    ensure!(
+        !animals.is_empty(),
+        "No animals found in {}.",
+        path.display()
+    );
+
+    Ok(animals)
+}
@@ -1,28 +1,35 @@
+use super::fuzzy::{self, BreedMatch};
+use super::metadata::{self, BreedMetadata, Purpose, Region, SizeClass};
+use super::{Breed, BreedParseError};
+use crate::types::LivestockType;
 use inflector::Inflector;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
-/// An enum representing the different breeds of camel.
-///
-/// Initial data from: <https://breeds.okstate.edu/other-breeds-of-livestock/camels/>
-///
-/// # Examples
-/// ``` rust
-/// use livestock_rs::breeds::camel::CamelBreed;
-///
-/// let breed = CamelBreed::AfarDromedary;
-/// println!("{:?}", breed);
-/// ```
-#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub enum CamelBreed {
-    AfarDromedary,
-    AlxaBactrian,
-    ArvanaDromedary,
-    KalmykBactrian,
-    SomaliDromedary,
+breed_enum! {
+    /// An enum representing the different breeds of camel.
+    ///
+    /// Initial data from: <https://breeds.okstate.edu/other-breeds-of-livestock/camels/>
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::camel::CamelBreed;
+    ///
+    /// let breed = CamelBreed::AfarDromedary;
+    /// println!("{:?}", breed);
+    /// ```
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum CamelBreed {
+        AfarDromedary,
+        AlxaBactrian,
+        ArvanaDromedary,
+        KalmykBactrian,
+        SomaliDromedary,
+    }
 }
 
-impl ToString for CamelBreed {
+impl fmt::Display for CamelBreed {
     /// Converts the CamelBreed enum to a human readable string.
     ///
     /// # Examples
@@ -32,8 +39,8 @@ impl ToString for CamelBreed {
     /// let alxa = CamelBreed::AlxaBactrian;
     /// println!("{}", alxa.to_string());
     /// ```
-    fn to_string(&self) -> String {
-        format!("{:?}", self).to_title_case()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format!("{:?}", self).to_title_case())
     }
 }
 
@@ -47,24 +54,176 @@ impl ToString for CamelBreed {
 /// let breed = CamelBreed::from_str("Somali Dromedary").unwrap();
 /// println!("{:?}", breed);
 /// ```
-
 impl FromStr for CamelBreed {
-    type Err = std::io::Error;
+    type Err = BreedParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "afar dromedary" | "afar" => Ok(CamelBreed::AfarDromedary),
-            "alxa bactrian" | "alxa" => Ok(CamelBreed::AlxaBactrian),
-            "arvana dromedary" | "arvana" => Ok(CamelBreed::ArvanaDromedary),
-            "kalmyk bactrian" | "kalmyk" => Ok(CamelBreed::KalmykBactrian),
-            "somali dromedary" | "somali" => Ok(CamelBreed::SomaliDromedary),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Invalid camel breed",
-            )),
+            "afar dromedary" => Ok(CamelBreed::AfarDromedary),
+            "alxa bactrian" => Ok(CamelBreed::AlxaBactrian),
+            "arvana dromedary" => Ok(CamelBreed::ArvanaDromedary),
+            "kalmyk bactrian" => Ok(CamelBreed::KalmykBactrian),
+            "somali dromedary" => Ok(CamelBreed::SomaliDromedary),
+            _ => fuzzy::resolve(VARIANTS, s),
         }
     }
 }
 
+impl CamelBreed {
+    /// Returns every camel breed, in declaration order.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::camel::CamelBreed;
+    ///
+    /// let breeds = CamelBreed::all();
+    /// assert_eq!(breeds.len(), CamelBreed::count());
+    /// ```
+    pub fn all() -> &'static [CamelBreed] {
+        VARIANTS
+    }
+
+    /// Returns an iterator over every camel breed, in declaration order.
+    pub fn iter() -> impl Iterator<Item = CamelBreed> {
+        VARIANTS.iter().copied()
+    }
+
+    /// Returns the number of camel breeds.
+    pub fn count() -> usize {
+        VARIANTS.len()
+    }
+
+    /// Returns the camel breeds whose names closely match the query, best match first.
+    ///
+    /// Case, diacritics and punctuation are ignored, and small typos are tolerated.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::camel::CamelBreed;
+    ///
+    /// let matches = CamelBreed::fuzzy_search("Somaly Dromedary");
+    /// assert_eq!(matches[0].breed, CamelBreed::SomaliDromedary);
+    /// ```
+    pub fn fuzzy_search(query: &str) -> Vec<BreedMatch<CamelBreed>> {
+        fuzzy::search(VARIANTS, query)
+    }
+
+    /// Returns the camel breeds raised primarily for the given purpose.
+    ///
+    /// Metadata has only been compiled for some camel breeds, those returned by
+    /// [`CamelBreed::with_metadata`], so other breeds are left out even if they would match.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::camel::CamelBreed;
+    /// use livestock_rs::breeds::metadata::Purpose;
+    ///
+    /// let breeds = CamelBreed::by_purpose(Purpose::Dairy);
+    /// assert!(breeds.contains(&CamelBreed::ArvanaDromedary));
+    /// ```
+    pub fn by_purpose(purpose: Purpose) -> Vec<CamelBreed> {
+        metadata::filter(METADATA, |metadata| metadata.purpose == purpose)
+    }
+
+    /// Returns the camel breeds that originated in the given region.
+    ///
+    /// Metadata has only been compiled for some camel breeds, those returned by
+    /// [`CamelBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_region(region: Region) -> Vec<CamelBreed> {
+        metadata::filter(METADATA, |metadata| metadata.region == region)
+    }
+
+    /// Returns the camel breeds of the given size class.
+    ///
+    /// Metadata has only been compiled for some camel breeds, those returned by
+    /// [`CamelBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_size_class(size_class: SizeClass) -> Vec<CamelBreed> {
+        metadata::filter(METADATA, |metadata| metadata.size_class == size_class)
+    }
+
+    /// Returns the camel breeds that have metadata.
+    ///
+    /// Metadata covers the better documented breeds first, so this is usually a subset of
+    /// [`CamelBreed::all`].
+    pub fn with_metadata() -> Vec<CamelBreed> {
+        METADATA.iter().map(|(breed, _)| *breed).collect()
+    }
+}
+
+impl Breed for CamelBreed {
+    fn species(&self) -> LivestockType {
+        LivestockType::Camel
+    }
+
+    fn metadata(&self) -> Option<BreedMetadata> {
+        metadata::lookup(METADATA, self)
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        ALIASES
+            .iter()
+            .filter(|(breed, _)| breed == self)
+            .map(|(_, alias)| *alias)
+            .collect()
+    }
+}
+
+/// Metadata for the camel breeds with well documented characteristics.
+const METADATA: &[(CamelBreed, BreedMetadata)] = &[
+    (
+        CamelBreed::AfarDromedary,
+        BreedMetadata::new(
+            "Ethiopia",
+            Region::Africa,
+            Purpose::Pack,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        CamelBreed::AlxaBactrian,
+        BreedMetadata::new("China", Region::Asia, Purpose::Dual, SizeClass::Large, None),
+    ),
+    (
+        CamelBreed::ArvanaDromedary,
+        BreedMetadata::new(
+            "Turkmenistan",
+            Region::Asia,
+            Purpose::Dairy,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        CamelBreed::KalmykBactrian,
+        BreedMetadata::new(
+            "Russia",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Heavy,
+            None,
+        ),
+    ),
+    (
+        CamelBreed::SomaliDromedary,
+        BreedMetadata::new(
+            "Somalia",
+            Region::Africa,
+            Purpose::Dairy,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+];
+
+/// Alternative names a camel breed is known by, in addition to its canonical name.
+const ALIASES: &[(CamelBreed, &str)] = &[
+    (CamelBreed::AfarDromedary, "afar"),
+    (CamelBreed::AlxaBactrian, "alxa"),
+    (CamelBreed::ArvanaDromedary, "arvana"),
+    (CamelBreed::KalmykBactrian, "kalmyk"),
+    (CamelBreed::SomaliDromedary, "somali"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,7 +254,49 @@ mod tests {
         ];
 
         for (breed, expected) in breeds.iter() {
-            assert_eq!(CamelBreed::from_str(*breed).unwrap(), *expected);
+            assert_eq!(CamelBreed::from_str(breed).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = CamelBreed::AfarDromedary.metadata().unwrap();
+        assert_eq!(metadata.origin, "Ethiopia");
+        assert_eq!(metadata.purpose, Purpose::Pack);
+        assert_eq!(metadata.size_class, SizeClass::Medium);
+
+        for breed in CamelBreed::by_purpose(Purpose::Dairy) {
+            assert_eq!(breed.metadata().unwrap().purpose, Purpose::Dairy);
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let matches = CamelBreed::fuzzy_search("Somaly Dromedary");
+        assert_eq!(matches[0].breed, CamelBreed::SomaliDromedary);
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_from_str_suggestions() {
+        let err = CamelBreed::from_str("Somaly Dromedary").unwrap_err();
+        assert_eq!(err.suggestions[0], CamelBreed::SomaliDromedary.to_string());
+    }
+
+    #[test]
+    fn test_variant_names_are_unique() {
+        for breed in CamelBreed::iter() {
+            assert_eq!(CamelBreed::from_str(&breed.to_string()).unwrap(), breed);
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        // Variants are numbered in declaration order, so each one is listed at its own index.
+        for (index, breed) in CamelBreed::iter().enumerate() {
+            assert_eq!(breed as usize, index);
         }
     }
 }
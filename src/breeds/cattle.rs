@@ -1,294 +1,301 @@
+use super::fuzzy::{self, BreedMatch};
+use super::metadata::{self, BreedMetadata, Horns, Purpose, Region, SizeClass};
+use super::{Breed, BreedParseError};
+use crate::types::LivestockType;
 use inflector::Inflector;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
-/// An enum representing the different breeds of cattle.
-///
-/// Initial data from: <https://breeds.okstate.edu/cattle/>
-///
-/// # Examples
-/// ``` rust
-/// use livestock_rs::breeds::cattle::CattleBreed;
-///
-/// let breed = CattleBreed::Angus;
-/// println!("{:?}", breed);
-/// ```
-#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub enum CattleBreed {
-    Africander,
-    Akaushi,
-    Alberes,
-    Alentejana,
-    Allmogekor,
-    AmericanBreed,
-    AmericanBraford,
-    AmericanWhitePark,
-    Amerifax,
-    AmritMahal,
-    AnatolianBlack,
-    AndalusianBlack,
-    AndalusianGrey,
-    Angeln,
-    Angus,
-    Ankole,
-    AnkoleWatusi,
-    ArgentineCriollo,
-    AsturianMountain,
-    AsturianValley,
-    Aubrac,
-    AulieAta,
-    AustralianBraford,
-    AustralianFriesianSahiwal,
-    AustralianLowline,
-    AustralianMilkingZebu,
-    Ayrshire,
-    Azaouak,
-    Bachaur,
-    Baladi,
-    BaltataRomaneasca,
-    Barka,
-    Barzona,
-    Bazadais,
-    Bearnais,
-    Beefalo,
-    Beefmaker,
-    Beefmaster,
-    BelarusRed,
-    BelgianBlue,
-    BelgianRed,
-    BelmontAdaptaur,
-    BelmontRed,
-    BeltedGalloway,
-    Bengali,
-    Berrendas,
-    Bhagnari,
-    BlackHereford,
-    BlacksidedTrondheimAndNorland,
-    BlancaCacerena,
-    BlancoOrejinegro,
-    BlondeDAquitaine,
-    Bonsmara,
-    Boran,
-    Bordelais,
-    Brahman,
-    Brahmousin,
-    Brangus,
-    Braunvieh,
-    BritishWhite,
-    BrownSwiss,
-    Busa,
-    Cachena,
-    CanadianHighland,
-    Canadienne,
-    CanaryIsland,
-    Canchim,
-    CarinthianBlond,
-    Caucasian,
-    Channi,
-    Charbray,
-    Charolais,
-    Chianina,
-    Chinampo,
-    ChineseBlackAndWhite,
-    ChineseMongolian,
-    ChineseXinjiangBrown,
-    Cholistani,
-    Corriente,
-    CostenoConCuernos,
-    Dajal,
-    Damascus,
-    Damietta,
-    Dangi,
-    DanishJersey,
-    DanishRed,
-    Deoni,
-    Devon,
-    Dexter,
-    Dhanni,
-    Djali,
-    Dolafe,
-    Droughtmaster,
-    Dulong,
-    DutchBelted,
-    DutchFriesian,
-    EastAnatolianRed,
-    EnderbyIsland,
-    EnglishLonghorn,
-    EstonianRed,
-    Evolene,
-    Fighting,
-    Finnish,
-    Fjall,
-    FloridaCracker,
-    GalicianBlond,
-    Galloway,
-    Gaolao,
-    Gascon,
-    Gelbray,
-    Gelbvieh,
-    GermanAngusMoiled,
-    GermanRedPied,
-    Gir,
-    Glan,
-    Gloucester,
-    Gobra,
-    GreekShorthorn,
-    GreekSteppe,
-    Groningen,
-    Guernsey,
-    Guzerat,
-    Hallikar,
-    Hariana,
-    Harton,
-    HaysConverter,
-    Hereford,
-    Herens,
-    Highland,
-    Hinterwald,
-    HolandoArgentino,
-    Holstein,
-    Horro,
-    HungarianGrey,
-    Icelandic,
-    Illawarra,
-    IndoBrazilian,
-    IrishMoiled,
-    IsraeliHolstein,
-    IsraeliRed,
-    Istoben,
-    JamaicaBlack,
-    JamaicaHope,
-    JamaicaRed,
-    Jaulan,
-    JaulanSharabi,
-    Jersey,
-    Kangayam,
-    Kankrej,
-    KaranFries,
-    KaranSwiss,
-    Kazakh,
-    Kenwariya,
-    Kerry,
-    Kherigarh,
-    Khillari,
-    Kholmogory,
-    Kilis,
-    KrishnaValley,
-    KurdiBlack,
-    Kuri,
-    LatvianBrown,
-    Limousin,
-    Limpurger,
-    LincolnRed,
-    LithuanianRed,
-    Lohani,
-    Lourdais,
-    Luing,
-    MadagascarZebu,
-    MaineAnjou,
-    Malvi,
-    Mandalong,
-    Marchigiana,
-    Maremmana,
-    Masai,
-    Mashona,
-    Maure,
-    Mazandarani,
-    MeuseRhineYssel,
-    Mewati,
-    MilkingDevon,
-    MilkingShorthorn,
-    MiniatureZebu,
-    Mirandesa,
-    Modicana,
-    Montbeliard,
-    Morucha,
-    Murboden,
-    MurrayGrey,
-    Muturu,
-    Ndama,
-    Nagori,
-    Nanyang,
-    Nelore,
-    Nguni,
-    Nimari,
-    Normande,
-    NorwegianRed,
-    Ongole,
-    OrmaBoran,
-    Oropa,
-    Ovambo,
-    Parthenais,
-    PhilippineNative,
-    Piedmontese,
-    Pinzgauer,
-    PolishRed,
-    PolledHereford,
-    Ponwar,
-    Qinchuan,
-    Rath,
-    Rathi,
-    RatienGray,
-    RedAngus,
-    RedBrangus,
-    RedFulani,
-    RedPiedFriesian,
-    RedPoll,
-    RedPolledOstland,
-    RedSindhi,
-    RedSteppe,
-    Reggiana,
-    Retinta,
-    Rojhan,
-    Romagnola,
-    Romosinuano,
-    RussianBlackPied,
-    RX3,
-    Sahiwal,
-    Salers,
-    Salorn,
-    SanMartinero,
-    Sanhe,
-    SantaCruz,
-    SantaGertrudis,
-    Sarabi,
-    Senepol,
-    Shetland,
-    Shorthorn,
-    Siboney,
-    Simbrah,
-    Simmental,
-    Siri,
-    SlovenianCika,
-    SouthDevon,
-    SudaneseFulani,
-    Sussex,
-    SwedishFriesian,
-    SwedishRedPolled,
-    SwedishRedAndWhite,
-    Tarentaise,
-    Telemark,
-    TexasLonghorn,
-    Texon,
-    Tharparkar,
-    Tswana,
-    Tuli,
-    TurkishGreySteppe,
-    UkrainianBeef,
-    UkrainianGrey,
-    UkrainianWhitehead,
-    Umblachery,
-    UralBlackPied,
-    VestlandFjord,
-    VestlandRedPolled,
-    Vosges,
-    Wagyu,
-    WelshBlack,
-    WhitePark,
-    Yanbian,
+breed_enum! {
+    /// An enum representing the different breeds of cattle.
+    ///
+    /// Initial data from: <https://breeds.okstate.edu/cattle/>
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::cattle::CattleBreed;
+    ///
+    /// let breed = CattleBreed::Angus;
+    /// println!("{:?}", breed);
+    /// ```
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum CattleBreed {
+        Africander,
+        Akaushi,
+        Alberes,
+        Alentejana,
+        Allmogekor,
+        AmericanBreed,
+        AmericanBraford,
+        AmericanWhitePark,
+        Amerifax,
+        AmritMahal,
+        AnatolianBlack,
+        AndalusianBlack,
+        AndalusianGrey,
+        Angeln,
+        Angus,
+        Ankole,
+        AnkoleWatusi,
+        ArgentineCriollo,
+        AsturianMountain,
+        AsturianValley,
+        Aubrac,
+        AulieAta,
+        AustralianBraford,
+        AustralianFriesianSahiwal,
+        AustralianLowline,
+        AustralianMilkingZebu,
+        Ayrshire,
+        Azaouak,
+        Bachaur,
+        Baladi,
+        BaltataRomaneasca,
+        Barka,
+        Barzona,
+        Bazadais,
+        Bearnais,
+        Beefalo,
+        Beefmaker,
+        Beefmaster,
+        BelarusRed,
+        BelgianBlue,
+        BelgianRed,
+        BelmontAdaptaur,
+        BelmontRed,
+        BeltedGalloway,
+        Bengali,
+        Berrendas,
+        Bhagnari,
+        BlackHereford,
+        BlacksidedTrondheimAndNorland,
+        BlancaCacerena,
+        BlancoOrejinegro,
+        BlondeDAquitaine,
+        Bonsmara,
+        Boran,
+        Bordelais,
+        Brahman,
+        Brahmousin,
+        Brangus,
+        Braunvieh,
+        BritishWhite,
+        BrownSwiss,
+        Busa,
+        Cachena,
+        CanadianHighland,
+        Canadienne,
+        CanaryIsland,
+        Canchim,
+        CarinthianBlond,
+        Caucasian,
+        Channi,
+        Charbray,
+        Charolais,
+        Chianina,
+        Chinampo,
+        ChineseBlackAndWhite,
+        ChineseMongolian,
+        ChineseXinjiangBrown,
+        Cholistani,
+        Corriente,
+        CostenoConCuernos,
+        Dajal,
+        Damascus,
+        Damietta,
+        Dangi,
+        DanishJersey,
+        DanishRed,
+        Deoni,
+        Devon,
+        Dexter,
+        Dhanni,
+        Djali,
+        Dolafe,
+        Droughtmaster,
+        Dulong,
+        DutchBelted,
+        DutchFriesian,
+        EastAnatolianRed,
+        EnderbyIsland,
+        EnglishLonghorn,
+        EstonianRed,
+        Evolene,
+        Fighting,
+        Finnish,
+        Fjall,
+        FloridaCracker,
+        GalicianBlond,
+        Galloway,
+        Gaolao,
+        Gascon,
+        Gelbray,
+        Gelbvieh,
+        GermanAngusMoiled,
+        GermanRedPied,
+        Gir,
+        Glan,
+        Gloucester,
+        Gobra,
+        GreekShorthorn,
+        GreekSteppe,
+        Groningen,
+        Guernsey,
+        Guzerat,
+        Hallikar,
+        Hariana,
+        Harton,
+        HaysConverter,
+        Hereford,
+        Herens,
+        Highland,
+        Hinterwald,
+        HolandoArgentino,
+        Holstein,
+        Horro,
+        HungarianGrey,
+        Icelandic,
+        Illawarra,
+        IndoBrazilian,
+        IrishMoiled,
+        IsraeliHolstein,
+        IsraeliRed,
+        Istoben,
+        JamaicaBlack,
+        JamaicaHope,
+        JamaicaRed,
+        Jaulan,
+        JaulanSharabi,
+        Jersey,
+        Kangayam,
+        Kankrej,
+        KaranFries,
+        KaranSwiss,
+        Kazakh,
+        Kenwariya,
+        Kerry,
+        Kherigarh,
+        Khillari,
+        Kholmogory,
+        Kilis,
+        KrishnaValley,
+        KurdiBlack,
+        Kuri,
+        LatvianBrown,
+        Limousin,
+        Limpurger,
+        LincolnRed,
+        LithuanianRed,
+        Lohani,
+        Lourdais,
+        Luing,
+        MadagascarZebu,
+        MaineAnjou,
+        Malvi,
+        Mandalong,
+        Marchigiana,
+        Maremmana,
+        Masai,
+        Mashona,
+        Maure,
+        Mazandarani,
+        MeuseRhineYssel,
+        Mewati,
+        MilkingDevon,
+        MilkingShorthorn,
+        MiniatureZebu,
+        Mirandesa,
+        Modicana,
+        Montbeliard,
+        Morucha,
+        Murboden,
+        MurrayGrey,
+        Muturu,
+        Ndama,
+        Nagori,
+        Nanyang,
+        Nelore,
+        Nguni,
+        Nimari,
+        Normande,
+        NorwegianRed,
+        Ongole,
+        OrmaBoran,
+        Oropa,
+        Ovambo,
+        Parthenais,
+        PhilippineNative,
+        Piedmontese,
+        Pinzgauer,
+        PolishRed,
+        PolledHereford,
+        Ponwar,
+        Qinchuan,
+        Rath,
+        Rathi,
+        RatienGray,
+        RedAngus,
+        RedBrangus,
+        RedFulani,
+        RedPiedFriesian,
+        RedPoll,
+        RedPolledOstland,
+        RedSindhi,
+        RedSteppe,
+        Reggiana,
+        Retinta,
+        Rojhan,
+        Romagnola,
+        Romosinuano,
+        RussianBlackPied,
+        RX3,
+        Sahiwal,
+        Salers,
+        Salorn,
+        SanMartinero,
+        Sanhe,
+        SantaCruz,
+        SantaGertrudis,
+        Sarabi,
+        Senepol,
+        Shetland,
+        Shorthorn,
+        Siboney,
+        Simbrah,
+        Simmental,
+        Siri,
+        SlovenianCika,
+        SouthDevon,
+        SudaneseFulani,
+        Sussex,
+        SwedishFriesian,
+        SwedishRedPolled,
+        SwedishRedAndWhite,
+        Tarentaise,
+        Telemark,
+        TexasLonghorn,
+        Texon,
+        Tharparkar,
+        Tswana,
+        Tuli,
+        TurkishGreySteppe,
+        UkrainianBeef,
+        UkrainianGrey,
+        UkrainianWhitehead,
+        Umblachery,
+        UralBlackPied,
+        VestlandFjord,
+        VestlandRedPolled,
+        Vosges,
+        Wagyu,
+        WelshBlack,
+        WhitePark,
+        Yanbian,
+    }
 }
 
-impl ToString for CattleBreed {
+impl fmt::Display for CattleBreed {
     /// Converts the CattleBreed enum to a human readable string.
     ///
     /// # Examples
@@ -298,27 +305,27 @@ impl ToString for CattleBreed {
     /// let angus = CattleBreed::Angus;
     /// println!("{}", angus.to_string());
     /// ```
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            CattleBreed::AnkoleWatusi => "Ankole-Watusi".to_string(),
-            CattleBreed::AulieAta => "Aulie-Ata".to_string(),
-            CattleBreed::Bearnais => "Béarnais".to_string(),
-            CattleBreed::BlancaCacerena => "Blanca Cacereña/White Cáceres".to_string(),
-            CattleBreed::BlondeDAquitaine => "Blonde d'Aquitaine".to_string(),
-            CattleBreed::ChineseBlackAndWhite => "Chinese Black-and-White".to_string(),
-            CattleBreed::CostenoConCuernos => "Costeño con Cuernos".to_string(),
-            CattleBreed::DutchBelted => "Dutch Belted (Lakenvelder)".to_string(),
-            CattleBreed::HolandoArgentino => "Holando-Argentino".to_string(),
-            CattleBreed::IndoBrazilian => "Indo-Brazilian".to_string(),
-            CattleBreed::LatvianBrown => "Latvian Brown (Buraya Latviiskaya)".to_string(),
-            CattleBreed::MaineAnjou => "Maine-Anjou".to_string(),
-            CattleBreed::MeuseRhineYssel => "Meuse-Rhine-Yssel".to_string(),
-            CattleBreed::Montbeliard => "Montbéliarde".to_string(),
-            CattleBreed::Muturu => "Muturu - West African Dwarf Shorthorn".to_string(),
-            CattleBreed::Ndama => "N'dama".to_string(),
-            CattleBreed::RedPolledOstland => "Red Polled Østland".to_string(),
-            CattleBreed::SwedishRedAndWhite => "Swedish Red-and-White".to_string(),
-            _ => format!("{:?}", self).to_title_case(),
+            CattleBreed::AnkoleWatusi => f.write_str("Ankole-Watusi"),
+            CattleBreed::AulieAta => f.write_str("Aulie-Ata"),
+            CattleBreed::Bearnais => f.write_str("Béarnais"),
+            CattleBreed::BlancaCacerena => f.write_str("Blanca Cacereña/White Cáceres"),
+            CattleBreed::BlondeDAquitaine => f.write_str("Blonde d'Aquitaine"),
+            CattleBreed::ChineseBlackAndWhite => f.write_str("Chinese Black-and-White"),
+            CattleBreed::CostenoConCuernos => f.write_str("Costeño con Cuernos"),
+            CattleBreed::DutchBelted => f.write_str("Dutch Belted (Lakenvelder)"),
+            CattleBreed::HolandoArgentino => f.write_str("Holando-Argentino"),
+            CattleBreed::IndoBrazilian => f.write_str("Indo-Brazilian"),
+            CattleBreed::LatvianBrown => f.write_str("Latvian Brown (Buraya Latviiskaya)"),
+            CattleBreed::MaineAnjou => f.write_str("Maine-Anjou"),
+            CattleBreed::MeuseRhineYssel => f.write_str("Meuse-Rhine-Yssel"),
+            CattleBreed::Montbeliard => f.write_str("Montbéliarde"),
+            CattleBreed::Muturu => f.write_str("Muturu - West African Dwarf Shorthorn"),
+            CattleBreed::Ndama => f.write_str("N'dama"),
+            CattleBreed::RedPolledOstland => f.write_str("Red Polled Østland"),
+            CattleBreed::SwedishRedAndWhite => f.write_str("Swedish Red-and-White"),
+            _ => f.write_str(&format!("{:?}", self).to_title_case()),
         }
     }
 }
@@ -333,9 +340,8 @@ impl ToString for CattleBreed {
 /// let breed = CattleBreed::from_str("angus").unwrap();
 /// println!("{:?}", breed);
 /// ```
-
 impl FromStr for CattleBreed {
-    type Err = std::io::Error;
+    type Err = BreedParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().replace("-", " ").as_str() {
             "africander" => Ok(CattleBreed::Africander),
@@ -343,9 +349,9 @@ impl FromStr for CattleBreed {
             "alberes" => Ok(CattleBreed::Alberes),
             "alentejana" => Ok(CattleBreed::Alentejana),
             "allmogekor" => Ok(CattleBreed::Allmogekor),
-            "american breed" | "american" => Ok(CattleBreed::AmericanBreed),
+            "american breed" => Ok(CattleBreed::AmericanBreed),
             "american braford" => Ok(CattleBreed::AmericanBraford),
-            "american whitepark" | "american white park" => Ok(CattleBreed::AmericanWhitePark),
+            "american whitepark" => Ok(CattleBreed::AmericanWhitePark),
             "amerifax" => Ok(CattleBreed::Amerifax),
             "amrit mahal" => Ok(CattleBreed::AmritMahal),
             "anatolian black" => Ok(CattleBreed::AnatolianBlack),
@@ -372,7 +378,7 @@ impl FromStr for CattleBreed {
             "barka" => Ok(CattleBreed::Barka),
             "barzona" => Ok(CattleBreed::Barzona),
             "bazadais" => Ok(CattleBreed::Bazadais),
-            "bearnais" | "béarnais" => Ok(CattleBreed::Bearnais),
+            "bearnais" => Ok(CattleBreed::Bearnais),
             "beefalo" => Ok(CattleBreed::Beefalo),
             "beefmaker" => Ok(CattleBreed::Beefmaker),
             "beefmaster" => Ok(CattleBreed::Beefmaster),
@@ -387,14 +393,7 @@ impl FromStr for CattleBreed {
             "bhagnari" => Ok(CattleBreed::Bhagnari),
             "black hereford" => Ok(CattleBreed::BlackHereford),
             "blacksided trondheim and norland" => Ok(CattleBreed::BlacksidedTrondheimAndNorland),
-            "blanca cacerena"
-            | "blanca cacereña"
-            | "white cáceres"
-            | "white caceres"
-            | "blanca cacerena/white caceres"
-            | "blanca cacereña/white cáceres"
-            | "blanca cacerena/white cáceres"
-            | "blanca cacereña/white caceres" => Ok(CattleBreed::BlancaCacerena),
+            "blanca cacerena" => Ok(CattleBreed::BlancaCacerena),
             "blanco orejinegro" => Ok(CattleBreed::BlancoOrejinegro),
             "blonde d'aquitaine" => Ok(CattleBreed::BlondeDAquitaine),
             "bonsmara" => Ok(CattleBreed::Bonsmara),
@@ -424,7 +423,7 @@ impl FromStr for CattleBreed {
             "chinese xinjiang brown" => Ok(CattleBreed::ChineseXinjiangBrown),
             "cholistani" => Ok(CattleBreed::Cholistani),
             "corriente" => Ok(CattleBreed::Corriente),
-            "costeno con cuernos" | "costeño con cuernos" => Ok(CattleBreed::CostenoConCuernos),
+            "costeno con cuernos" => Ok(CattleBreed::CostenoConCuernos),
             "dajal" => Ok(CattleBreed::Dajal),
             "damascus" => Ok(CattleBreed::Damascus),
             "damietta" => Ok(CattleBreed::Damietta),
@@ -436,20 +435,16 @@ impl FromStr for CattleBreed {
             "dexter" => Ok(CattleBreed::Dexter),
             "dhanni" => Ok(CattleBreed::Dhanni),
             "djali" => Ok(CattleBreed::Djali),
-            "dolafe" | "dølafe" => Ok(CattleBreed::Dolafe),
+            "dolafe" => Ok(CattleBreed::Dolafe),
             "droughtmaster" => Ok(CattleBreed::Droughtmaster),
             "dulong" => Ok(CattleBreed::Dulong),
-            "dutch belted"
-            | "lakenvelder"
-            | "dutch belted (lakenvelder)"
-            | "dutch belted/lakenvelder"
-            | "lakenvelder/dutch belted" => Ok(CattleBreed::DutchBelted),
+            "dutch belted" => Ok(CattleBreed::DutchBelted),
             "dutch friesian" => Ok(CattleBreed::DutchFriesian),
             "east anatolian red" => Ok(CattleBreed::EastAnatolianRed),
             "enderby island" => Ok(CattleBreed::EnderbyIsland),
             "english longhorn" => Ok(CattleBreed::EnglishLonghorn),
             "estonian red" => Ok(CattleBreed::EstonianRed),
-            "evolene" | "evolène" => Ok(CattleBreed::Evolene),
+            "evolene" => Ok(CattleBreed::Evolene),
             "fighting" => Ok(CattleBreed::Fighting),
             "finnish" => Ok(CattleBreed::Finnish),
             "fjall" => Ok(CattleBreed::Fjall),
@@ -494,7 +489,7 @@ impl FromStr for CattleBreed {
             "jamaica hope" => Ok(CattleBreed::JamaicaHope),
             "jamaica red" => Ok(CattleBreed::JamaicaRed),
             "jaulan" => Ok(CattleBreed::Jaulan),
-            "jaulan sharabi" | "sharabi" => Ok(CattleBreed::JaulanSharabi),
+            "jaulan sharabi" => Ok(CattleBreed::JaulanSharabi),
             "jersey" => Ok(CattleBreed::Jersey),
             "kangayam" => Ok(CattleBreed::Kangayam),
             "kankrej" => Ok(CattleBreed::Kankrej),
@@ -510,9 +505,7 @@ impl FromStr for CattleBreed {
             "krishna valley" => Ok(CattleBreed::KrishnaValley),
             "kurdi black" => Ok(CattleBreed::KurdiBlack),
             "kuri" => Ok(CattleBreed::Kuri),
-            "latvian brown" | "buraya latviiskaya" | "latvian brown (buraya latviiskaya)" => {
-                Ok(CattleBreed::LatvianBrown)
-            }
+            "latvian brown" => Ok(CattleBreed::LatvianBrown),
             "limousin" => Ok(CattleBreed::Limousin),
             "limpurger" => Ok(CattleBreed::Limpurger),
             "lincoln red" => Ok(CattleBreed::LincolnRed),
@@ -537,14 +530,12 @@ impl FromStr for CattleBreed {
             "miniature zebu" => Ok(CattleBreed::MiniatureZebu),
             "mirandesa" => Ok(CattleBreed::Mirandesa),
             "modicana" => Ok(CattleBreed::Modicana),
-            "montbeliard" | "montbéliard" => Ok(CattleBreed::Montbeliard),
+            "montbeliard" => Ok(CattleBreed::Montbeliard),
             "morucha" => Ok(CattleBreed::Morucha),
             "murboden" => Ok(CattleBreed::Murboden),
             "murray grey" => Ok(CattleBreed::MurrayGrey),
-            "muturu" | "west african dwarf shorthorn" | "muturu   west african dwarf shorthorn" => {
-                Ok(CattleBreed::Muturu)
-            }
-            "ndama" | "n'dama" => Ok(CattleBreed::Ndama),
+            "muturu" => Ok(CattleBreed::Muturu),
+            "ndama" => Ok(CattleBreed::Ndama),
             "nagori" => Ok(CattleBreed::Nagori),
             "nanyang" => Ok(CattleBreed::Nanyang),
             "nelore" => Ok(CattleBreed::Nelore),
@@ -566,13 +557,13 @@ impl FromStr for CattleBreed {
             "qinchuan" => Ok(CattleBreed::Qinchuan),
             "rath" => Ok(CattleBreed::Rath),
             "rathi" => Ok(CattleBreed::Rathi),
-            "ratien gray" | "rätien gray" => Ok(CattleBreed::RatienGray),
+            "ratien gray" => Ok(CattleBreed::RatienGray),
             "red angus" => Ok(CattleBreed::RedAngus),
             "red brangus" => Ok(CattleBreed::RedBrangus),
             "red fulani" => Ok(CattleBreed::RedFulani),
             "red pied friesian" => Ok(CattleBreed::RedPiedFriesian),
             "red poll" => Ok(CattleBreed::RedPoll),
-            "red polled ostland" | "red polled østland" => Ok(CattleBreed::RedPolledOstland),
+            "red polled ostland" => Ok(CattleBreed::RedPolledOstland),
             "red sindhi" => Ok(CattleBreed::RedSindhi),
             "red steppe" => Ok(CattleBreed::RedSteppe),
             "reggiana" => Ok(CattleBreed::Reggiana),
@@ -594,7 +585,7 @@ impl FromStr for CattleBreed {
             "shetland" => Ok(CattleBreed::Shetland),
             "shorthorn" => Ok(CattleBreed::Shorthorn),
             "siboney" => Ok(CattleBreed::Siboney),
-            "simbra" | "simbrah" => Ok(CattleBreed::Simbrah),
+            "simbra" => Ok(CattleBreed::Simbrah),
             "simmental" => Ok(CattleBreed::Simmental),
             "siri" => Ok(CattleBreed::Siri),
             "slovenian cika" => Ok(CattleBreed::SlovenianCika),
@@ -624,14 +615,547 @@ impl FromStr for CattleBreed {
             "welsh black" => Ok(CattleBreed::WelshBlack),
             "white park" => Ok(CattleBreed::WhitePark),
             "yanbian" => Ok(CattleBreed::Yanbian),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Invalid cattle breed",
-            )),
+            _ => fuzzy::resolve(VARIANTS, s),
         }
     }
 }
 
+impl CattleBreed {
+    /// Returns every cattle breed, in declaration order.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::cattle::CattleBreed;
+    ///
+    /// let breeds = CattleBreed::all();
+    /// assert_eq!(breeds.len(), CattleBreed::count());
+    /// ```
+    pub fn all() -> &'static [CattleBreed] {
+        VARIANTS
+    }
+
+    /// Returns an iterator over every cattle breed, in declaration order.
+    pub fn iter() -> impl Iterator<Item = CattleBreed> {
+        VARIANTS.iter().copied()
+    }
+
+    /// Returns the number of cattle breeds.
+    pub fn count() -> usize {
+        VARIANTS.len()
+    }
+
+    /// Returns the cattle breeds whose names closely match the query, best match first.
+    ///
+    /// Case, diacritics and punctuation are ignored, and small typos are tolerated.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::cattle::CattleBreed;
+    ///
+    /// let matches = CattleBreed::fuzzy_search("Charlois");
+    /// assert_eq!(matches[0].breed, CattleBreed::Charolais);
+    /// ```
+    pub fn fuzzy_search(query: &str) -> Vec<BreedMatch<CattleBreed>> {
+        fuzzy::search(VARIANTS, query)
+    }
+
+    /// Returns the cattle breeds raised primarily for the given purpose.
+    ///
+    /// Metadata has only been compiled for some cattle breeds, those returned by
+    /// [`CattleBreed::with_metadata`], so other breeds are left out even if they would match.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::cattle::CattleBreed;
+    /// use livestock_rs::breeds::metadata::Purpose;
+    ///
+    /// let breeds = CattleBreed::by_purpose(Purpose::Beef);
+    /// assert!(breeds.contains(&CattleBreed::Angus));
+    /// ```
+    pub fn by_purpose(purpose: Purpose) -> Vec<CattleBreed> {
+        metadata::filter(METADATA, |metadata| metadata.purpose == purpose)
+    }
+
+    /// Returns the cattle breeds that originated in the given region.
+    ///
+    /// Metadata has only been compiled for some cattle breeds, those returned by
+    /// [`CattleBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_region(region: Region) -> Vec<CattleBreed> {
+        metadata::filter(METADATA, |metadata| metadata.region == region)
+    }
+
+    /// Returns the cattle breeds of the given size class.
+    ///
+    /// Metadata has only been compiled for some cattle breeds, those returned by
+    /// [`CattleBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_size_class(size_class: SizeClass) -> Vec<CattleBreed> {
+        metadata::filter(METADATA, |metadata| metadata.size_class == size_class)
+    }
+
+    /// Returns the cattle breeds that have metadata.
+    ///
+    /// Metadata covers the better documented breeds first, so this is usually a subset of
+    /// [`CattleBreed::all`].
+    pub fn with_metadata() -> Vec<CattleBreed> {
+        METADATA.iter().map(|(breed, _)| *breed).collect()
+    }
+}
+
+impl Breed for CattleBreed {
+    fn species(&self) -> LivestockType {
+        LivestockType::Cattle
+    }
+
+    fn metadata(&self) -> Option<BreedMetadata> {
+        metadata::lookup(METADATA, self)
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        ALIASES
+            .iter()
+            .filter(|(breed, _)| breed == self)
+            .map(|(_, alias)| *alias)
+            .collect()
+    }
+
+    fn abbreviation(&self) -> Option<&'static str> {
+        ABBREVIATIONS
+            .iter()
+            .find(|(breed, _)| breed == self)
+            .map(|(_, abbreviation)| *abbreviation)
+    }
+
+    fn from_abbreviation(abbreviation: &str) -> Option<Self> {
+        ABBREVIATIONS
+            .iter()
+            .find(|(_, candidate)| candidate.eq_ignore_ascii_case(abbreviation))
+            .map(|(breed, _)| *breed)
+    }
+}
+
+/// Registry abbreviations of cattle breeds, as used by the National Association of Animal
+/// Breeders (NAAB) breed codes.
+const ABBREVIATIONS: &[(CattleBreed, &str)] = &[
+    (CattleBreed::Angus, "AN"),
+    (CattleBreed::RedAngus, "AR"),
+    (CattleBreed::Ayrshire, "AY"),
+    (CattleBreed::BelgianBlue, "BB"),
+    (CattleBreed::BeltedGalloway, "BG"),
+    (CattleBreed::Beefmaster, "BM"),
+    (CattleBreed::Brangus, "BN"),
+    (CattleBreed::Brahman, "BR"),
+    (CattleBreed::BrownSwiss, "BS"),
+    (CattleBreed::Chianina, "CA"),
+    (CattleBreed::Charolais, "CH"),
+    (CattleBreed::Galloway, "GA"),
+    (CattleBreed::Guernsey, "GU"),
+    (CattleBreed::Gelbvieh, "GV"),
+    (CattleBreed::Hereford, "HH"),
+    (CattleBreed::Holstein, "HO"),
+    (CattleBreed::PolledHereford, "HP"),
+    (CattleBreed::Jersey, "JE"),
+    (CattleBreed::Limousin, "LM"),
+    (CattleBreed::MaineAnjou, "MA"),
+    (CattleBreed::MurrayGrey, "MG"),
+    (CattleBreed::MilkingShorthorn, "MS"),
+    (CattleBreed::Piedmontese, "PI"),
+    (CattleBreed::Pinzgauer, "PZ"),
+    (CattleBreed::RedPoll, "RP"),
+    (CattleBreed::Salers, "SA"),
+    (CattleBreed::SouthDevon, "SD"),
+    (CattleBreed::SantaGertrudis, "SG"),
+    (CattleBreed::Shorthorn, "SH"),
+    (CattleBreed::Simmental, "SM"),
+    (CattleBreed::Tarentaise, "TA"),
+    (CattleBreed::TexasLonghorn, "TL"),
+    (CattleBreed::Tuli, "TU"),
+    (CattleBreed::Wagyu, "WA"),
+    (CattleBreed::WelshBlack, "WB"),
+];
+
+/// Metadata for the cattle breeds with well documented characteristics.
+const METADATA: &[(CattleBreed, BreedMetadata)] = &[
+    (
+        CattleBreed::Angus,
+        BreedMetadata::new(
+            "Scotland",
+            Region::Europe,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        CattleBreed::AnkoleWatusi,
+        BreedMetadata::new(
+            "Uganda",
+            Region::Africa,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Ayrshire,
+        BreedMetadata::new(
+            "Scotland",
+            Region::Europe,
+            Purpose::Dairy,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Beefmaster,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        CattleBreed::BelgianBlue,
+        BreedMetadata::new(
+            "Belgium",
+            Region::Europe,
+            Purpose::Beef,
+            SizeClass::Large,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::BeltedGalloway,
+        BreedMetadata::new(
+            "Scotland",
+            Region::Europe,
+            Purpose::Beef,
+            SizeClass::Small,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        CattleBreed::Bonsmara,
+        BreedMetadata::new(
+            "South Africa",
+            Region::Africa,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        CattleBreed::Boran,
+        BreedMetadata::new(
+            "Ethiopia",
+            Region::Africa,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Brahman,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Beef,
+            SizeClass::Large,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Brangus,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        CattleBreed::BrownSwiss,
+        BreedMetadata::new(
+            "Switzerland",
+            Region::Europe,
+            Purpose::Dairy,
+            SizeClass::Large,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Charolais,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Beef,
+            SizeClass::Large,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        CattleBreed::Chianina,
+        BreedMetadata::new(
+            "Italy",
+            Region::Europe,
+            Purpose::Beef,
+            SizeClass::Heavy,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Dexter,
+        BreedMetadata::new(
+            "Ireland",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Small,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        CattleBreed::Galloway,
+        BreedMetadata::new(
+            "Scotland",
+            Region::Europe,
+            Purpose::Beef,
+            SizeClass::Small,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        CattleBreed::Gelbvieh,
+        BreedMetadata::new(
+            "Germany",
+            Region::Europe,
+            Purpose::Beef,
+            SizeClass::Large,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        CattleBreed::Gir,
+        BreedMetadata::new(
+            "India",
+            Region::Asia,
+            Purpose::Dairy,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Guernsey,
+        BreedMetadata::new(
+            "Guernsey",
+            Region::Europe,
+            Purpose::Dairy,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Hereford,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Highland,
+        BreedMetadata::new(
+            "Scotland",
+            Region::Europe,
+            Purpose::Beef,
+            SizeClass::Small,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Holstein,
+        BreedMetadata::new(
+            "Netherlands",
+            Region::Europe,
+            Purpose::Dairy,
+            SizeClass::Large,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Jersey,
+        BreedMetadata::new(
+            "Jersey",
+            Region::Europe,
+            Purpose::Dairy,
+            SizeClass::Small,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Limousin,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Beef,
+            SizeClass::Large,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        CattleBreed::MilkingShorthorn,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Dairy,
+            SizeClass::Medium,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        CattleBreed::MurrayGrey,
+        BreedMetadata::new(
+            "Australia",
+            Region::Oceania,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        CattleBreed::Nelore,
+        BreedMetadata::new(
+            "Brazil",
+            Region::SouthAmerica,
+            Purpose::Beef,
+            SizeClass::Large,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Normande,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Large,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Piedmontese,
+        BreedMetadata::new(
+            "Italy",
+            Region::Europe,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::PolledHereford,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        CattleBreed::RedAngus,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        CattleBreed::Sahiwal,
+        BreedMetadata::new(
+            "Pakistan",
+            Region::Asia,
+            Purpose::Dairy,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::SantaGertrudis,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Beef,
+            SizeClass::Large,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        CattleBreed::Shorthorn,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Medium,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        CattleBreed::Simmental,
+        BreedMetadata::new(
+            "Switzerland",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Large,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        CattleBreed::TexasLonghorn,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        CattleBreed::Wagyu,
+        BreedMetadata::new(
+            "Japan",
+            Region::Asia,
+            Purpose::Beef,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+];
+
+/// Alternative names a cattle breed is known by, in addition to its canonical name.
+const ALIASES: &[(CattleBreed, &str)] = &[
+    (CattleBreed::AmericanBreed, "american"),
+    (CattleBreed::BlancaCacerena, "white cáceres"),
+    (CattleBreed::BlancaCacerena, "blanca cacerena/white caceres"),
+    (CattleBreed::DutchBelted, "lakenvelder"),
+    (CattleBreed::DutchBelted, "dutch belted (lakenvelder)"),
+    (CattleBreed::DutchBelted, "lakenvelder/dutch belted"),
+    (CattleBreed::JaulanSharabi, "sharabi"),
+    (CattleBreed::LatvianBrown, "buraya latviiskaya"),
+    (
+        CattleBreed::LatvianBrown,
+        "latvian brown (buraya latviiskaya)",
+    ),
+    (CattleBreed::Muturu, "west african dwarf shorthorn"),
+    (CattleBreed::Muturu, "muturu   west african dwarf shorthorn"),
+    (CattleBreed::Ndama, "n'dama"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -693,7 +1217,69 @@ mod tests {
         ];
 
         for (breed, expected) in breeds.iter() {
-            assert_eq!(CattleBreed::from_str(*breed).unwrap(), *expected);
+            assert_eq!(CattleBreed::from_str(breed).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = CattleBreed::Angus.metadata().unwrap();
+        assert_eq!(metadata.origin, "Scotland");
+        assert_eq!(metadata.purpose, Purpose::Beef);
+        assert_eq!(metadata.size_class, SizeClass::Medium);
+
+        for breed in CattleBreed::by_purpose(Purpose::Beef) {
+            assert_eq!(breed.metadata().unwrap().purpose, Purpose::Beef);
+        }
+
+        let documented = CattleBreed::with_metadata();
+        for breed in CattleBreed::iter() {
+            assert_eq!(breed.metadata().is_some(), documented.contains(&breed));
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let matches = CattleBreed::fuzzy_search("Charlois");
+        assert_eq!(matches[0].breed, CattleBreed::Charolais);
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_from_str_suggestions() {
+        let err = CattleBreed::from_str("Charlois").unwrap_err();
+        assert_eq!(err.suggestions[0], CattleBreed::Charolais.to_string());
+    }
+
+    #[test]
+    fn test_variant_names_are_unique() {
+        for breed in CattleBreed::iter() {
+            assert_eq!(CattleBreed::from_str(&breed.to_string()).unwrap(), breed);
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        // Variants are numbered in declaration order, so each one is listed at its own index.
+        for (index, breed) in CattleBreed::iter().enumerate() {
+            assert_eq!(breed as usize, index);
+        }
+    }
+
+    #[test]
+    fn test_abbreviations() {
+        assert_eq!(CattleBreed::Angus.abbreviation(), Some("AN"));
+        assert_eq!(CattleBreed::Ankole.abbreviation(), None);
+        assert_eq!(
+            CattleBreed::from_abbreviation("hh"),
+            Some(CattleBreed::Hereford)
+        );
+        assert_eq!(CattleBreed::from_abbreviation("ZZ"), None);
+
+        for (breed, abbreviation) in ABBREVIATIONS {
+            assert_eq!(CattleBreed::from_abbreviation(abbreviation), Some(*breed));
         }
     }
 }
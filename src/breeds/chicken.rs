@@ -1,94 +1,101 @@
+use super::fuzzy::{self, BreedMatch};
+use super::metadata::{self, BreedMetadata, Purpose, Region, SizeClass};
+use super::{Breed, BreedParseError};
+use crate::types::LivestockType;
 use inflector::Inflector;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
-/// An enum representing the different breeds of chickens.
-///
-/// Initial data from: <https://breeds.okstate.edu/poultry/chickens/>
-///
-/// # Examples
-/// ``` rust
-/// use livestock_rs::breeds::chicken::ChickenBreed;
-///
-/// let breed = ChickenBreed::Orpington;
-/// println!("{:?}", breed);
-/// ```
-#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub enum ChickenBreed {
-    AC,
-    Ameraucana,
-    Ancona,
-    Andalusian,
-    AppenzellBeardedHen,
-    AppenzellPointedHoodHen,
-    Araucana,
-    Aseel,
-    Australorp,
-    Baheij,
-    Bandara,
-    Barnevelders,
-    Brahma,
-    Buckeye,
-    Buttercup,
-    Campine,
-    Catalana,
-    Chantecler,
-    Cochin,
-    Cornish,
-    Crevecoeur,
-    Cubalaya,
-    Delaware,
-    Dominiques,
-    Dorking,
-    DutchBantam,
-    Faverolles,
-    Friesian,
-    Frizzle,
-    Gimmizah,
-    GoldenMontazah,
-    Hamburg,
-    Holland,
-    Houdan,
-    Java,
-    JerseyGiant,
-    LaFleche,
-    Lakenvelder,
-    Lamona,
-    Langshan,
-    Legbar,
-    Leghorn,
-    Marans,
-    Malay,
-    Matrouh,
-    Minorca,
-    ModernGame,
-    NakedNeck, // (Turken)
-    Nankin,
-    NewHampshireRed,
-    OldEnglishGame,
-    Orpington,
-    PlymouthRock,
-    Polish,
-    RedCap,
-    RhodeIslandRed,
-    RussianOrloff,
-    Sasso,
-    Sebright,
-    Shamo,
-    SilkieBantam,
-    SilverMontazah,
-    Styrian,
-    Sultan,
-    Sumatra,
-    Sussex,
-    SwissHen, // (Schweizerhuhn)
-    Welsummer,
-    WhiteFacedBlackSpanish,
-    Wyandotte,
-    Yokohama,
+breed_enum! {
+    /// An enum representing the different breeds of chickens.
+    ///
+    /// Initial data from: <https://breeds.okstate.edu/poultry/chickens/>
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::chicken::ChickenBreed;
+    ///
+    /// let breed = ChickenBreed::Orpington;
+    /// println!("{:?}", breed);
+    /// ```
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum ChickenBreed {
+        AC,
+        Ameraucana,
+        Ancona,
+        Andalusian,
+        AppenzellBeardedHen,
+        AppenzellPointedHoodHen,
+        Araucana,
+        Aseel,
+        Australorp,
+        Baheij,
+        Bandara,
+        Barnevelders,
+        Brahma,
+        Buckeye,
+        Buttercup,
+        Campine,
+        Catalana,
+        Chantecler,
+        Cochin,
+        Cornish,
+        Crevecoeur,
+        Cubalaya,
+        Delaware,
+        Dominiques,
+        Dorking,
+        DutchBantam,
+        Faverolles,
+        Friesian,
+        Frizzle,
+        Gimmizah,
+        GoldenMontazah,
+        Hamburg,
+        Holland,
+        Houdan,
+        Java,
+        JerseyGiant,
+        LaFleche,
+        Lakenvelder,
+        Lamona,
+        Langshan,
+        Legbar,
+        Leghorn,
+        Marans,
+        Malay,
+        Matrouh,
+        Minorca,
+        ModernGame,
+        NakedNeck, // (Turken)
+        Nankin,
+        NewHampshireRed,
+        OldEnglishGame,
+        Orpington,
+        PlymouthRock,
+        Polish,
+        RedCap,
+        RhodeIslandRed,
+        RussianOrloff,
+        Sasso,
+        Sebright,
+        Shamo,
+        SilkieBantam,
+        SilverMontazah,
+        Styrian,
+        Sultan,
+        Sumatra,
+        Sussex,
+        SwissHen, // (Schweizerhuhn)
+        Welsummer,
+        WhiteFacedBlackSpanish,
+        Wyandotte,
+        Yokohama,
+    }
 }
 
-impl ToString for ChickenBreed {
+impl fmt::Display for ChickenBreed {
     /// Converts the ChickenBreed enum to a human readable string.
     ///
     /// # Examples
@@ -98,8 +105,8 @@ impl ToString for ChickenBreed {
     /// let orp = ChickenBreed::Orpington;
     /// println!("{}", orp.to_string());
     /// ```
-    fn to_string(&self) -> String {
-        format!("{:?}", self).to_title_case()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format!("{:?}", self).to_title_case())
     }
 }
 
@@ -113,9 +120,8 @@ impl ToString for ChickenBreed {
 /// let breed = ChickenBreed::from_str("Buff orpington").unwrap();
 /// println!("{:?}", breed);
 /// ```
-
 impl FromStr for ChickenBreed {
-    type Err = std::io::Error;
+    type Err = BreedParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "ac" => Ok(ChickenBreed::AC),
@@ -165,13 +171,11 @@ impl FromStr for ChickenBreed {
             "matrouh" => Ok(ChickenBreed::Matrouh),
             "minorca" => Ok(ChickenBreed::Minorca),
             "modern game" => Ok(ChickenBreed::ModernGame),
-            "naked neck" | "turken" => Ok(ChickenBreed::NakedNeck),
+            "naked neck" => Ok(ChickenBreed::NakedNeck),
             "nankin" => Ok(ChickenBreed::Nankin),
             "new hampshire red" => Ok(ChickenBreed::NewHampshireRed),
             "old english game" => Ok(ChickenBreed::OldEnglishGame),
-            "orpington" | "buff orpington" | "lavender orpington" | "buff" => {
-                Ok(ChickenBreed::Orpington)
-            }
+            "orpington" => Ok(ChickenBreed::Orpington),
             "plymouth rock" => Ok(ChickenBreed::PlymouthRock),
             "polish" => Ok(ChickenBreed::Polish),
             "red cap" => Ok(ChickenBreed::RedCap),
@@ -186,19 +190,382 @@ impl FromStr for ChickenBreed {
             "sultan" => Ok(ChickenBreed::Sultan),
             "sumatra" => Ok(ChickenBreed::Sumatra),
             "sussex" => Ok(ChickenBreed::Sussex),
-            "swiss hen" | "schweizerhuhn" => Ok(ChickenBreed::SwissHen),
+            "swiss hen" => Ok(ChickenBreed::SwissHen),
             "welsummer" => Ok(ChickenBreed::Welsummer),
             "white faced black spanish" => Ok(ChickenBreed::WhiteFacedBlackSpanish),
             "wyandotte" => Ok(ChickenBreed::Wyandotte),
             "yokohama" => Ok(ChickenBreed::Yokohama),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Unknown breed",
-            )),
+            _ => fuzzy::resolve(VARIANTS, s),
         }
     }
 }
 
+impl ChickenBreed {
+    /// Returns every chicken breed, in declaration order.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::chicken::ChickenBreed;
+    ///
+    /// let breeds = ChickenBreed::all();
+    /// assert_eq!(breeds.len(), ChickenBreed::count());
+    /// ```
+    pub fn all() -> &'static [ChickenBreed] {
+        VARIANTS
+    }
+
+    /// Returns an iterator over every chicken breed, in declaration order.
+    pub fn iter() -> impl Iterator<Item = ChickenBreed> {
+        VARIANTS.iter().copied()
+    }
+
+    /// Returns the number of chicken breeds.
+    pub fn count() -> usize {
+        VARIANTS.len()
+    }
+
+    /// Returns the chicken breeds whose names closely match the query, best match first.
+    ///
+    /// Case, diacritics and punctuation are ignored, and small typos are tolerated.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::chicken::ChickenBreed;
+    ///
+    /// let matches = ChickenBreed::fuzzy_search("Rhode Island");
+    /// assert_eq!(matches[0].breed, ChickenBreed::RhodeIslandRed);
+    /// ```
+    pub fn fuzzy_search(query: &str) -> Vec<BreedMatch<ChickenBreed>> {
+        fuzzy::search(VARIANTS, query)
+    }
+
+    /// Returns the chicken breeds raised primarily for the given purpose.
+    ///
+    /// Metadata has only been compiled for some chicken breeds, those returned by
+    /// [`ChickenBreed::with_metadata`], so other breeds are left out even if they would match.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::chicken::ChickenBreed;
+    /// use livestock_rs::breeds::metadata::Purpose;
+    ///
+    /// let breeds = ChickenBreed::by_purpose(Purpose::Dual);
+    /// assert!(breeds.contains(&ChickenBreed::Buckeye));
+    /// ```
+    pub fn by_purpose(purpose: Purpose) -> Vec<ChickenBreed> {
+        metadata::filter(METADATA, |metadata| metadata.purpose == purpose)
+    }
+
+    /// Returns the chicken breeds that originated in the given region.
+    ///
+    /// Metadata has only been compiled for some chicken breeds, those returned by
+    /// [`ChickenBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_region(region: Region) -> Vec<ChickenBreed> {
+        metadata::filter(METADATA, |metadata| metadata.region == region)
+    }
+
+    /// Returns the chicken breeds of the given size class.
+    ///
+    /// Metadata has only been compiled for some chicken breeds, those returned by
+    /// [`ChickenBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_size_class(size_class: SizeClass) -> Vec<ChickenBreed> {
+        metadata::filter(METADATA, |metadata| metadata.size_class == size_class)
+    }
+
+    /// Returns the chicken breeds that have metadata.
+    ///
+    /// Metadata covers the better documented breeds first, so this is usually a subset of
+    /// [`ChickenBreed::all`].
+    pub fn with_metadata() -> Vec<ChickenBreed> {
+        METADATA.iter().map(|(breed, _)| *breed).collect()
+    }
+}
+
+impl Breed for ChickenBreed {
+    fn species(&self) -> LivestockType {
+        LivestockType::Chicken
+    }
+
+    fn metadata(&self) -> Option<BreedMetadata> {
+        metadata::lookup(METADATA, self)
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        ALIASES
+            .iter()
+            .filter(|(breed, _)| breed == self)
+            .map(|(_, alias)| *alias)
+            .collect()
+    }
+}
+
+/// Metadata for the chicken breeds with well documented characteristics.
+const METADATA: &[(ChickenBreed, BreedMetadata)] = &[
+    (
+        ChickenBreed::Ameraucana,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Egg,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Ancona,
+        BreedMetadata::new(
+            "Italy",
+            Region::Europe,
+            Purpose::Egg,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Araucana,
+        BreedMetadata::new(
+            "Chile",
+            Region::SouthAmerica,
+            Purpose::Egg,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Aseel,
+        BreedMetadata::new(
+            "India",
+            Region::Asia,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Australorp,
+        BreedMetadata::new(
+            "Australia",
+            Region::Oceania,
+            Purpose::Egg,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Brahma,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Buckeye,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Dual,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Cochin,
+        BreedMetadata::new("China", Region::Asia, Purpose::Meat, SizeClass::Large, None),
+    ),
+    (
+        ChickenBreed::Cornish,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Delaware,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Dual,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Dorking,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Faverolles,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::JerseyGiant,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Leghorn,
+        BreedMetadata::new(
+            "Italy",
+            Region::Europe,
+            Purpose::Egg,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Marans,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Egg,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Minorca,
+        BreedMetadata::new(
+            "Spain",
+            Region::Europe,
+            Purpose::Egg,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::NewHampshireRed,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Dual,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Orpington,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::PlymouthRock,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Dual,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::RhodeIslandRed,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Dual,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Sasso,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Sebright,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Companion,
+            SizeClass::Miniature,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::SilkieBantam,
+        BreedMetadata::new(
+            "China",
+            Region::Asia,
+            Purpose::Companion,
+            SizeClass::Miniature,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Sussex,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Welsummer,
+        BreedMetadata::new(
+            "Netherlands",
+            Region::Europe,
+            Purpose::Egg,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ChickenBreed::Wyandotte,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Dual,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+];
+
+/// Alternative names a chicken breed is known by, in addition to its canonical name.
+const ALIASES: &[(ChickenBreed, &str)] = &[
+    (ChickenBreed::NakedNeck, "turken"),
+    (ChickenBreed::Orpington, "buff orpington"),
+    (ChickenBreed::Orpington, "lavender orpington"),
+    (ChickenBreed::Orpington, "buff"),
+    (ChickenBreed::SwissHen, "schweizerhuhn"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,7 +619,49 @@ mod tests {
         ];
 
         for (breed, expected) in breeds.iter() {
-            assert_eq!(ChickenBreed::from_str(*breed).unwrap(), *expected);
+            assert_eq!(ChickenBreed::from_str(breed).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = ChickenBreed::Ameraucana.metadata().unwrap();
+        assert_eq!(metadata.origin, "United States");
+        assert_eq!(metadata.purpose, Purpose::Egg);
+        assert_eq!(metadata.size_class, SizeClass::Small);
+
+        for breed in ChickenBreed::by_purpose(Purpose::Dual) {
+            assert_eq!(breed.metadata().unwrap().purpose, Purpose::Dual);
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let matches = ChickenBreed::fuzzy_search("Rhode Island");
+        assert_eq!(matches[0].breed, ChickenBreed::RhodeIslandRed);
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_from_str_suggestions() {
+        let err = ChickenBreed::from_str("Rhode Island").unwrap_err();
+        assert_eq!(err.suggestions[0], ChickenBreed::RhodeIslandRed.to_string());
+    }
+
+    #[test]
+    fn test_variant_names_are_unique() {
+        for breed in ChickenBreed::iter() {
+            assert_eq!(ChickenBreed::from_str(&breed.to_string()).unwrap(), breed);
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        // Variants are numbered in declaration order, so each one is listed at its own index.
+        for (index, breed) in ChickenBreed::iter().enumerate() {
+            assert_eq!(breed as usize, index);
         }
     }
 }
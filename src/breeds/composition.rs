@@ -0,0 +1,370 @@
+use super::{Breed, BreedParseError};
+use anyhow::{anyhow, ensure, Context, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// How far the fractions of a composition may drift from summing to one.
+const TOLERANCE: f64 = 1e-6;
+
+/// The largest denominator used when writing fractions, e.g. `1/256`.
+const MAX_DENOMINATOR: u32 = 256;
+
+/// The breed makeup of a crossbred or composite animal, as fractions of each breed.
+///
+/// Compositions are written as fraction and breed pairs, e.g. `1/2 AN 1/4 HH 1/4 BR`. Breeds
+/// are written with their registry abbreviation when they have one, and their canonical id
+/// otherwise. Fractions may also be written as percentages (`50%`) or decimals (`0.5`).
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::breeds::{BreedComposition, CattleBreed};
+/// use std::str::FromStr;
+///
+/// let sire = BreedComposition::purebred(CattleBreed::Angus);
+/// let dam = BreedComposition::from_str("1/2 HH 1/2 BR").unwrap();
+///
+/// let calf = BreedComposition::offspring(&sire, &dam);
+/// assert_eq!(calf.percentage(CattleBreed::Angus), 50.0);
+/// assert_eq!(calf.percentage(CattleBreed::Brahman), 25.0);
+/// assert_eq!(calf.to_string(), "1/2 AN 1/4 BR 1/4 HH");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BreedComposition<B: Ord> {
+    fractions: BTreeMap<B, f64>,
+}
+
+impl<B: Breed + Copy + Ord> BreedComposition<B> {
+    /// Creates a composition from breed and fraction pairs, where each fraction is between 0 and
+    /// 1 and the fractions sum to 1. Fractions for a breed listed more than once are added
+    /// together.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::{BreedComposition, CattleBreed};
+    ///
+    /// let brangus = BreedComposition::new([
+    ///     (CattleBreed::Angus, 0.625),
+    ///     (CattleBreed::Brahman, 0.375),
+    /// ]).unwrap();
+    /// assert_eq!(brangus.to_string(), "5/8 AN 3/8 BR");
+    ///
+    /// // Fractions that do not sum to 1 are rejected.
+    /// assert!(BreedComposition::new([(CattleBreed::Angus, 0.5)]).is_err());
+    /// ```
+    pub fn new(parts: impl IntoIterator<Item = (B, f64)>) -> Result<Self> {
+        let mut fractions = BTreeMap::new();
+        for (breed, fraction) in parts {
+            ensure!(
+                fraction > 0.0 && fraction <= 1.0 + TOLERANCE,
+                "Fraction of {} must be greater than 0 and at most 1.",
+                breed
+            );
+            *fractions.entry(breed).or_insert(0.0) += fraction;
+        }
+
+        let total: f64 = fractions.values().sum();
+        ensure!(
+            (total - 1.0).abs() <= TOLERANCE,
+            "Breed fractions must sum to 100%, but sum to {:.2}%.",
+            total * 100.0
+        );
+
+        Ok(BreedComposition { fractions })
+    }
+
+    /// Creates a composition from breed and percentage pairs, where the percentages sum to 100.
+    pub fn from_percentages(parts: impl IntoIterator<Item = (B, f64)>) -> Result<Self> {
+        BreedComposition::new(
+            parts
+                .into_iter()
+                .map(|(breed, percentage)| (breed, percentage / 100.0)),
+        )
+    }
+
+    /// Creates the composition of a purebred animal.
+    pub fn purebred(breed: B) -> Self {
+        BreedComposition {
+            fractions: BTreeMap::from([(breed, 1.0)]),
+        }
+    }
+
+    /// Calculates the expected composition of the offspring of a sire and dam, which inherit
+    /// half of their breed makeup from each parent.
+    pub fn offspring(sire: &Self, dam: &Self) -> Self {
+        let mut fractions = BTreeMap::new();
+        for (breed, fraction) in sire.fractions.iter().chain(dam.fractions.iter()) {
+            *fractions.entry(*breed).or_insert(0.0) += fraction / 2.0;
+        }
+
+        BreedComposition { fractions }
+    }
+
+    /// The fraction of the composition made up by a breed, from 0 to 1.
+    pub fn fraction(&self, breed: B) -> f64 {
+        self.fractions.get(&breed).copied().unwrap_or(0.0)
+    }
+
+    /// The percentage of the composition made up by a breed, from 0 to 100.
+    pub fn percentage(&self, breed: B) -> f64 {
+        self.fraction(breed) * 100.0
+    }
+
+    /// The breeds in the composition and their fractions, largest fraction first.
+    pub fn breeds(&self) -> Vec<(B, f64)> {
+        let mut breeds: Vec<(B, f64)> = self
+            .fractions
+            .iter()
+            .map(|(breed, fraction)| (*breed, *fraction))
+            .collect();
+        breeds.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        breeds
+    }
+
+    /// Whether the composition is a single breed.
+    pub fn is_purebred(&self) -> bool {
+        self.fractions.len() == 1
+    }
+}
+
+impl<B: Breed + Copy + Ord> fmt::Display for BreedComposition<B> {
+    /// Writes the composition in fraction and breed notation, e.g. `1/2 AN 1/4 HH 1/4 BR`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self
+            .breeds()
+            .iter()
+            .map(|(breed, fraction)| {
+                let breed = breed
+                    .abbreviation()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| breed.id());
+                format!("{} {}", format_fraction(*fraction), breed)
+            })
+            .collect();
+
+        f.write_str(&parts.join(" "))
+    }
+}
+
+/// Converts fraction and breed notation to a BreedComposition.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::breeds::{BreedComposition, SheepBreed};
+/// use std::str::FromStr;
+///
+/// let ewe: BreedComposition<SheepBreed> = BreedComposition::from_str("50% dorper 50% katahdin").unwrap();
+/// assert_eq!(ewe.fraction(SheepBreed::Dorper), 0.5);
+/// ```
+impl<B> FromStr for BreedComposition<B>
+where
+    B: Breed + Copy + Ord + FromStr<Err = BreedParseError>,
+{
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+        ensure!(!tokens.is_empty(), "Breed composition cannot be empty.");
+        ensure!(
+            tokens.len().is_multiple_of(2),
+            "Breed composition must be fraction and breed pairs, e.g. \"1/2 AN 1/2 HH\"."
+        );
+
+        let parts = tokens
+            .chunks(2)
+            .map(|pair| {
+                let fraction = parse_fraction(pair[0])?;
+                let breed = match B::from_abbreviation(pair[1]) {
+                    Some(breed) => breed,
+                    None => B::from_str(pair[1])?,
+                };
+                Ok((breed, fraction))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        BreedComposition::new(parts)
+    }
+}
+
+impl<B: Breed + Copy + Ord> Serialize for BreedComposition<B> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de, B> Deserialize<'de> for BreedComposition<B>
+where
+    B: Breed + Copy + Ord + FromStr<Err = BreedParseError>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        BreedComposition::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Parses a fraction written as `n/d`, a percentage or a decimal.
+fn parse_fraction(s: &str) -> Result<f64> {
+    let invalid = || format!("Invalid breed fraction: \"{}\"", s);
+
+    if let Some((numerator, denominator)) = s.split_once('/') {
+        let numerator: f64 = numerator.parse().with_context(invalid)?;
+        let denominator: f64 = denominator.parse().with_context(invalid)?;
+        ensure!(denominator > 0.0, invalid());
+        return Ok(numerator / denominator);
+    }
+
+    if let Some(percentage) = s.strip_suffix('%') {
+        let percentage: f64 = percentage.parse().with_context(invalid)?;
+        return Ok(percentage / 100.0);
+    }
+
+    s.parse().map_err(|_| anyhow!(invalid()))
+}
+
+/// Writes a fraction as `n/d` with the smallest exact denominator, or as a percentage when no
+/// small denominator fits.
+fn format_fraction(fraction: f64) -> String {
+    (1..=MAX_DENOMINATOR)
+        .find_map(|denominator| {
+            let numerator = fraction * denominator as f64;
+            ((numerator - numerator.round()).abs() <= TOLERANCE).then(|| {
+                match (numerator.round() as u32, denominator) {
+                    (numerator, 1) => numerator.to_string(),
+                    (numerator, denominator) => format!("{}/{}", numerator, denominator),
+                }
+            })
+        })
+        .unwrap_or_else(|| format!("{:.2}%", fraction * 100.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::breeds::{CattleBreed, GoatBreed};
+
+    #[test]
+    fn test_new() {
+        let composition = BreedComposition::from_percentages([
+            (CattleBreed::Angus, 50.0),
+            (CattleBreed::Hereford, 25.0),
+            (CattleBreed::Brahman, 25.0),
+        ])
+        .unwrap();
+
+        assert_eq!(composition.fraction(CattleBreed::Angus), 0.5);
+        assert_eq!(composition.percentage(CattleBreed::Hereford), 25.0);
+        assert_eq!(composition.percentage(CattleBreed::Jersey), 0.0);
+        assert!(!composition.is_purebred());
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        let invalid_cases = [
+            vec![(CattleBreed::Angus, 0.5), (CattleBreed::Hereford, 0.25)],
+            vec![(CattleBreed::Angus, 1.5), (CattleBreed::Hereford, -0.5)],
+            vec![(CattleBreed::Angus, 0.0), (CattleBreed::Hereford, 1.0)],
+            vec![],
+        ];
+
+        for parts in invalid_cases {
+            assert!(BreedComposition::new(parts).is_err());
+        }
+    }
+
+    #[test]
+    fn test_offspring() {
+        let sire = BreedComposition::purebred(CattleBreed::Angus);
+        let dam = BreedComposition::from_str("1/2 HH 1/2 BR").unwrap();
+        let calf = BreedComposition::offspring(&sire, &dam);
+
+        assert_eq!(
+            calf.breeds(),
+            vec![
+                (CattleBreed::Angus, 0.5),
+                (CattleBreed::Brahman, 0.25),
+                (CattleBreed::Hereford, 0.25),
+            ]
+        );
+
+        let backcross = BreedComposition::offspring(&sire, &calf);
+        assert_eq!(backcross.to_string(), "3/4 AN 1/8 BR 1/8 HH");
+    }
+
+    #[test]
+    fn test_to_string() {
+        let cases = [
+            (BreedComposition::purebred(CattleBreed::Angus), "1 AN"),
+            (
+                BreedComposition::new([(CattleBreed::Ankole, 0.5), (CattleBreed::Angus, 0.5)])
+                    .unwrap(),
+                "1/2 AN 1/2 ankole",
+            ),
+            (
+                BreedComposition::new([(CattleBreed::Angus, 0.3), (CattleBreed::Hereford, 0.7)])
+                    .unwrap(),
+                "7/10 HH 3/10 AN",
+            ),
+            (
+                BreedComposition::new([
+                    (CattleBreed::Angus, 0.123),
+                    (CattleBreed::Hereford, 0.877),
+                ])
+                .unwrap(),
+                "87.70% HH 12.30% AN",
+            ),
+        ];
+
+        for (composition, expected) in cases.iter() {
+            assert_eq!(composition.to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        let cases = [
+            ("1/2 AN 1/4 HH 1/4 BR", 0.5),
+            ("50% an 25% hh 25% br", 0.5),
+            ("0.5 angus 0.25 hereford 0.25 brahman", 0.5),
+            ("1/4 AN 1/4 AN 1/4 HH 1/4 BR", 0.5),
+        ];
+
+        for (notation, angus) in cases.iter() {
+            let composition: BreedComposition<CattleBreed> =
+                BreedComposition::from_str(notation).unwrap();
+            assert_eq!(composition.fraction(CattleBreed::Angus), *angus);
+            assert_eq!(composition.to_string(), "1/2 AN 1/4 BR 1/4 HH");
+        }
+
+        let goat: BreedComposition<GoatBreed> =
+            BreedComposition::from_str("3/4 boer 1/4 kiko").unwrap();
+        assert_eq!(goat.to_string(), "3/4 boer 1/4 kiko");
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        let invalid_cases = [
+            "",
+            "1/2 AN 1/2",
+            "1/2 AN 1/4 HH",
+            "half AN half HH",
+            "1/0 AN",
+            "1 XX",
+        ];
+
+        for notation in invalid_cases.iter() {
+            assert!(BreedComposition::<CattleBreed>::from_str(notation).is_err());
+        }
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let composition: BreedComposition<CattleBreed> =
+            BreedComposition::from_str("5/8 AN 3/8 BR").unwrap();
+        let json = serde_json::to_string(&composition).unwrap();
+        assert_eq!(json, "\"5/8 AN 3/8 BR\"");
+        assert_eq!(
+            serde_json::from_str::<BreedComposition<CattleBreed>>(&json).unwrap(),
+            composition
+        );
+    }
+}
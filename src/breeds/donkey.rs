@@ -1,31 +1,38 @@
+use super::fuzzy::{self, BreedMatch};
+use super::metadata::{self, BreedMetadata, Purpose, Region, SizeClass};
+use super::{Breed, BreedParseError};
+use crate::types::LivestockType;
 use inflector::Inflector;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
-/// An enum representing the different breeds of donkeys.
-///
-/// Initial data from: <https://breeds.okstate.edu/other-breeds-of-livestock/donkeys/>
-///
-/// # Examples
-/// ``` rust
-/// use livestock_rs::breeds::donkey::DonkeyBreed;
-///
-/// let breed = DonkeyBreed::Abyssinian;
-/// println!("{:?}", breed);
-/// ```
-#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub enum DonkeyBreed {
-    Abyssinian,
-    Anatolia,
-    LargeStandard,
-    MammothJackStock,
-    Mary,
-    Miniature,
-    Poitou,
-    Standard,
+breed_enum! {
+    /// An enum representing the different breeds of donkeys.
+    ///
+    /// Initial data from: <https://breeds.okstate.edu/other-breeds-of-livestock/donkeys/>
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::donkey::DonkeyBreed;
+    ///
+    /// let breed = DonkeyBreed::Abyssinian;
+    /// println!("{:?}", breed);
+    /// ```
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum DonkeyBreed {
+        Abyssinian,
+        Anatolia,
+        LargeStandard,
+        MammothJackStock,
+        Mary,
+        Miniature,
+        Poitou,
+        Standard,
+    }
 }
 
-impl ToString for DonkeyBreed {
+impl fmt::Display for DonkeyBreed {
     /// Converts the DonkeyBreed enum to a human readable string.
     ///
     /// # Examples
@@ -35,8 +42,8 @@ impl ToString for DonkeyBreed {
     /// let evenk = DonkeyBreed::Standard;
     /// println!("{}", evenk.to_string());
     /// ```
-    fn to_string(&self) -> String {
-        format!("{:?}", self).to_title_case()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format!("{:?}", self).to_title_case())
     }
 }
 
@@ -50,9 +57,8 @@ impl ToString for DonkeyBreed {
 /// let breed = DonkeyBreed::from_str("standard").unwrap();
 /// println!("{:?}", breed);
 /// ```
-
 impl FromStr for DonkeyBreed {
-    type Err = std::io::Error;
+    type Err = BreedParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "abyssinian" => Ok(DonkeyBreed::Abyssinian),
@@ -60,17 +66,200 @@ impl FromStr for DonkeyBreed {
             "large standard" => Ok(DonkeyBreed::LargeStandard),
             "mammoth jack stock" => Ok(DonkeyBreed::MammothJackStock),
             "mary" => Ok(DonkeyBreed::Mary),
-            "miniature" | "mini" => Ok(DonkeyBreed::Miniature),
+            "miniature" => Ok(DonkeyBreed::Miniature),
             "poitou" => Ok(DonkeyBreed::Poitou),
             "standard" => Ok(DonkeyBreed::Standard),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Invalid reindeer breed",
-            )),
+            _ => fuzzy::resolve(VARIANTS, s),
         }
     }
 }
 
+impl DonkeyBreed {
+    /// Returns every donkey breed, in declaration order.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::donkey::DonkeyBreed;
+    ///
+    /// let breeds = DonkeyBreed::all();
+    /// assert_eq!(breeds.len(), DonkeyBreed::count());
+    /// ```
+    pub fn all() -> &'static [DonkeyBreed] {
+        VARIANTS
+    }
+
+    /// Returns an iterator over every donkey breed, in declaration order.
+    pub fn iter() -> impl Iterator<Item = DonkeyBreed> {
+        VARIANTS.iter().copied()
+    }
+
+    /// Returns the number of donkey breeds.
+    pub fn count() -> usize {
+        VARIANTS.len()
+    }
+
+    /// Returns the donkey breeds whose names closely match the query, best match first.
+    ///
+    /// Case, diacritics and punctuation are ignored, and small typos are tolerated.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::donkey::DonkeyBreed;
+    ///
+    /// let matches = DonkeyBreed::fuzzy_search("Mamoth Jack");
+    /// assert_eq!(matches[0].breed, DonkeyBreed::MammothJackStock);
+    /// ```
+    pub fn fuzzy_search(query: &str) -> Vec<BreedMatch<DonkeyBreed>> {
+        fuzzy::search(VARIANTS, query)
+    }
+
+    /// Returns the donkey breeds raised primarily for the given purpose.
+    ///
+    /// Metadata has only been compiled for some donkey breeds, those returned by
+    /// [`DonkeyBreed::with_metadata`], so other breeds are left out even if they would match.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::donkey::DonkeyBreed;
+    /// use livestock_rs::breeds::metadata::Purpose;
+    ///
+    /// let breeds = DonkeyBreed::by_purpose(Purpose::Pack);
+    /// assert!(breeds.contains(&DonkeyBreed::Abyssinian));
+    /// ```
+    pub fn by_purpose(purpose: Purpose) -> Vec<DonkeyBreed> {
+        metadata::filter(METADATA, |metadata| metadata.purpose == purpose)
+    }
+
+    /// Returns the donkey breeds that originated in the given region.
+    ///
+    /// Metadata has only been compiled for some donkey breeds, those returned by
+    /// [`DonkeyBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_region(region: Region) -> Vec<DonkeyBreed> {
+        metadata::filter(METADATA, |metadata| metadata.region == region)
+    }
+
+    /// Returns the donkey breeds of the given size class.
+    ///
+    /// Metadata has only been compiled for some donkey breeds, those returned by
+    /// [`DonkeyBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_size_class(size_class: SizeClass) -> Vec<DonkeyBreed> {
+        metadata::filter(METADATA, |metadata| metadata.size_class == size_class)
+    }
+
+    /// Returns the donkey breeds that have metadata.
+    ///
+    /// Metadata covers the better documented breeds first, so this is usually a subset of
+    /// [`DonkeyBreed::all`].
+    pub fn with_metadata() -> Vec<DonkeyBreed> {
+        METADATA.iter().map(|(breed, _)| *breed).collect()
+    }
+}
+
+impl Breed for DonkeyBreed {
+    fn species(&self) -> LivestockType {
+        LivestockType::Donkey
+    }
+
+    fn metadata(&self) -> Option<BreedMetadata> {
+        metadata::lookup(METADATA, self)
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        ALIASES
+            .iter()
+            .filter(|(breed, _)| breed == self)
+            .map(|(_, alias)| *alias)
+            .collect()
+    }
+}
+
+/// Metadata for the donkey breeds with well documented characteristics.
+const METADATA: &[(DonkeyBreed, BreedMetadata)] = &[
+    (
+        DonkeyBreed::Abyssinian,
+        BreedMetadata::new(
+            "Ethiopia",
+            Region::Africa,
+            Purpose::Pack,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        DonkeyBreed::Anatolia,
+        BreedMetadata::new(
+            "Turkey",
+            Region::MiddleEast,
+            Purpose::Pack,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        DonkeyBreed::LargeStandard,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Pack,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        DonkeyBreed::MammothJackStock,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Draft,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        DonkeyBreed::Mary,
+        BreedMetadata::new(
+            "Turkmenistan",
+            Region::Asia,
+            Purpose::Pack,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        DonkeyBreed::Miniature,
+        BreedMetadata::new(
+            "Italy",
+            Region::Europe,
+            Purpose::Companion,
+            SizeClass::Miniature,
+            None,
+        ),
+    ),
+    (
+        DonkeyBreed::Poitou,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Draft,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        DonkeyBreed::Standard,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Pack,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+];
+
+/// Alternative names a donkey breed is known by, in addition to its canonical name.
+const ALIASES: &[(DonkeyBreed, &str)] = &[(DonkeyBreed::Miniature, "mini")];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,7 +297,52 @@ mod tests {
         ];
 
         for (breed, expected) in breeds.iter() {
-            assert_eq!(DonkeyBreed::from_str(*breed).unwrap(), *expected);
+            assert_eq!(DonkeyBreed::from_str(breed).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = DonkeyBreed::Abyssinian.metadata().unwrap();
+        assert_eq!(metadata.origin, "Ethiopia");
+        assert_eq!(metadata.purpose, Purpose::Pack);
+        assert_eq!(metadata.size_class, SizeClass::Small);
+
+        for breed in DonkeyBreed::by_purpose(Purpose::Pack) {
+            assert_eq!(breed.metadata().unwrap().purpose, Purpose::Pack);
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let matches = DonkeyBreed::fuzzy_search("Mamoth Jack");
+        assert_eq!(matches[0].breed, DonkeyBreed::MammothJackStock);
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_from_str_suggestions() {
+        let err = DonkeyBreed::from_str("Mamoth Jack").unwrap_err();
+        assert_eq!(
+            err.suggestions[0],
+            DonkeyBreed::MammothJackStock.to_string()
+        );
+    }
+
+    #[test]
+    fn test_variant_names_are_unique() {
+        for breed in DonkeyBreed::iter() {
+            assert_eq!(DonkeyBreed::from_str(&breed.to_string()).unwrap(), breed);
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        // Variants are numbered in declaration order, so each one is listed at its own index.
+        for (index, breed) in DonkeyBreed::iter().enumerate() {
+            assert_eq!(breed as usize, index);
         }
     }
 }
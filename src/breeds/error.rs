@@ -0,0 +1,90 @@
+use crate::types::LivestockType;
+use std::error::Error;
+use std::fmt;
+
+/// The error returned when a string cannot be parsed as a breed.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::breeds::CattleBreed;
+/// use livestock_rs::types::LivestockType;
+/// use std::str::FromStr;
+///
+/// let err = CattleBreed::from_str("Charlois").unwrap_err();
+/// assert_eq!(err.species, Some(LivestockType::Cattle));
+/// assert_eq!(err.input, "Charlois");
+/// assert_eq!(err.suggestions[0], "Charolais");
+/// assert_eq!(err.to_string(), "Invalid cattle breed: \"Charlois\". Did you mean Charolais?");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BreedParseError {
+    /// The species the input was parsed as, or `None` if the species itself was not recognized.
+    pub species: Option<LivestockType>,
+    /// The string that failed to parse.
+    pub input: String,
+    /// Names of the closest matching breeds, best match first.
+    pub suggestions: Vec<String>,
+}
+
+impl BreedParseError {
+    pub(crate) fn new(species: Option<LivestockType>, input: &str) -> Self {
+        BreedParseError {
+            species,
+            input: input.to_string(),
+            suggestions: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for BreedParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.species {
+            Some(species) => write!(
+                f,
+                "Invalid {} breed: \"{}\"",
+                format!("{:?}", species).to_lowercase(),
+                self.input
+            )?,
+            None => write!(f, "Invalid breed: \"{}\"", self.input)?,
+        }
+
+        if !self.suggestions.is_empty() {
+            write!(f, ". Did you mean {}?", self.suggestions.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for BreedParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let cases = [
+            (
+                BreedParseError::new(Some(LivestockType::Goat), "Nubain"),
+                "Invalid goat breed: \"Nubain\"",
+            ),
+            (
+                BreedParseError::new(None, "unicorn:angus"),
+                "Invalid breed: \"unicorn:angus\"",
+            ),
+            (
+                BreedParseError {
+                    species: Some(LivestockType::Cattle),
+                    input: "Herford".to_string(),
+                    suggestions: vec!["Hereford".to_string(), "Black Hereford".to_string()],
+                },
+                "Invalid cattle breed: \"Herford\". Did you mean Hereford, Black Hereford?",
+            ),
+        ];
+
+        for (err, expected) in cases.iter() {
+            assert_eq!(err.to_string(), *expected);
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use super::{AnyBreed, Breed, BreedParseError};
+use serde::Serialize;
+
+/// The lowest score a breed needs to be returned by a fuzzy search.
+pub const MIN_SCORE: f64 = 0.6;
+
+/// The number of suggestions carried by a `BreedParseError`.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// A breed matched by a fuzzy search, along with how closely it matched.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct BreedMatch<B> {
+    pub breed: B,
+    /// How closely the breed matched, from `0.0` (nothing in common) to `1.0` (identical once
+    /// case, diacritics and punctuation are ignored).
+    pub score: f64,
+}
+
+impl<B: Into<AnyBreed>> BreedMatch<B> {
+    /// Converts a match for a species' breed into a match for `AnyBreed`.
+    pub fn into_any(self) -> BreedMatch<AnyBreed> {
+        BreedMatch {
+            breed: self.breed.into(),
+            score: self.score,
+        }
+    }
+}
+
+/// Ranks breeds by how closely their names match the query.
+///
+/// Names are compared after folding case, diacritics and punctuation, using the edit distance
+/// between the folded strings. Only breeds scoring at least `MIN_SCORE` are returned, best
+/// match first.
+pub(crate) fn search<B: Breed + Copy>(breeds: &[B], query: &str) -> Vec<BreedMatch<B>> {
+    let query = fold(query);
+    let mut matches: Vec<BreedMatch<B>> = breeds
+        .iter()
+        .map(|breed| BreedMatch {
+            breed: *breed,
+            score: candidate_names(breed)
+                .iter()
+                .map(|name| similarity(&query, name))
+                .fold(0.0, f64::max),
+        })
+        .filter(|m| m.score >= MIN_SCORE)
+        .collect();
+
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    matches
+}
+
+/// Resolves input that did not match any of a breed's spellings exactly.
+///
+/// Input identical to a breed name or alias once case, diacritics and punctuation are folded is
+/// accepted; anything else is rejected with a `BreedParseError` carrying the closest
+/// suggestions.
+pub(crate) fn resolve<B: Breed + Copy>(breeds: &[B], input: &str) -> Result<B, BreedParseError> {
+    let folded = fold(input);
+    if let Some(breed) = breeds
+        .iter()
+        .find(|breed| candidate_names(*breed).contains(&folded))
+    {
+        return Ok(*breed);
+    }
+
+    Err(BreedParseError {
+        species: breeds.first().map(|breed| breed.species()),
+        input: input.to_string(),
+        suggestions: search(breeds, input)
+            .iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|m| m.breed.name())
+            .collect(),
+    })
+}
+
+/// The folded names a breed can be matched against.
+fn candidate_names<B: Breed>(breed: &B) -> Vec<String> {
+    let mut names = vec![fold(&breed.name()), fold(&breed.id())];
+    names.extend(breed.aliases().iter().map(|alias| fold(alias)));
+    names
+}
+
+/// Lowercases a string, strips diacritics and replaces punctuation with spaces.
+pub(crate) fn fold(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.chars().flat_map(char::to_lowercase) {
+        match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' => folded.push('a'),
+            'ç' | 'č' | 'ć' => folded.push('c'),
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ę' => folded.push('e'),
+            'ì' | 'í' | 'î' | 'ï' | 'ī' => folded.push('i'),
+            'ñ' | 'ń' => folded.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' => folded.push('o'),
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' => folded.push('u'),
+            'ý' | 'ÿ' => folded.push('y'),
+            'š' | 'ś' => folded.push('s'),
+            'ž' | 'ź' | 'ż' => folded.push('z'),
+            'ł' => folded.push('l'),
+            'ß' => folded.push_str("ss"),
+            'æ' => folded.push_str("ae"),
+            'œ' => folded.push_str("oe"),
+            '&' => folded.push_str(" and "),
+            c if c.is_alphanumeric() => folded.push(c),
+            _ => folded.push(' '),
+        }
+    }
+
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// How much a word-by-word match is worth relative to a whole-name match.
+const WORD_MATCH_WEIGHT: f64 = 0.9;
+
+/// Scores a folded query against a folded breed name from `0.0` to `1.0`.
+///
+/// The query is compared against the whole name, and word by word so that a partial name such
+/// as "Somali" still ranks "Somali Dromedary" highly.
+fn similarity(query: &str, name: &str) -> f64 {
+    let words: Vec<&str> = name.split(' ').collect();
+    let query_words: Vec<&str> = query.split(' ').collect();
+    let word_score = query_words
+        .iter()
+        .map(|query_word| {
+            words
+                .iter()
+                .map(|word| string_similarity(query_word, word))
+                .fold(0.0, f64::max)
+        })
+        .sum::<f64>()
+        / query_words.len() as f64;
+
+    string_similarity(query, name).max(word_score * WORD_MATCH_WEIGHT)
+}
+
+/// Scores two strings from `0.0` to `1.0` by their edit distance.
+fn string_similarity(a: &str, b: &str) -> f64 {
+    let len = a.chars().count().max(b.chars().count());
+    if len == 0 {
+        return 0.0;
+    }
+
+    1.0 - edit_distance(a, b) as f64 / len as f64
+}
+
+/// The optimal string alignment distance between two strings: the number of insertions,
+/// deletions, substitutions and adjacent transpositions needed to turn one into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            rows[i][j] = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                rows[i][j] = rows[i][j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold() {
+        let cases = [
+            ("Canindé", "caninde"),
+            ("Blonde d'Aquitaine", "blonde d aquitaine"),
+            ("Jezersko-Solčava", "jezersko solcava"),
+            ("Oxford Sandy & Black", "oxford sandy and black"),
+            (
+                "  St. Croix  (Virgin Island White) ",
+                "st croix virgin island white",
+            ),
+        ];
+
+        for (input, expected) in cases.iter() {
+            assert_eq!(fold(input), *expected);
+        }
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        let cases = [
+            ("", "", 0),
+            ("angus", "angus", 0),
+            ("angus", "agnus", 1),
+            ("charlois", "charolais", 2),
+            ("boer", "", 4),
+        ];
+
+        for (a, b, expected) in cases.iter() {
+            assert_eq!(edit_distance(a, b), *expected);
+        }
+    }
+}
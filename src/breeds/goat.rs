@@ -1,106 +1,113 @@
+use super::fuzzy::{self, BreedMatch};
+use super::metadata::{self, BreedMetadata, Horns, Purpose, Region, SizeClass};
+use super::{Breed, BreedParseError};
+use crate::types::LivestockType;
 use inflector::Inflector;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
-/// An enum representing the different breeds of goats.
-///
-/// Initial data from: <https://breeds.okstate.edu/goats/>
-///
-/// # Examples
-/// ``` rust
-/// use livestock_rs::breeds::goat::GoatBreed;
-///
-/// let breed = GoatBreed::Alpine;
-/// println!("{:?}", breed);
-/// ```
-#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub enum GoatBreed {
-    Alpine,
-    AltaiMountain,
-    AngloNubian,
-    Angora,
-    Appenzell,
-    ArapawaIsland,
-    Australian,
-    Bagot,
-    Barbari,
-    Beetal,
-    BelgianFawn,
-    Benadir,
-    Bhuj,
-    Boer,
-    Booted,
-    BritishAlpine,
-    BrownShorthair,
-    CanaryIsland,
-    Caninde,
-    Cashmere,
-    Chapar,
-    Corsican,
-    DaeraDinPanah,
-    Damani,
-    DanishLandrace,
-    Don,
-    DutchLandrace,
-    Erzgebirge,
-    FinnishLandrace,
-    GoldenGuernsey,
-    GrisonsStriped,
-    Hailun,
-    Haimen,
-    Hasi,
-    Hejazi,
-    HexiCashmere,
-    Hongtong,
-    Huaipi,
-    Huaitoutala,
-    HungarianImproved,
-    Irish,
-    JiningGrey,
-    Kaghani,
-    KalahariRed,
-    Kamori,
-    Kiko,
-    Kinder,
-    LaMancha,
-    Laoshan,
-    Moxoto,
-    MurciaGranada,
-    Myotonic, // Wooden Leg
-    Nachi,
-    NigerianDwarf,
-    Norwegian,
-    Oberhasli,
-    Peacock,
-    Philippine,
-    Poitou,
-    Pygmy,
-    Pyrenean,
-    Qinshan,
-    Repartida,
-    RussianWhiteAndGorki,
-    Saanen,
-    Sahelian,
-    Savanna,
-    SanClemente,
-    Somali,
-    Spanish,
-    SRD,
-    SwedishLandrace,
-    Thuringian,
-    Toggenburg,
-    UzbekBlack,
-    ValaisBlackneck,
-    Verata,
-    WestAfricanDwarf,
-    WhiteShorthair,
-    Xinjiang,
-    Xuhai,
-    YemenMountain,
-    Zhongwei,
+breed_enum! {
+    /// An enum representing the different breeds of goats.
+    ///
+    /// Initial data from: <https://breeds.okstate.edu/goats/>
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::goat::GoatBreed;
+    ///
+    /// let breed = GoatBreed::Alpine;
+    /// println!("{:?}", breed);
+    /// ```
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum GoatBreed {
+        Alpine,
+        AltaiMountain,
+        AngloNubian,
+        Angora,
+        Appenzell,
+        ArapawaIsland,
+        Australian,
+        Bagot,
+        Barbari,
+        Beetal,
+        BelgianFawn,
+        Benadir,
+        Bhuj,
+        Boer,
+        Booted,
+        BritishAlpine,
+        BrownShorthair,
+        CanaryIsland,
+        Caninde,
+        Cashmere,
+        Chapar,
+        Corsican,
+        DaeraDinPanah,
+        Damani,
+        DanishLandrace,
+        Don,
+        DutchLandrace,
+        Erzgebirge,
+        FinnishLandrace,
+        GoldenGuernsey,
+        GrisonsStriped,
+        Hailun,
+        Haimen,
+        Hasi,
+        Hejazi,
+        HexiCashmere,
+        Hongtong,
+        Huaipi,
+        Huaitoutala,
+        HungarianImproved,
+        Irish,
+        JiningGrey,
+        Kaghani,
+        KalahariRed,
+        Kamori,
+        Kiko,
+        Kinder,
+        LaMancha,
+        Laoshan,
+        Moxoto,
+        MurciaGranada,
+        Myotonic, // Wooden Leg
+        Nachi,
+        NigerianDwarf,
+        Norwegian,
+        Oberhasli,
+        Peacock,
+        Philippine,
+        Poitou,
+        Pygmy,
+        Pyrenean,
+        Qinshan,
+        Repartida,
+        RussianWhiteAndGorki,
+        Saanen,
+        Sahelian,
+        Savanna,
+        SanClemente,
+        Somali,
+        Spanish,
+        SRD,
+        SwedishLandrace,
+        Thuringian,
+        Toggenburg,
+        UzbekBlack,
+        ValaisBlackneck,
+        Verata,
+        WestAfricanDwarf,
+        WhiteShorthair,
+        Xinjiang,
+        Xuhai,
+        YemenMountain,
+        Zhongwei,
+    }
 }
 
-impl ToString for GoatBreed {
+impl fmt::Display for GoatBreed {
     /// Converts the GoatBreed enum to a human readable string.
     ///
     /// # Examples
@@ -113,13 +120,13 @@ impl ToString for GoatBreed {
     /// let canindé = GoatBreed::Caninde;
     /// println!("{}", canindé.to_string());
     /// ```
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            GoatBreed::AngloNubian => "Anglo-Nubian".to_string(),
-            GoatBreed::Caninde => "Canindé".to_string(),
-            GoatBreed::MurciaGranada => "Murcia-Granada".to_string(),
-            GoatBreed::Myotonic => "Myotonic (Wooden Leg)".to_string(),
-            _ => format!("{:?}", self).to_title_case(),
+            GoatBreed::AngloNubian => f.write_str("Anglo-Nubian"),
+            GoatBreed::Caninde => f.write_str("Canindé"),
+            GoatBreed::MurciaGranada => f.write_str("Murcia-Granada"),
+            GoatBreed::Myotonic => f.write_str("Myotonic (Wooden Leg)"),
+            _ => f.write_str(&format!("{:?}", self).to_title_case()),
         }
     }
 }
@@ -137,14 +144,13 @@ impl ToString for GoatBreed {
 /// let breed = GoatBreed::from_str("Anglo-Nubian").unwrap();
 /// println!("{:?}", breed);
 /// ```
-
 impl FromStr for GoatBreed {
-    type Err = std::io::Error;
+    type Err = BreedParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "alpine" => Ok(GoatBreed::Alpine),
             "altai mountain" => Ok(GoatBreed::AltaiMountain),
-            "anglo-nubian" | "anglo nubian" => Ok(GoatBreed::AngloNubian),
+            "anglo-nubian" => Ok(GoatBreed::AngloNubian),
             "angora" => Ok(GoatBreed::Angora),
             "appenzell" => Ok(GoatBreed::Appenzell),
             "arapawa island" => Ok(GoatBreed::ArapawaIsland),
@@ -160,7 +166,7 @@ impl FromStr for GoatBreed {
             "british alpine" => Ok(GoatBreed::BritishAlpine),
             "brown shorthair" => Ok(GoatBreed::BrownShorthair),
             "canary island" => Ok(GoatBreed::CanaryIsland),
-            "canindé" | "caninde" => Ok(GoatBreed::Caninde),
+            "canindé" => Ok(GoatBreed::Caninde),
             "cashmere" => Ok(GoatBreed::Cashmere),
             "chapar" => Ok(GoatBreed::Chapar),
             "corsican" => Ok(GoatBreed::Corsican),
@@ -192,10 +198,8 @@ impl FromStr for GoatBreed {
             "la mancha" => Ok(GoatBreed::LaMancha),
             "laoshan" => Ok(GoatBreed::Laoshan),
             "moxoto" => Ok(GoatBreed::Moxoto),
-            "murcia granada" | "murcia-granada" => Ok(GoatBreed::MurciaGranada),
-            "myotonic" | "wooden leg" | "myotonic wooden leg" | "myotonic (wooden leg)" => {
-                Ok(GoatBreed::Myotonic)
-            }
+            "murcia granada" => Ok(GoatBreed::MurciaGranada),
+            "myotonic" => Ok(GoatBreed::Myotonic),
             "nachi" => Ok(GoatBreed::Nachi),
             "nigerian dwarf" => Ok(GoatBreed::NigerianDwarf),
             "norwegian" => Ok(GoatBreed::Norwegian),
@@ -207,9 +211,7 @@ impl FromStr for GoatBreed {
             "pyrenean" => Ok(GoatBreed::Pyrenean),
             "qinshan" => Ok(GoatBreed::Qinshan),
             "repartida" => Ok(GoatBreed::Repartida),
-            "russian white and gorki" | "russian white & gorki" => {
-                Ok(GoatBreed::RussianWhiteAndGorki)
-            }
+            "russian white and gorki" => Ok(GoatBreed::RussianWhiteAndGorki),
             "saanen" => Ok(GoatBreed::Saanen),
             "sahelian" => Ok(GoatBreed::Sahelian),
             "savanna" => Ok(GoatBreed::Savanna),
@@ -229,14 +231,330 @@ impl FromStr for GoatBreed {
             "xuhai" => Ok(GoatBreed::Xuhai),
             "yemen mountain" => Ok(GoatBreed::YemenMountain),
             "zhongwei" => Ok(GoatBreed::Zhongwei),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Unknown breed",
-            )),
+            _ => fuzzy::resolve(VARIANTS, s),
         }
     }
 }
 
+impl GoatBreed {
+    /// Returns every goat breed, in declaration order.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::goat::GoatBreed;
+    ///
+    /// let breeds = GoatBreed::all();
+    /// assert_eq!(breeds.len(), GoatBreed::count());
+    /// ```
+    pub fn all() -> &'static [GoatBreed] {
+        VARIANTS
+    }
+
+    /// Returns an iterator over every goat breed, in declaration order.
+    pub fn iter() -> impl Iterator<Item = GoatBreed> {
+        VARIANTS.iter().copied()
+    }
+
+    /// Returns the number of goat breeds.
+    pub fn count() -> usize {
+        VARIANTS.len()
+    }
+
+    /// Returns the goat breeds whose names closely match the query, best match first.
+    ///
+    /// Case, diacritics and punctuation are ignored, and small typos are tolerated.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::goat::GoatBreed;
+    ///
+    /// let matches = GoatBreed::fuzzy_search("Nubain");
+    /// assert_eq!(matches[0].breed, GoatBreed::AngloNubian);
+    /// ```
+    pub fn fuzzy_search(query: &str) -> Vec<BreedMatch<GoatBreed>> {
+        fuzzy::search(VARIANTS, query)
+    }
+
+    /// Returns the goat breeds raised primarily for the given purpose.
+    ///
+    /// Metadata has only been compiled for some goat breeds, those returned by
+    /// [`GoatBreed::with_metadata`], so other breeds are left out even if they would match.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::goat::GoatBreed;
+    /// use livestock_rs::breeds::metadata::Purpose;
+    ///
+    /// let breeds = GoatBreed::by_purpose(Purpose::Meat);
+    /// assert!(breeds.contains(&GoatBreed::Boer));
+    /// ```
+    pub fn by_purpose(purpose: Purpose) -> Vec<GoatBreed> {
+        metadata::filter(METADATA, |metadata| metadata.purpose == purpose)
+    }
+
+    /// Returns the goat breeds that originated in the given region.
+    ///
+    /// Metadata has only been compiled for some goat breeds, those returned by
+    /// [`GoatBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_region(region: Region) -> Vec<GoatBreed> {
+        metadata::filter(METADATA, |metadata| metadata.region == region)
+    }
+
+    /// Returns the goat breeds of the given size class.
+    ///
+    /// Metadata has only been compiled for some goat breeds, those returned by
+    /// [`GoatBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_size_class(size_class: SizeClass) -> Vec<GoatBreed> {
+        metadata::filter(METADATA, |metadata| metadata.size_class == size_class)
+    }
+
+    /// Returns the goat breeds that have metadata.
+    ///
+    /// Metadata covers the better documented breeds first, so this is usually a subset of
+    /// [`GoatBreed::all`].
+    pub fn with_metadata() -> Vec<GoatBreed> {
+        METADATA.iter().map(|(breed, _)| *breed).collect()
+    }
+}
+
+impl Breed for GoatBreed {
+    fn species(&self) -> LivestockType {
+        LivestockType::Goat
+    }
+
+    fn metadata(&self) -> Option<BreedMetadata> {
+        metadata::lookup(METADATA, self)
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        ALIASES
+            .iter()
+            .filter(|(breed, _)| breed == self)
+            .map(|(_, alias)| *alias)
+            .collect()
+    }
+}
+
+/// Metadata for the goat breeds with well documented characteristics.
+const METADATA: &[(GoatBreed, BreedMetadata)] = &[
+    (
+        GoatBreed::Alpine,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Dairy,
+            SizeClass::Medium,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        GoatBreed::AngloNubian,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Large,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        GoatBreed::Angora,
+        BreedMetadata::new(
+            "Turkey",
+            Region::MiddleEast,
+            Purpose::Fiber,
+            SizeClass::Small,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::Barbari,
+        BreedMetadata::new(
+            "India",
+            Region::Asia,
+            Purpose::Dual,
+            SizeClass::Small,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::Beetal,
+        BreedMetadata::new(
+            "Pakistan",
+            Region::Asia,
+            Purpose::Dual,
+            SizeClass::Large,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::Boer,
+        BreedMetadata::new(
+            "South Africa",
+            Region::Africa,
+            Purpose::Meat,
+            SizeClass::Large,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::Cashmere,
+        BreedMetadata::new(
+            "Central Asia",
+            Region::Asia,
+            Purpose::Fiber,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::KalahariRed,
+        BreedMetadata::new(
+            "South Africa",
+            Region::Africa,
+            Purpose::Meat,
+            SizeClass::Large,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::Kiko,
+        BreedMetadata::new(
+            "New Zealand",
+            Region::Oceania,
+            Purpose::Meat,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::LaMancha,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Dairy,
+            SizeClass::Medium,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        GoatBreed::Myotonic,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Small,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::NigerianDwarf,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Dairy,
+            SizeClass::Miniature,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        GoatBreed::Oberhasli,
+        BreedMetadata::new(
+            "Switzerland",
+            Region::Europe,
+            Purpose::Dairy,
+            SizeClass::Medium,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        GoatBreed::Pygmy,
+        BreedMetadata::new(
+            "West Africa",
+            Region::Africa,
+            Purpose::Meat,
+            SizeClass::Miniature,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::Saanen,
+        BreedMetadata::new(
+            "Switzerland",
+            Region::Europe,
+            Purpose::Dairy,
+            SizeClass::Large,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        GoatBreed::Sahelian,
+        BreedMetadata::new(
+            "West Africa",
+            Region::Africa,
+            Purpose::Dual,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::Savanna,
+        BreedMetadata::new(
+            "South Africa",
+            Region::Africa,
+            Purpose::Meat,
+            SizeClass::Large,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::Somali,
+        BreedMetadata::new(
+            "Somalia",
+            Region::Africa,
+            Purpose::Meat,
+            SizeClass::Small,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::Spanish,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        GoatBreed::Toggenburg,
+        BreedMetadata::new(
+            "Switzerland",
+            Region::Europe,
+            Purpose::Dairy,
+            SizeClass::Medium,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        GoatBreed::WestAfricanDwarf,
+        BreedMetadata::new(
+            "West Africa",
+            Region::Africa,
+            Purpose::Meat,
+            SizeClass::Miniature,
+            Some(Horns::Horned),
+        ),
+    ),
+];
+
+/// Alternative names a goat breed is known by, in addition to its canonical name.
+const ALIASES: &[(GoatBreed, &str)] = &[
+    (GoatBreed::Myotonic, "wooden leg"),
+    (GoatBreed::Myotonic, "myotonic wooden leg"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -274,4 +592,46 @@ mod tests {
             assert_eq!(GoatBreed::from_str(breed).unwrap(), *expected);
         }
     }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = GoatBreed::Alpine.metadata().unwrap();
+        assert_eq!(metadata.origin, "France");
+        assert_eq!(metadata.purpose, Purpose::Dairy);
+        assert_eq!(metadata.size_class, SizeClass::Medium);
+
+        for breed in GoatBreed::by_purpose(Purpose::Meat) {
+            assert_eq!(breed.metadata().unwrap().purpose, Purpose::Meat);
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let matches = GoatBreed::fuzzy_search("Nubain");
+        assert_eq!(matches[0].breed, GoatBreed::AngloNubian);
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_from_str_suggestions() {
+        let err = GoatBreed::from_str("Nubain").unwrap_err();
+        assert_eq!(err.suggestions[0], GoatBreed::AngloNubian.to_string());
+    }
+
+    #[test]
+    fn test_variant_names_are_unique() {
+        for breed in GoatBreed::iter() {
+            assert_eq!(GoatBreed::from_str(&breed.to_string()).unwrap(), breed);
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        // Variants are numbered in declaration order, so each one is listed at its own index.
+        for (index, breed) in GoatBreed::iter().enumerate() {
+            assert_eq!(breed as usize, index);
+        }
+    }
 }
@@ -1,240 +1,247 @@
+use super::fuzzy::{self, BreedMatch};
+use super::metadata::{self, BreedMetadata, Purpose, Region, SizeClass};
+use super::{Breed, BreedParseError};
+use crate::types::LivestockType;
 use inflector::Inflector;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
-/// An enum representing the different breeds of,.
-///
-/// Initial data from: <https://breeds.okstate.edu/horses/>
-///
-/// # Examples
-/// ``` rust
-/// use livestock_rs::breeds::horse::HorseBreed;
-///
-/// let breed = HorseBreed::Racking;
-/// println!("{:?}", breed);
-/// ```
-#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub enum HorseBreed {
-    Abyssinian,
-    AkhalTeke,
-    Albanian,
-    Altai,
-    AmericanCreamDraft,
-    AmericanCremeandWhite,
-    AmericanWalking,
-    Andalusian,
-    Andravida,
-    AngloKabarda,
-    Appaloosa,
-    Araappaloosa,
-    Arabian,
-    Ardennes,
-    ArgentineCriollo,
-    Asturian,
-    AustralianBrumby,
-    AustralianStock,
-    Azteca,
-    Balearic,
-    Baluchi,
-    Banker,
-    Banei,
-    Barb,
-    Bashkir,
-    BashkirCurly,
-    Basotho,
-    Belgian,
-    Bhirum,
-    Bhotia,
-    Boer,
-    BosnianMountain,
-    Breton,
-    Buckskin,
-    Budyonny,
-    ByelorussianHarness,
-    Camargue,
-    Campolina,
-    Canadian,
-    Carthusian,
-    Caspian,
-    CayuseIndian,
-    Cheju,
-    ChileanCorralero,
-    Chincoteague,
-    ClevelandBay,
-    Clydesdale,
-    ColoradoRanger,
-    Connemara,
-    Criollo, // (Uruguay)
-    Crioulo,
-    Dales,
-    Danube,
-    Dartmoor,
-    Deliboz,
-    Djerma,
-    Dole,
-    Dongola,
-    Dulmen,
-    DutchDraft,
-    DutchWarmblood,
-    EastBulgarian,
-    Egyptian,
-    Eriskay,
-    EstonianNative,
-    Exmoor,
-    Faeroes,
-    Falabella,
-    Fell,
-    Finnhorses,
-    Fleuve,
-    FloridaCracker,
-    Fouta,
-    Frederiksborg,
-    FrenchSaddlebred,
-    FrenchTrotter,
-    Friesian,
-    Galiceno,
-    Galician,
-    Gelderlander,
-    Gidran,
-    GoldenAmericaSaddlebred,
-    Gotland,
-    Groningen,
-    Guangxi,
-    Hackney,
-    Haflinger,
-    Hanoverian,
-    Hequ,
-    Highland,
-    Hokkaido,
-    Hucul,
-    HungarianWarmblood,
-    Icelandic,
-    Iomud,
-    IrishDraught,
-    Jinzhou,
-    Jutland,
-    Kabarda,
-    Karabair,
-    Karabakh,
-    Kazakh,
-    KerryBog,
-    KigerMustang,
-    Kirdi,
-    KisberFelver,
-    Kiso,
-    Kladruby,
-    Knabstrup,
-    Kushum,
-    Kustanai,
-    Latvian,
-    LithuanianHeavyDraft,
-    Lipizzan,
-    Lokai,
-    Losino,
-    Lusitano,
-    Malopolski,
-    Mangalarga,
-    Marwari,
-    MBayar,
-    Merens,
-    Messara,
-    Miniature,
-    Misaki,
-    MissouriFoxTrotting,
-    Miyako,
-    Mongolian,
-    Morab,
-    Morgan,
-    Moyle,
-    Mustang,
-    Murgese,
-    NationalShow,
-    NewForest,
-    NewKirgiz,
-    Newfoundland,
-    Nokota,
-    Noma,
-    Nooitgedacht,
-    Noric,
-    Nordland,
-    NortheasternCrioulo,
-    NorthSwedish,
-    NorwegianFjord,
-    Ob,
-    Oldenburg,
-    OrlovTrotter,
-    Paint,
-    Palomino,
-    PantaneiroCrioulo,
-    PasoFino,
-    Percheron,
-    PeruvianPaso,
-    Pindos,
-    Pinia,
-    Pintabian,
-    Pinto,
-    PolishKonik,
-    PonyOfTheAmericas,
-    Pottok,
-    Przewalski,
-    PyreneanTarpan,
-    Qatgani,
-    Quarab,
-    Quarter,
-    Racking,
-    RockyMountain,
-    RussianDon,
-    RussianHeavyDraft,
-    RussianTrotter,
-    Saddlebred,
-    Sanhe,
-    SchleswigerHeavyDraft,
-    SchwarzwalderFuchs,
-    SelleFrancais,
-    Shagya,
-    Shetland,
-    Shire,
-    SingleFooting,
-    Skyros,
-    Somali,
-    Sorraia,
-    SovietHeavyDraft,
-    SpanishBarb,
-    SpanishMustang,
-    SpanishNorman,
-    Standardbred,
-    SudanCountryBred,
-    Suffolk,
-    SwedishWarmblood,
-    Taishuh,
-    Tarpan,
-    Tawleed,
-    TennesseeWalking,
-    Tersk,
-    Thessalian,
-    Thoroughbred,
-    Tokara,
-    Tori,
-    Trakehner,
-    UkrainianSaddle,
-    Vlaamperd,
-    VladimirHeavyDraft,
-    Vyatka,
-    Welara,
-    WelshPonyAndCob,
-    WestAfricanBarb,
-    WesternSudan,
-    Wielkopolski,
-    Xilingol,
-    Yakut,
-    Yanqi,
-    Yili,
-    Yonaguni,
-    Zaniskari,
-    Zhemaichu,
+breed_enum! {
+    /// An enum representing the different breeds of,.
+    ///
+    /// Initial data from: <https://breeds.okstate.edu/horses/>
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::horse::HorseBreed;
+    ///
+    /// let breed = HorseBreed::Racking;
+    /// println!("{:?}", breed);
+    /// ```
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum HorseBreed {
+        Abyssinian,
+        AkhalTeke,
+        Albanian,
+        Altai,
+        AmericanCreamDraft,
+        AmericanCremeandWhite,
+        AmericanWalking,
+        Andalusian,
+        Andravida,
+        AngloKabarda,
+        Appaloosa,
+        Araappaloosa,
+        Arabian,
+        Ardennes,
+        ArgentineCriollo,
+        Asturian,
+        AustralianBrumby,
+        AustralianStock,
+        Azteca,
+        Balearic,
+        Baluchi,
+        Banker,
+        Banei,
+        Barb,
+        Bashkir,
+        BashkirCurly,
+        Basotho,
+        Belgian,
+        Bhirum,
+        Bhotia,
+        Boer,
+        BosnianMountain,
+        Breton,
+        Buckskin,
+        Budyonny,
+        ByelorussianHarness,
+        Camargue,
+        Campolina,
+        Canadian,
+        Carthusian,
+        Caspian,
+        CayuseIndian,
+        Cheju,
+        ChileanCorralero,
+        Chincoteague,
+        ClevelandBay,
+        Clydesdale,
+        ColoradoRanger,
+        Connemara,
+        Criollo, // (Uruguay)
+        Crioulo,
+        Dales,
+        Danube,
+        Dartmoor,
+        Deliboz,
+        Djerma,
+        Dole,
+        Dongola,
+        Dulmen,
+        DutchDraft,
+        DutchWarmblood,
+        EastBulgarian,
+        Egyptian,
+        Eriskay,
+        EstonianNative,
+        Exmoor,
+        Faeroes,
+        Falabella,
+        Fell,
+        Finnhorses,
+        Fleuve,
+        FloridaCracker,
+        Fouta,
+        Frederiksborg,
+        FrenchSaddlebred,
+        FrenchTrotter,
+        Friesian,
+        Galiceno,
+        Galician,
+        Gelderlander,
+        Gidran,
+        GoldenAmericaSaddlebred,
+        Gotland,
+        Groningen,
+        Guangxi,
+        Hackney,
+        Haflinger,
+        Hanoverian,
+        Hequ,
+        Highland,
+        Hokkaido,
+        Hucul,
+        HungarianWarmblood,
+        Icelandic,
+        Iomud,
+        IrishDraught,
+        Jinzhou,
+        Jutland,
+        Kabarda,
+        Karabair,
+        Karabakh,
+        Kazakh,
+        KerryBog,
+        KigerMustang,
+        Kirdi,
+        KisberFelver,
+        Kiso,
+        Kladruby,
+        Knabstrup,
+        Kushum,
+        Kustanai,
+        Latvian,
+        LithuanianHeavyDraft,
+        Lipizzan,
+        Lokai,
+        Losino,
+        Lusitano,
+        Malopolski,
+        Mangalarga,
+        Marwari,
+        MBayar,
+        Merens,
+        Messara,
+        Miniature,
+        Misaki,
+        MissouriFoxTrotting,
+        Miyako,
+        Mongolian,
+        Morab,
+        Morgan,
+        Moyle,
+        Mustang,
+        Murgese,
+        NationalShow,
+        NewForest,
+        NewKirgiz,
+        Newfoundland,
+        Nokota,
+        Noma,
+        Nooitgedacht,
+        Noric,
+        Nordland,
+        NortheasternCrioulo,
+        NorthSwedish,
+        NorwegianFjord,
+        Ob,
+        Oldenburg,
+        OrlovTrotter,
+        Paint,
+        Palomino,
+        PantaneiroCrioulo,
+        PasoFino,
+        Percheron,
+        PeruvianPaso,
+        Pindos,
+        Pinia,
+        Pintabian,
+        Pinto,
+        PolishKonik,
+        PonyOfTheAmericas,
+        Pottok,
+        Przewalski,
+        PyreneanTarpan,
+        Qatgani,
+        Quarab,
+        Quarter,
+        Racking,
+        RockyMountain,
+        RussianDon,
+        RussianHeavyDraft,
+        RussianTrotter,
+        Saddlebred,
+        Sanhe,
+        SchleswigerHeavyDraft,
+        SchwarzwalderFuchs,
+        SelleFrancais,
+        Shagya,
+        Shetland,
+        Shire,
+        SingleFooting,
+        Skyros,
+        Somali,
+        Sorraia,
+        SovietHeavyDraft,
+        SpanishBarb,
+        SpanishMustang,
+        SpanishNorman,
+        Standardbred,
+        SudanCountryBred,
+        Suffolk,
+        SwedishWarmblood,
+        Taishuh,
+        Tarpan,
+        Tawleed,
+        TennesseeWalking,
+        Tersk,
+        Thessalian,
+        Thoroughbred,
+        Tokara,
+        Tori,
+        Trakehner,
+        UkrainianSaddle,
+        Vlaamperd,
+        VladimirHeavyDraft,
+        Vyatka,
+        Welara,
+        WelshPonyAndCob,
+        WestAfricanBarb,
+        WesternSudan,
+        Wielkopolski,
+        Xilingol,
+        Yakut,
+        Yanqi,
+        Yili,
+        Yonaguni,
+        Zaniskari,
+        Zhemaichu,
+    }
 }
 
-impl ToString for HorseBreed {
+impl fmt::Display for HorseBreed {
     /// Converts the HorseBreed enum to a human readable string.
     ///
     /// # Examples
@@ -244,23 +251,23 @@ impl ToString for HorseBreed {
     /// let racking = HorseBreed::Racking;
     /// println!("{}", racking.to_string());
     /// ```
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            HorseBreed::AkhalTeke => "Akhal-Teke".to_string(),
-            HorseBreed::AngloKabarda => "Anglo-Kabarda".to_string(),
-            HorseBreed::Banei => "Ban-ei".to_string(),
-            HorseBreed::Criollo => "Criollo (Uruguay)".to_string(),
-            HorseBreed::Galiceno => "Galiceño".to_string(),
-            HorseBreed::MBayar => "M'Bayar".to_string(),
-            HorseBreed::Merens => "Mérens".to_string(),
-            HorseBreed::NortheasternCrioulo => "Northeastern - Crioulo".to_string(),
-            HorseBreed::PantaneiroCrioulo => "Pantaneiro - Crioulo".to_string(),
-            HorseBreed::SchwarzwalderFuchs => "Schwarzwälder Fuchs".to_string(),
-            HorseBreed::SingleFooting => "Single-Footing".to_string(),
-            HorseBreed::SpanishNorman => "Spanish-Norman".to_string(),
-            HorseBreed::SudanCountryBred => "Sudan Country-Bred".to_string(),
-            HorseBreed::WelshPonyAndCob => "Welsh Pony & Cob".to_string(),
-            _ => format!("{:?}", self).to_title_case(),
+            HorseBreed::AkhalTeke => f.write_str("Akhal-Teke"),
+            HorseBreed::AngloKabarda => f.write_str("Anglo-Kabarda"),
+            HorseBreed::Banei => f.write_str("Ban-ei"),
+            HorseBreed::Criollo => f.write_str("Criollo (Uruguay)"),
+            HorseBreed::Galiceno => f.write_str("Galiceño"),
+            HorseBreed::MBayar => f.write_str("M'Bayar"),
+            HorseBreed::Merens => f.write_str("Mérens"),
+            HorseBreed::NortheasternCrioulo => f.write_str("Northeastern - Crioulo"),
+            HorseBreed::PantaneiroCrioulo => f.write_str("Pantaneiro - Crioulo"),
+            HorseBreed::SchwarzwalderFuchs => f.write_str("Schwarzwälder Fuchs"),
+            HorseBreed::SingleFooting => f.write_str("Single-Footing"),
+            HorseBreed::SpanishNorman => f.write_str("Spanish-Norman"),
+            HorseBreed::SudanCountryBred => f.write_str("Sudan Country-Bred"),
+            HorseBreed::WelshPonyAndCob => f.write_str("Welsh Pony & Cob"),
+            _ => f.write_str(&format!("{:?}", self).to_title_case()),
         }
     }
 }
@@ -275,9 +282,8 @@ impl ToString for HorseBreed {
 /// let breed = HorseBreed::from_str("racking").unwrap();
 /// println!("{:?}", breed);
 /// ```
-
 impl FromStr for HorseBreed {
-    type Err = std::io::Error;
+    type Err = BreedParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().replace("-", " ").as_str() {
             "abyssinian" => Ok(HorseBreed::Abyssinian),
@@ -302,7 +308,7 @@ impl FromStr for HorseBreed {
             "balearic" => Ok(HorseBreed::Balearic),
             "baluchi" => Ok(HorseBreed::Baluchi),
             "banker" => Ok(HorseBreed::Banker),
-            "ban ei" | "banei" => Ok(HorseBreed::Banei),
+            "ban ei" => Ok(HorseBreed::Banei),
             "barb" => Ok(HorseBreed::Barb),
             "bashkir" => Ok(HorseBreed::Bashkir),
             "bashkir curly" => Ok(HorseBreed::BashkirCurly),
@@ -329,7 +335,7 @@ impl FromStr for HorseBreed {
             "clydesdale" => Ok(HorseBreed::Clydesdale),
             "colorado ranger" => Ok(HorseBreed::ColoradoRanger),
             "connemara" => Ok(HorseBreed::Connemara),
-            "criollo" | "criollo (uruguay)" | "uruguay" => Ok(HorseBreed::Criollo),
+            "criollo" => Ok(HorseBreed::Criollo),
             "crioulo" => Ok(HorseBreed::Crioulo),
             "dales" => Ok(HorseBreed::Dales),
             "danube" => Ok(HorseBreed::Danube),
@@ -357,7 +363,7 @@ impl FromStr for HorseBreed {
             "french saddlebred" => Ok(HorseBreed::FrenchSaddlebred),
             "french trotter" => Ok(HorseBreed::FrenchTrotter),
             "friesian" => Ok(HorseBreed::Friesian),
-            "galiceno" | "galiceño" => Ok(HorseBreed::Galiceno),
+            "galiceno" => Ok(HorseBreed::Galiceno),
             "galician" => Ok(HorseBreed::Galician),
             "gelderlander" => Ok(HorseBreed::Gelderlander),
             "gidran" => Ok(HorseBreed::Gidran),
@@ -400,8 +406,8 @@ impl FromStr for HorseBreed {
             "malopolski" => Ok(HorseBreed::Malopolski),
             "mangalarga" => Ok(HorseBreed::Mangalarga),
             "marwari" => Ok(HorseBreed::Marwari),
-            "m bayar" | "mbayar" | "m'bayar" => Ok(HorseBreed::MBayar),
-            "merens" | "mérens" => Ok(HorseBreed::Merens),
+            "m bayar" => Ok(HorseBreed::MBayar),
+            "merens" => Ok(HorseBreed::Merens),
             "messara" => Ok(HorseBreed::Messara),
             "miniature" => Ok(HorseBreed::Miniature),
             "misaki" => Ok(HorseBreed::Misaki),
@@ -422,9 +428,7 @@ impl FromStr for HorseBreed {
             "nooitgedacht" => Ok(HorseBreed::Nooitgedacht),
             "noric" => Ok(HorseBreed::Noric),
             "nordland" => Ok(HorseBreed::Nordland),
-            "northeastern crioulo" | "northeastern   crioulo" => {
-                Ok(HorseBreed::NortheasternCrioulo)
-            }
+            "northeastern crioulo" => Ok(HorseBreed::NortheasternCrioulo),
             "north swedish" => Ok(HorseBreed::NorthSwedish),
             "norwegian fjord" => Ok(HorseBreed::NorwegianFjord),
             "ob" => Ok(HorseBreed::Ob),
@@ -432,7 +436,7 @@ impl FromStr for HorseBreed {
             "orlov trotter" => Ok(HorseBreed::OrlovTrotter),
             "paint" => Ok(HorseBreed::Paint),
             "palomino" => Ok(HorseBreed::Palomino),
-            "pantaneiro crioulo" | "pantaneiro   crioulo" => Ok(HorseBreed::PantaneiroCrioulo),
+            "pantaneiro crioulo" => Ok(HorseBreed::PantaneiroCrioulo),
             "paso fino" => Ok(HorseBreed::PasoFino),
             "percheron" => Ok(HorseBreed::Percheron),
             "peruvian paso" => Ok(HorseBreed::PeruvianPaso),
@@ -456,7 +460,7 @@ impl FromStr for HorseBreed {
             "saddlebred" => Ok(HorseBreed::Saddlebred),
             "sanhe" => Ok(HorseBreed::Sanhe),
             "schleswiger heavy draft" => Ok(HorseBreed::SchleswigerHeavyDraft),
-            "schwarzwalder fuchs" | "schwarzwälder fuchs" => Ok(HorseBreed::SchwarzwalderFuchs),
+            "schwarzwalder fuchs" => Ok(HorseBreed::SchwarzwalderFuchs),
             "selle francais" => Ok(HorseBreed::SelleFrancais),
             "shagya" => Ok(HorseBreed::Shagya),
             "shetland" => Ok(HorseBreed::Shetland),
@@ -488,7 +492,7 @@ impl FromStr for HorseBreed {
             "vladimir heavy draft" => Ok(HorseBreed::VladimirHeavyDraft),
             "vyatka" => Ok(HorseBreed::Vyatka),
             "welara" => Ok(HorseBreed::Welara),
-            "welsh pony and cob" | "welsh pony & cob" => Ok(HorseBreed::WelshPonyAndCob),
+            "welsh pony and cob" => Ok(HorseBreed::WelshPonyAndCob),
             "west african barb" => Ok(HorseBreed::WestAfricanBarb),
             "western sudan" => Ok(HorseBreed::WesternSudan),
             "wielkopolski" => Ok(HorseBreed::Wielkopolski),
@@ -499,14 +503,401 @@ impl FromStr for HorseBreed {
             "yonaguni" => Ok(HorseBreed::Yonaguni),
             "zaniskari" => Ok(HorseBreed::Zaniskari),
             "zhemaichu" => Ok(HorseBreed::Zhemaichu),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Invalid horse breed",
-            )),
+            _ => fuzzy::resolve(VARIANTS, s),
         }
     }
 }
 
+impl HorseBreed {
+    /// Returns every horse breed, in declaration order.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::horse::HorseBreed;
+    ///
+    /// let breeds = HorseBreed::all();
+    /// assert_eq!(breeds.len(), HorseBreed::count());
+    /// ```
+    pub fn all() -> &'static [HorseBreed] {
+        VARIANTS
+    }
+
+    /// Returns an iterator over every horse breed, in declaration order.
+    pub fn iter() -> impl Iterator<Item = HorseBreed> {
+        VARIANTS.iter().copied()
+    }
+
+    /// Returns the number of horse breeds.
+    pub fn count() -> usize {
+        VARIANTS.len()
+    }
+
+    /// Returns the horse breeds whose names closely match the query, best match first.
+    ///
+    /// Case, diacritics and punctuation are ignored, and small typos are tolerated.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::horse::HorseBreed;
+    ///
+    /// let matches = HorseBreed::fuzzy_search("Quater");
+    /// assert_eq!(matches[0].breed, HorseBreed::Quarter);
+    /// ```
+    pub fn fuzzy_search(query: &str) -> Vec<BreedMatch<HorseBreed>> {
+        fuzzy::search(VARIANTS, query)
+    }
+
+    /// Returns the horse breeds raised primarily for the given purpose.
+    ///
+    /// Metadata has only been compiled for some horse breeds, those returned by
+    /// [`HorseBreed::with_metadata`], so other breeds are left out even if they would match.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::horse::HorseBreed;
+    /// use livestock_rs::breeds::metadata::Purpose;
+    ///
+    /// let breeds = HorseBreed::by_purpose(Purpose::Riding);
+    /// assert!(breeds.contains(&HorseBreed::AkhalTeke));
+    /// ```
+    pub fn by_purpose(purpose: Purpose) -> Vec<HorseBreed> {
+        metadata::filter(METADATA, |metadata| metadata.purpose == purpose)
+    }
+
+    /// Returns the horse breeds that originated in the given region.
+    ///
+    /// Metadata has only been compiled for some horse breeds, those returned by
+    /// [`HorseBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_region(region: Region) -> Vec<HorseBreed> {
+        metadata::filter(METADATA, |metadata| metadata.region == region)
+    }
+
+    /// Returns the horse breeds of the given size class.
+    ///
+    /// Metadata has only been compiled for some horse breeds, those returned by
+    /// [`HorseBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_size_class(size_class: SizeClass) -> Vec<HorseBreed> {
+        metadata::filter(METADATA, |metadata| metadata.size_class == size_class)
+    }
+
+    /// Returns the horse breeds that have metadata.
+    ///
+    /// Metadata covers the better documented breeds first, so this is usually a subset of
+    /// [`HorseBreed::all`].
+    pub fn with_metadata() -> Vec<HorseBreed> {
+        METADATA.iter().map(|(breed, _)| *breed).collect()
+    }
+}
+
+impl Breed for HorseBreed {
+    fn species(&self) -> LivestockType {
+        LivestockType::Horse
+    }
+
+    fn metadata(&self) -> Option<BreedMetadata> {
+        metadata::lookup(METADATA, self)
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        ALIASES
+            .iter()
+            .filter(|(breed, _)| breed == self)
+            .map(|(_, alias)| *alias)
+            .collect()
+    }
+}
+
+/// Metadata for the horse breeds with well documented characteristics.
+const METADATA: &[(HorseBreed, BreedMetadata)] = &[
+    (
+        HorseBreed::AkhalTeke,
+        BreedMetadata::new(
+            "Turkmenistan",
+            Region::Asia,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Andalusian,
+        BreedMetadata::new(
+            "Spain",
+            Region::Europe,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Appaloosa,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Arabian,
+        BreedMetadata::new(
+            "Arabian Peninsula",
+            Region::MiddleEast,
+            Purpose::Riding,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Belgian,
+        BreedMetadata::new(
+            "Belgium",
+            Region::Europe,
+            Purpose::Draft,
+            SizeClass::Heavy,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Clydesdale,
+        BreedMetadata::new(
+            "Scotland",
+            Region::Europe,
+            Purpose::Draft,
+            SizeClass::Heavy,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Friesian,
+        BreedMetadata::new(
+            "Netherlands",
+            Region::Europe,
+            Purpose::Riding,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Haflinger,
+        BreedMetadata::new(
+            "Austria",
+            Region::Europe,
+            Purpose::Riding,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Hanoverian,
+        BreedMetadata::new(
+            "Germany",
+            Region::Europe,
+            Purpose::Riding,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Icelandic,
+        BreedMetadata::new(
+            "Iceland",
+            Region::Europe,
+            Purpose::Riding,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Lusitano,
+        BreedMetadata::new(
+            "Portugal",
+            Region::Europe,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Marwari,
+        BreedMetadata::new(
+            "India",
+            Region::Asia,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Miniature,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Companion,
+            SizeClass::Miniature,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Mongolian,
+        BreedMetadata::new(
+            "Mongolia",
+            Region::Asia,
+            Purpose::Riding,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Morgan,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Mustang,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::NorwegianFjord,
+        BreedMetadata::new(
+            "Norway",
+            Region::Europe,
+            Purpose::Draft,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Paint,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Percheron,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Draft,
+            SizeClass::Heavy,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Quarter,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Shetland,
+        BreedMetadata::new(
+            "Scotland",
+            Region::Europe,
+            Purpose::Riding,
+            SizeClass::Miniature,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Shire,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Draft,
+            SizeClass::Heavy,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Standardbred,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Suffolk,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Draft,
+            SizeClass::Heavy,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::TennesseeWalking,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Thoroughbred,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Riding,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::Trakehner,
+        BreedMetadata::new(
+            "Germany",
+            Region::Europe,
+            Purpose::Riding,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        HorseBreed::WelshPonyAndCob,
+        BreedMetadata::new(
+            "Wales",
+            Region::Europe,
+            Purpose::Riding,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+];
+
+/// Alternative names a horse breed is known by, in addition to its canonical name.
+const ALIASES: &[(HorseBreed, &str)] = &[
+    (HorseBreed::Criollo, "criollo (uruguay)"),
+    (HorseBreed::Criollo, "uruguay"),
+    (HorseBreed::MBayar, "mbayar"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -555,7 +946,49 @@ mod tests {
         ];
 
         for (breed, expected) in breeds.iter() {
-            assert_eq!(HorseBreed::from_str(*breed).unwrap(), *expected);
+            assert_eq!(HorseBreed::from_str(breed).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = HorseBreed::AkhalTeke.metadata().unwrap();
+        assert_eq!(metadata.origin, "Turkmenistan");
+        assert_eq!(metadata.purpose, Purpose::Riding);
+        assert_eq!(metadata.size_class, SizeClass::Medium);
+
+        for breed in HorseBreed::by_purpose(Purpose::Riding) {
+            assert_eq!(breed.metadata().unwrap().purpose, Purpose::Riding);
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let matches = HorseBreed::fuzzy_search("Quater");
+        assert_eq!(matches[0].breed, HorseBreed::Quarter);
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_from_str_suggestions() {
+        let err = HorseBreed::from_str("Quater").unwrap_err();
+        assert_eq!(err.suggestions[0], HorseBreed::Quarter.to_string());
+    }
+
+    #[test]
+    fn test_variant_names_are_unique() {
+        for breed in HorseBreed::iter() {
+            assert_eq!(HorseBreed::from_str(&breed.to_string()).unwrap(), breed);
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        // Variants are numbered in declaration order, so each one is listed at its own index.
+        for (index, breed) in HorseBreed::iter().enumerate() {
+            assert_eq!(breed as usize, index);
         }
     }
 }
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+
+/// The primary purpose a breed is raised for.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Purpose {
+    Beef,
+    Dairy,
+    /// Raised for two purposes, e.g. beef and milk for cattle, meat and milk for goats, or meat
+    /// and eggs for chickens.
+    Dual,
+    Meat,
+    Fiber,
+    Fur,
+    Egg,
+    Draft,
+    Riding,
+    Pack,
+    Companion,
+}
+
+/// The broad geographic region a breed originated in.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Region {
+    Africa,
+    Asia,
+    Europe,
+    MiddleEast,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
+/// The size of a breed relative to other breeds of the same species.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum SizeClass {
+    Miniature,
+    Small,
+    Medium,
+    Large,
+    Heavy,
+}
+
+/// Whether animals of a breed usually carry horns.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Horns {
+    Horned,
+    Polled,
+    /// Both horned and polled animals are common within the breed.
+    Variable,
+}
+
+/// Descriptive information about a breed.
+///
+/// Metadata has only been compiled for some breeds of each species so far; each breed enum's
+/// `with_metadata` returns them, and the README lists them by species. Origins, purposes and horn tendencies follow the breed references
+/// cited by each breed module, such as <https://breeds.okstate.edu/>. Those pages rarely give
+/// weights, so mature weights are `None` until a sourced range is compiled for the breed.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::breeds::{Breed, CattleBreed};
+/// use livestock_rs::breeds::metadata::{Horns, Purpose};
+///
+/// let metadata = CattleBreed::Angus.metadata().unwrap();
+/// assert_eq!(metadata.origin, "Scotland");
+/// assert_eq!(metadata.purpose, Purpose::Beef);
+/// assert_eq!(metadata.horns, Some(Horns::Polled));
+/// ```
+///
+/// Metadata can be combined to answer questions like "which dual-purpose goat breeds come from
+/// Africa?":
+/// ``` rust
+/// use livestock_rs::breeds::{Breed, GoatBreed};
+/// use livestock_rs::breeds::metadata::{Purpose, Region};
+///
+/// let breeds: Vec<GoatBreed> = GoatBreed::by_purpose(Purpose::Dual)
+///     .into_iter()
+///     .filter(|breed| breed.metadata().unwrap().region == Region::Africa)
+///     .collect();
+/// assert_eq!(breeds, vec![GoatBreed::Sahelian]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct BreedMetadata {
+    /// The country or area the breed was developed in.
+    pub origin: &'static str,
+    pub region: Region,
+    pub purpose: Purpose,
+    pub size_class: SizeClass,
+    /// Typical mature weight range of males, in kg, if a sourced range has been compiled.
+    pub male_weight_kg: Option<(f64, f64)>,
+    /// Typical mature weight range of females, in kg, if a sourced range has been compiled.
+    pub female_weight_kg: Option<(f64, f64)>,
+    /// Horn tendency of the breed, or `None` for species that do not grow horns.
+    pub horns: Option<Horns>,
+}
+
+impl BreedMetadata {
+    pub(crate) const fn new(
+        origin: &'static str,
+        region: Region,
+        purpose: Purpose,
+        size_class: SizeClass,
+        horns: Option<Horns>,
+    ) -> Self {
+        BreedMetadata {
+            origin,
+            region,
+            purpose,
+            size_class,
+            male_weight_kg: None,
+            female_weight_kg: None,
+            horns,
+        }
+    }
+}
+
+/// Finds the metadata for a breed in a species' metadata table.
+pub(crate) fn lookup<B: PartialEq>(
+    table: &[(B, BreedMetadata)],
+    breed: &B,
+) -> Option<BreedMetadata> {
+    table
+        .iter()
+        .find(|(candidate, _)| candidate == breed)
+        .map(|(_, metadata)| *metadata)
+}
+
+/// Returns the breeds in a species' metadata table whose metadata matches the predicate.
+pub(crate) fn filter<B: Copy>(
+    table: &[(B, BreedMetadata)],
+    predicate: impl Fn(&BreedMetadata) -> bool,
+) -> Vec<B> {
+    table
+        .iter()
+        .filter(|(_, metadata)| predicate(metadata))
+        .map(|(breed, _)| *breed)
+        .collect()
+}
@@ -1,9 +1,530 @@
+use crate::types::LivestockType;
+use clap::ValueEnum;
+use fuzzy::BreedMatch;
+use inflector::Inflector;
+use metadata::BreedMetadata;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Declares a breed enum along with `VARIANTS`, every variant in declaration order.
+///
+/// Generating the list from the declaration means a new breed cannot be left out of it.
+macro_rules! breed_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident,)*
+        }
+    ) => {
+        $(#[$meta])*
+        pub enum $name {
+            $($variant,)*
+        }
+
+        #[doc = concat!("Every ", stringify!($name), " variant, in declaration order.")]
+        const VARIANTS: &[$name] = &[$($name::$variant,)*];
+    };
+}
+
 pub mod camel;
 pub mod cattle;
 pub mod chicken;
+pub mod composition;
 pub mod donkey;
+pub mod error;
+pub mod fuzzy;
 pub mod goat;
 pub mod horse;
+pub mod metadata;
+pub mod rabbit;
 pub mod reindeer;
 pub mod sheep;
 pub mod swine;
+
+pub use camel::CamelBreed;
+pub use cattle::CattleBreed;
+pub use chicken::ChickenBreed;
+pub use composition::BreedComposition;
+pub use donkey::DonkeyBreed;
+pub use error::BreedParseError;
+pub use goat::GoatBreed;
+pub use horse::HorseBreed;
+pub use rabbit::RabbitBreed;
+pub use reindeer::ReindeerBreed;
+pub use sheep::SheepBreed;
+pub use swine::SwineBreed;
+
+/// Behavior shared by every species' breed enum.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::breeds::{Breed, CattleBreed};
+/// use livestock_rs::types::LivestockType;
+///
+/// let breed = CattleBreed::BlondeDAquitaine;
+/// assert_eq!(breed.name(), "Blonde d'Aquitaine");
+/// assert_eq!(breed.id(), "blonde-d-aquitaine");
+/// assert_eq!(breed.species(), LivestockType::Cattle);
+/// ```
+pub trait Breed: fmt::Debug + fmt::Display {
+    /// The species the breed belongs to.
+    fn species(&self) -> LivestockType;
+
+    /// The human readable name of the breed.
+    fn name(&self) -> String {
+        self.to_string()
+    }
+
+    /// The canonical identifier of the breed, a lowercase, hyphenated form of the variant name.
+    fn id(&self) -> String {
+        format!("{:?}", self).to_kebab_case()
+    }
+
+    /// Descriptive information about the breed, such as its origin, purpose and mature
+    /// weights, or `None` if it is not yet documented.
+    fn metadata(&self) -> Option<BreedMetadata> {
+        None
+    }
+
+    /// Alternative names the breed is known by, such as regional names or common shortenings.
+    ///
+    /// Aliases are accepted when parsing and matched by fuzzy search.
+    fn aliases(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// The registry abbreviation of the breed used in breed composition notation, e.g. `AN`
+    /// for Angus, or `None` if the breed has no widely used abbreviation.
+    fn abbreviation(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Finds the breed with the given registry abbreviation, ignoring case.
+    fn from_abbreviation(_abbreviation: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+}
+
+/// A breed of any species.
+///
+/// `AnyBreed` wraps each species' breed enum so a breed can be stored without knowing its
+/// species up front. It is written as `<species>:<breed id>`, e.g. `cattle:angus`, and is
+/// serialized with serde in the same form.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::breeds::{AnyBreed, Breed, CattleBreed};
+/// use livestock_rs::types::LivestockType;
+/// use std::str::FromStr;
+///
+/// let breed = AnyBreed::from_str("cattle:angus").unwrap();
+/// assert_eq!(breed, AnyBreed::Cattle(CattleBreed::Angus));
+/// assert_eq!(breed.species(), LivestockType::Cattle);
+/// assert_eq!(breed.id(), "cattle:angus");
+///
+/// // Human readable names are also accepted after the species.
+/// let breed = AnyBreed::from_str("cattle:Blonde d'Aquitaine").unwrap();
+/// assert_eq!(breed.id(), "cattle:blonde-d-aquitaine");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AnyBreed {
+    Camel(CamelBreed),
+    Cattle(CattleBreed),
+    Chicken(ChickenBreed),
+    Donkey(DonkeyBreed),
+    Goat(GoatBreed),
+    Horse(HorseBreed),
+    Rabbit(RabbitBreed),
+    Reindeer(ReindeerBreed),
+    Sheep(SheepBreed),
+    Swine(SwineBreed),
+}
+
+impl AnyBreed {
+    /// Returns an iterator over every breed of every species, grouped by species.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::{AnyBreed, CattleBreed};
+    ///
+    /// assert!(AnyBreed::iter().any(|breed| breed == AnyBreed::Cattle(CattleBreed::Angus)));
+    /// ```
+    pub fn iter() -> impl Iterator<Item = AnyBreed> {
+        CamelBreed::iter()
+            .map(AnyBreed::from)
+            .chain(CattleBreed::iter().map(AnyBreed::from))
+            .chain(ChickenBreed::iter().map(AnyBreed::from))
+            .chain(DonkeyBreed::iter().map(AnyBreed::from))
+            .chain(GoatBreed::iter().map(AnyBreed::from))
+            .chain(HorseBreed::iter().map(AnyBreed::from))
+            .chain(RabbitBreed::iter().map(AnyBreed::from))
+            .chain(ReindeerBreed::iter().map(AnyBreed::from))
+            .chain(SheepBreed::iter().map(AnyBreed::from))
+            .chain(SwineBreed::iter().map(AnyBreed::from))
+    }
+
+    /// Returns the number of breeds across every species.
+    pub fn count() -> usize {
+        CamelBreed::count()
+            + CattleBreed::count()
+            + ChickenBreed::count()
+            + DonkeyBreed::count()
+            + GoatBreed::count()
+            + HorseBreed::count()
+            + RabbitBreed::count()
+            + ReindeerBreed::count()
+            + SheepBreed::count()
+            + SwineBreed::count()
+    }
+
+    /// Returns breeds of every species whose names closely match the query, best match first.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::{AnyBreed, GoatBreed};
+    ///
+    /// let matches = AnyBreed::fuzzy_search("Caninde");
+    /// assert_eq!(matches[0].breed, AnyBreed::Goat(GoatBreed::Caninde));
+    /// ```
+    pub fn fuzzy_search(query: &str) -> Vec<BreedMatch<AnyBreed>> {
+        let mut matches: Vec<BreedMatch<AnyBreed>> = Vec::new();
+        matches.extend(
+            CamelBreed::fuzzy_search(query)
+                .into_iter()
+                .map(BreedMatch::into_any),
+        );
+        matches.extend(
+            CattleBreed::fuzzy_search(query)
+                .into_iter()
+                .map(BreedMatch::into_any),
+        );
+        matches.extend(
+            ChickenBreed::fuzzy_search(query)
+                .into_iter()
+                .map(BreedMatch::into_any),
+        );
+        matches.extend(
+            DonkeyBreed::fuzzy_search(query)
+                .into_iter()
+                .map(BreedMatch::into_any),
+        );
+        matches.extend(
+            GoatBreed::fuzzy_search(query)
+                .into_iter()
+                .map(BreedMatch::into_any),
+        );
+        matches.extend(
+            HorseBreed::fuzzy_search(query)
+                .into_iter()
+                .map(BreedMatch::into_any),
+        );
+        matches.extend(
+            RabbitBreed::fuzzy_search(query)
+                .into_iter()
+                .map(BreedMatch::into_any),
+        );
+        matches.extend(
+            ReindeerBreed::fuzzy_search(query)
+                .into_iter()
+                .map(BreedMatch::into_any),
+        );
+        matches.extend(
+            SheepBreed::fuzzy_search(query)
+                .into_iter()
+                .map(BreedMatch::into_any),
+        );
+        matches.extend(
+            SwineBreed::fuzzy_search(query)
+                .into_iter()
+                .map(BreedMatch::into_any),
+        );
+
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+        matches
+    }
+
+    /// Returns the wrapped species breed as a `Breed` trait object.
+    pub fn as_breed(&self) -> &dyn Breed {
+        match self {
+            AnyBreed::Camel(breed) => breed,
+            AnyBreed::Cattle(breed) => breed,
+            AnyBreed::Chicken(breed) => breed,
+            AnyBreed::Donkey(breed) => breed,
+            AnyBreed::Goat(breed) => breed,
+            AnyBreed::Horse(breed) => breed,
+            AnyBreed::Rabbit(breed) => breed,
+            AnyBreed::Reindeer(breed) => breed,
+            AnyBreed::Sheep(breed) => breed,
+            AnyBreed::Swine(breed) => breed,
+        }
+    }
+}
+
+impl Breed for AnyBreed {
+    fn species(&self) -> LivestockType {
+        self.as_breed().species()
+    }
+
+    fn id(&self) -> String {
+        format!(
+            "{}:{}",
+            format!("{:?}", self.species()).to_kebab_case(),
+            self.as_breed().id()
+        )
+    }
+
+    fn metadata(&self) -> Option<BreedMetadata> {
+        self.as_breed().metadata()
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        self.as_breed().aliases()
+    }
+
+    fn abbreviation(&self) -> Option<&'static str> {
+        self.as_breed().abbreviation()
+    }
+}
+
+impl fmt::Display for AnyBreed {
+    /// Writes the human readable name of the wrapped breed.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_breed(), f)
+    }
+}
+
+/// Converts a `<species>:<breed>` string to an AnyBreed enum.
+///
+/// The breed may be given as its canonical id or as any name the species' breed enum accepts.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::breeds::{AnyBreed, GoatBreed};
+/// use std::str::FromStr;
+///
+/// let breed = AnyBreed::from_str("goat:anglo-nubian").unwrap();
+/// assert_eq!(breed, AnyBreed::Goat(GoatBreed::AngloNubian));
+/// ```
+impl FromStr for AnyBreed {
+    type Err = BreedParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || BreedParseError::new(None, s);
+
+        let (species, breed) = s.split_once(':').ok_or_else(invalid)?;
+        let species = LivestockType::from_str(species.trim(), true).map_err(|_| invalid())?;
+        let breed = breed.trim();
+
+        let any_breed = match species {
+            LivestockType::Camel => AnyBreed::Camel(breed.parse()?),
+            LivestockType::Cattle => AnyBreed::Cattle(breed.parse()?),
+            LivestockType::Chicken => AnyBreed::Chicken(breed.parse()?),
+            LivestockType::Donkey => AnyBreed::Donkey(breed.parse()?),
+            LivestockType::Goat => AnyBreed::Goat(breed.parse()?),
+            LivestockType::Horse => AnyBreed::Horse(breed.parse()?),
+            LivestockType::Rabbit => AnyBreed::Rabbit(breed.parse()?),
+            LivestockType::Reindeer => AnyBreed::Reindeer(breed.parse()?),
+            LivestockType::Sheep => AnyBreed::Sheep(breed.parse()?),
+            LivestockType::Swine => AnyBreed::Swine(breed.parse()?),
+        };
+
+        Ok(any_breed)
+    }
+}
+
+impl Serialize for AnyBreed {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for AnyBreed {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        AnyBreed::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<CamelBreed> for AnyBreed {
+    fn from(breed: CamelBreed) -> Self {
+        AnyBreed::Camel(breed)
+    }
+}
+
+impl From<CattleBreed> for AnyBreed {
+    fn from(breed: CattleBreed) -> Self {
+        AnyBreed::Cattle(breed)
+    }
+}
+
+impl From<ChickenBreed> for AnyBreed {
+    fn from(breed: ChickenBreed) -> Self {
+        AnyBreed::Chicken(breed)
+    }
+}
+
+impl From<DonkeyBreed> for AnyBreed {
+    fn from(breed: DonkeyBreed) -> Self {
+        AnyBreed::Donkey(breed)
+    }
+}
+
+impl From<GoatBreed> for AnyBreed {
+    fn from(breed: GoatBreed) -> Self {
+        AnyBreed::Goat(breed)
+    }
+}
+
+impl From<HorseBreed> for AnyBreed {
+    fn from(breed: HorseBreed) -> Self {
+        AnyBreed::Horse(breed)
+    }
+}
+
+impl From<RabbitBreed> for AnyBreed {
+    fn from(breed: RabbitBreed) -> Self {
+        AnyBreed::Rabbit(breed)
+    }
+}
+
+impl From<ReindeerBreed> for AnyBreed {
+    fn from(breed: ReindeerBreed) -> Self {
+        AnyBreed::Reindeer(breed)
+    }
+}
+
+impl From<SheepBreed> for AnyBreed {
+    fn from(breed: SheepBreed) -> Self {
+        AnyBreed::Sheep(breed)
+    }
+}
+
+impl From<SwineBreed> for AnyBreed {
+    fn from(breed: SwineBreed) -> Self {
+        AnyBreed::Swine(breed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_any_breed_id() {
+        let breeds = [
+            (AnyBreed::Cattle(CattleBreed::Angus), "cattle:angus"),
+            (
+                AnyBreed::Cattle(CattleBreed::BlondeDAquitaine),
+                "cattle:blonde-d-aquitaine",
+            ),
+            (AnyBreed::Chicken(ChickenBreed::AC), "chicken:ac"),
+            (AnyBreed::Goat(GoatBreed::SRD), "goat:srd"),
+            (AnyBreed::Horse(HorseBreed::MBayar), "horse:m-bayar"),
+            (
+                AnyBreed::Sheep(SheepBreed::RougeDeIQuest),
+                "sheep:rouge-de-i-quest",
+            ),
+            (AnyBreed::Reindeer(ReindeerBreed::Evenk), "reindeer:evenk"),
+        ];
+
+        for (breed, expected) in breeds.iter() {
+            assert_eq!(breed.id(), *expected);
+            assert_eq!(AnyBreed::from_str(expected).unwrap(), *breed);
+        }
+    }
+
+    #[test]
+    fn test_any_breed_from_str_names() {
+        let breeds = [
+            (
+                "Cattle:Blonde d'Aquitaine",
+                AnyBreed::Cattle(CattleBreed::BlondeDAquitaine),
+            ),
+            ("goat: Canindé", AnyBreed::Goat(GoatBreed::Caninde)),
+            ("donkey:mini", AnyBreed::Donkey(DonkeyBreed::Miniature)),
+            (
+                "rabbit:champagne-d-argent",
+                AnyBreed::Rabbit(RabbitBreed::ChampagneDArgent),
+            ),
+        ];
+
+        for (breed, expected) in breeds.iter() {
+            assert_eq!(AnyBreed::from_str(breed).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_any_breed_from_str_invalid() {
+        for breed in [
+            "angus",
+            "cattle:",
+            "cattle:boer",
+            "unicorn:angus",
+            "rabbit:angus",
+        ] {
+            assert!(AnyBreed::from_str(breed).is_err());
+        }
+    }
+
+    #[test]
+    fn test_any_breed_fuzzy_search() {
+        let matches = AnyBreed::fuzzy_search("Blond d Aquitaine");
+        assert_eq!(
+            matches[0].breed,
+            AnyBreed::Cattle(CattleBreed::BlondeDAquitaine)
+        );
+
+        let err = AnyBreed::from_str("cattle:Charlois").unwrap_err();
+        assert_eq!(err.suggestions, vec!["Charolais".to_string()]);
+    }
+
+    #[test]
+    fn test_any_breed_iter() {
+        assert_eq!(AnyBreed::iter().count(), AnyBreed::count());
+
+        // Every breed round trips through its canonical id.
+        for breed in AnyBreed::iter() {
+            assert_eq!(AnyBreed::from_str(&breed.id()).unwrap(), breed);
+        }
+    }
+
+    #[test]
+    fn test_any_breed_aliases() {
+        assert_eq!(
+            AnyBreed::from(ChickenBreed::NakedNeck).aliases(),
+            vec!["turken"]
+        );
+
+        // Every alias parses to the breed it belongs to.
+        for breed in AnyBreed::iter() {
+            for alias in breed.aliases() {
+                let species = format!("{:?}", breed.species()).to_lowercase();
+                let parsed = AnyBreed::from_str(&format!("{}:{}", species, alias)).unwrap();
+                assert_eq!(parsed, breed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_any_breed_species() {
+        assert_eq!(
+            AnyBreed::from(SwineBreed::Duroc).species(),
+            LivestockType::Swine
+        );
+        assert_eq!(
+            AnyBreed::from(CamelBreed::AlxaBactrian).species(),
+            LivestockType::Camel
+        );
+    }
+
+    #[test]
+    fn test_any_breed_serde_round_trip() {
+        let breed = AnyBreed::Sheep(SheepBreed::StCroix);
+        let json = serde_json::to_string(&breed).unwrap();
+        assert_eq!(json, "\"sheep:st-croix\"");
+        assert_eq!(serde_json::from_str::<AnyBreed>(&json).unwrap(), breed);
+    }
+}
@@ -0,0 +1,577 @@
+use super::fuzzy::{self, BreedMatch};
+use super::metadata::{self, BreedMetadata, Purpose, Region, SizeClass};
+use super::{Breed, BreedParseError};
+use crate::types::LivestockType;
+use inflector::Inflector;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+breed_enum! {
+    /// An enum representing the different breeds of rabbits.
+    ///
+    /// Initial data from: <https://arba.net/recognized-breeds/>
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::rabbit::RabbitBreed;
+    ///
+    /// let breed = RabbitBreed::NewZealand;
+    /// println!("{:?}", breed);
+    /// ```
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum RabbitBreed {
+        American,
+        AmericanChinchilla,
+        AmericanFuzzyLop,
+        AmericanSable,
+        ArgenteBrun,
+        BelgianHare,
+        Beveren,
+        BlancDeHotot,
+        BritanniaPetite,
+        Californian,
+        ChampagneDArgent,
+        CheckeredGiant,
+        Cinnamon,
+        CremeDArgent,
+        CzechFrosty,
+        Dutch,
+        DwarfHotot,
+        DwarfPapillon,
+        EnglishAngora,
+        EnglishLop,
+        EnglishSpot,
+        FlemishGiant,
+        FloridaWhite,
+        FrenchAngora,
+        FrenchLop,
+        GiantAngora,
+        GiantChinchilla,
+        Harlequin,
+        Havana,
+        Himalayan,
+        HollandLop,
+        JerseyWooly,
+        Lilac,
+        Lionhead,
+        MiniLop,
+        MiniRex,
+        MiniSatin,
+        NetherlandDwarf,
+        NewZealand,
+        Palomino,
+        Polish,
+        Rex,
+        Rhinelander,
+        Satin,
+        SatinAngora,
+        Silver,
+        SilverFox,
+        SilverMarten,
+        StandardChinchilla,
+        Sussex,
+        Tan,
+        Thrianta,
+    }
+}
+
+impl fmt::Display for RabbitBreed {
+    /// Converts the RabbitBreed enum to a human readable string.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::rabbit::RabbitBreed;
+    ///
+    /// let californian = RabbitBreed::Californian;
+    /// println!("{}", californian.to_string());
+    ///
+    /// let champagne = RabbitBreed::ChampagneDArgent;
+    /// println!("{}", champagne.to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RabbitBreed::BlancDeHotot => f.write_str("Blanc de Hotot"),
+            RabbitBreed::ChampagneDArgent => f.write_str("Champagne d'Argent"),
+            RabbitBreed::CremeDArgent => f.write_str("Crème d'Argent"),
+            _ => f.write_str(&format!("{:?}", self).to_title_case()),
+        }
+    }
+}
+
+/// Converts a string to a RabbitBreed enum.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::breeds::rabbit::RabbitBreed;
+/// use std::str::FromStr;
+///
+/// let breed = RabbitBreed::from_str("New Zealand").unwrap();
+/// println!("{:?}", breed);
+///
+/// let breed = RabbitBreed::from_str("Champagne d'Argent").unwrap();
+/// println!("{:?}", breed);
+/// ```
+impl FromStr for RabbitBreed {
+    type Err = BreedParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "american" => Ok(RabbitBreed::American),
+            "american chinchilla" => Ok(RabbitBreed::AmericanChinchilla),
+            "american fuzzy lop" => Ok(RabbitBreed::AmericanFuzzyLop),
+            "american sable" => Ok(RabbitBreed::AmericanSable),
+            "argente brun" => Ok(RabbitBreed::ArgenteBrun),
+            "belgian hare" => Ok(RabbitBreed::BelgianHare),
+            "beveren" => Ok(RabbitBreed::Beveren),
+            "blanc de hotot" => Ok(RabbitBreed::BlancDeHotot),
+            "britannia petite" => Ok(RabbitBreed::BritanniaPetite),
+            "californian" => Ok(RabbitBreed::Californian),
+            "champagne d'argent" => Ok(RabbitBreed::ChampagneDArgent),
+            "checkered giant" => Ok(RabbitBreed::CheckeredGiant),
+            "cinnamon" => Ok(RabbitBreed::Cinnamon),
+            "crème d'argent" => Ok(RabbitBreed::CremeDArgent),
+            "czech frosty" => Ok(RabbitBreed::CzechFrosty),
+            "dutch" => Ok(RabbitBreed::Dutch),
+            "dwarf hotot" => Ok(RabbitBreed::DwarfHotot),
+            "dwarf papillon" => Ok(RabbitBreed::DwarfPapillon),
+            "english angora" => Ok(RabbitBreed::EnglishAngora),
+            "english lop" => Ok(RabbitBreed::EnglishLop),
+            "english spot" => Ok(RabbitBreed::EnglishSpot),
+            "flemish giant" => Ok(RabbitBreed::FlemishGiant),
+            "florida white" => Ok(RabbitBreed::FloridaWhite),
+            "french angora" => Ok(RabbitBreed::FrenchAngora),
+            "french lop" => Ok(RabbitBreed::FrenchLop),
+            "giant angora" => Ok(RabbitBreed::GiantAngora),
+            "giant chinchilla" => Ok(RabbitBreed::GiantChinchilla),
+            "harlequin" => Ok(RabbitBreed::Harlequin),
+            "havana" => Ok(RabbitBreed::Havana),
+            "himalayan" => Ok(RabbitBreed::Himalayan),
+            "holland lop" => Ok(RabbitBreed::HollandLop),
+            "jersey wooly" => Ok(RabbitBreed::JerseyWooly),
+            "lilac" => Ok(RabbitBreed::Lilac),
+            "lionhead" => Ok(RabbitBreed::Lionhead),
+            "mini lop" => Ok(RabbitBreed::MiniLop),
+            "mini rex" => Ok(RabbitBreed::MiniRex),
+            "mini satin" => Ok(RabbitBreed::MiniSatin),
+            "netherland dwarf" => Ok(RabbitBreed::NetherlandDwarf),
+            "new zealand" => Ok(RabbitBreed::NewZealand),
+            "palomino" => Ok(RabbitBreed::Palomino),
+            "polish" => Ok(RabbitBreed::Polish),
+            "rex" => Ok(RabbitBreed::Rex),
+            "rhinelander" => Ok(RabbitBreed::Rhinelander),
+            "satin" => Ok(RabbitBreed::Satin),
+            "satin angora" => Ok(RabbitBreed::SatinAngora),
+            "silver" => Ok(RabbitBreed::Silver),
+            "silver fox" => Ok(RabbitBreed::SilverFox),
+            "silver marten" => Ok(RabbitBreed::SilverMarten),
+            "standard chinchilla" => Ok(RabbitBreed::StandardChinchilla),
+            "sussex" => Ok(RabbitBreed::Sussex),
+            "tan" => Ok(RabbitBreed::Tan),
+            "thrianta" => Ok(RabbitBreed::Thrianta),
+            _ => fuzzy::resolve(VARIANTS, s),
+        }
+    }
+}
+
+impl RabbitBreed {
+    /// Returns every rabbit breed, in declaration order.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::rabbit::RabbitBreed;
+    ///
+    /// let breeds = RabbitBreed::all();
+    /// assert_eq!(breeds.len(), RabbitBreed::count());
+    /// ```
+    pub fn all() -> &'static [RabbitBreed] {
+        VARIANTS
+    }
+
+    /// Returns an iterator over every rabbit breed, in declaration order.
+    pub fn iter() -> impl Iterator<Item = RabbitBreed> {
+        VARIANTS.iter().copied()
+    }
+
+    /// Returns the number of rabbit breeds.
+    pub fn count() -> usize {
+        VARIANTS.len()
+    }
+
+    /// Returns the rabbit breeds whose names closely match the query, best match first.
+    ///
+    /// Case, diacritics and punctuation are ignored, and small typos are tolerated.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::rabbit::RabbitBreed;
+    ///
+    /// let matches = RabbitBreed::fuzzy_search("New Zeeland");
+    /// assert_eq!(matches[0].breed, RabbitBreed::NewZealand);
+    /// ```
+    pub fn fuzzy_search(query: &str) -> Vec<BreedMatch<RabbitBreed>> {
+        fuzzy::search(VARIANTS, query)
+    }
+
+    /// Returns the rabbit breeds raised primarily for the given purpose.
+    ///
+    /// Metadata has only been compiled for some rabbit breeds, those returned by
+    /// [`RabbitBreed::with_metadata`], so other breeds are left out even if they would match.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::rabbit::RabbitBreed;
+    /// use livestock_rs::breeds::metadata::Purpose;
+    ///
+    /// let breeds = RabbitBreed::by_purpose(Purpose::Meat);
+    /// assert!(breeds.contains(&RabbitBreed::AmericanChinchilla));
+    /// ```
+    pub fn by_purpose(purpose: Purpose) -> Vec<RabbitBreed> {
+        metadata::filter(METADATA, |metadata| metadata.purpose == purpose)
+    }
+
+    /// Returns the rabbit breeds that originated in the given region.
+    ///
+    /// Metadata has only been compiled for some rabbit breeds, those returned by
+    /// [`RabbitBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_region(region: Region) -> Vec<RabbitBreed> {
+        metadata::filter(METADATA, |metadata| metadata.region == region)
+    }
+
+    /// Returns the rabbit breeds of the given size class.
+    ///
+    /// Metadata has only been compiled for some rabbit breeds, those returned by
+    /// [`RabbitBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_size_class(size_class: SizeClass) -> Vec<RabbitBreed> {
+        metadata::filter(METADATA, |metadata| metadata.size_class == size_class)
+    }
+
+    /// Returns the rabbit breeds that have metadata.
+    ///
+    /// Metadata covers the better documented breeds first, so this is usually a subset of
+    /// [`RabbitBreed::all`].
+    pub fn with_metadata() -> Vec<RabbitBreed> {
+        METADATA.iter().map(|(breed, _)| *breed).collect()
+    }
+}
+
+impl Breed for RabbitBreed {
+    fn species(&self) -> LivestockType {
+        LivestockType::Rabbit
+    }
+
+    fn metadata(&self) -> Option<BreedMetadata> {
+        metadata::lookup(METADATA, self)
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        ALIASES
+            .iter()
+            .filter(|(breed, _)| breed == self)
+            .map(|(_, alias)| *alias)
+            .collect()
+    }
+}
+
+/// Metadata for the rabbit breeds with well documented characteristics.
+const METADATA: &[(RabbitBreed, BreedMetadata)] = &[
+    (
+        RabbitBreed::AmericanChinchilla,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::AmericanFuzzyLop,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Companion,
+            SizeClass::Miniature,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::BelgianHare,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Companion,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::Californian,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::ChampagneDArgent,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::CremeDArgent,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::Dutch,
+        BreedMetadata::new(
+            "Netherlands",
+            Region::Europe,
+            Purpose::Companion,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::EnglishAngora,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Fiber,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::FlemishGiant,
+        BreedMetadata::new(
+            "Belgium",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Heavy,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::FrenchAngora,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Fiber,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::GiantAngora,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Fiber,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::GiantChinchilla,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Heavy,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::HollandLop,
+        BreedMetadata::new(
+            "Netherlands",
+            Region::Europe,
+            Purpose::Companion,
+            SizeClass::Miniature,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::Lionhead,
+        BreedMetadata::new(
+            "Belgium",
+            Region::Europe,
+            Purpose::Companion,
+            SizeClass::Miniature,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::MiniRex,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Companion,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::NetherlandDwarf,
+        BreedMetadata::new(
+            "Netherlands",
+            Region::Europe,
+            Purpose::Companion,
+            SizeClass::Miniature,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::NewZealand,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::Palomino,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::Rex,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Fur,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::Satin,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        RabbitBreed::SilverFox,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+];
+
+/// Alternative names a rabbit breed is known by, in addition to its canonical name.
+const ALIASES: &[(RabbitBreed, &str)] = &[
+    (RabbitBreed::BlancDeHotot, "hotot"),
+    (RabbitBreed::Californian, "california"),
+    (RabbitBreed::ChampagneDArgent, "champagne"),
+    (RabbitBreed::FlemishGiant, "flemish"),
+    (RabbitBreed::NewZealand, "new zealand white"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_string_special_cases() {
+        let special_case_breeds = [
+            (RabbitBreed::BlancDeHotot, "Blanc de Hotot"),
+            (RabbitBreed::ChampagneDArgent, "Champagne d'Argent"),
+            (RabbitBreed::CremeDArgent, "Crème d'Argent"),
+            (RabbitBreed::FlemishGiant, "Flemish Giant"),
+            (RabbitBreed::NewZealand, "New Zealand"),
+        ];
+
+        for (breed, expected) in special_case_breeds.iter() {
+            assert_eq!(breed.to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_from_string_special_cases() {
+        let special_case_breeds = [
+            ("Blanc de Hotot", RabbitBreed::BlancDeHotot),
+            ("Hotot", RabbitBreed::BlancDeHotot),
+            ("Champagne d'Argent", RabbitBreed::ChampagneDArgent),
+            ("Champagne d Argent", RabbitBreed::ChampagneDArgent),
+            ("Crème d'Argent", RabbitBreed::CremeDArgent),
+            ("Creme d'Argent", RabbitBreed::CremeDArgent),
+            ("New Zealand White", RabbitBreed::NewZealand),
+            ("Californian", RabbitBreed::Californian),
+            ("Rex", RabbitBreed::Rex),
+        ];
+
+        for (breed, expected) in special_case_breeds.iter() {
+            assert_eq!(RabbitBreed::from_str(breed).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = RabbitBreed::AmericanChinchilla.metadata().unwrap();
+        assert_eq!(metadata.origin, "United States");
+        assert_eq!(metadata.purpose, Purpose::Meat);
+        assert_eq!(metadata.size_class, SizeClass::Large);
+
+        for breed in RabbitBreed::by_purpose(Purpose::Meat) {
+            assert_eq!(breed.metadata().unwrap().purpose, Purpose::Meat);
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let matches = RabbitBreed::fuzzy_search("New Zeeland");
+        assert_eq!(matches[0].breed, RabbitBreed::NewZealand);
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_from_str_suggestions() {
+        let err = RabbitBreed::from_str("New Zeeland").unwrap_err();
+        assert_eq!(err.suggestions[0], RabbitBreed::NewZealand.to_string());
+    }
+
+    #[test]
+    fn test_variant_names_are_unique() {
+        for breed in RabbitBreed::iter() {
+            assert_eq!(RabbitBreed::from_str(&breed.to_string()).unwrap(), breed);
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        // Variants are numbered in declaration order, so each one is listed at its own index.
+        for (index, breed) in RabbitBreed::iter().enumerate() {
+            assert_eq!(breed as usize, index);
+        }
+    }
+}
@@ -1,26 +1,33 @@
+use super::fuzzy::{self, BreedMatch};
+use super::metadata::{self, BreedMetadata, Purpose, Region, SizeClass};
+use super::{Breed, BreedParseError};
+use crate::types::LivestockType;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
-/// An enum representing the different breeds of reindeer.
-///
-/// Initial data from: <https://breeds.okstate.edu/other-breeds-of-livestock/reindeer/>
-///
-/// # Examples
-/// ``` rust
-/// use livestock_rs::breeds::reindeer::ReindeerBreed;
-///
-/// let breed = ReindeerBreed::Chukotka;
-/// println!("{:?}", breed);
-/// ```
-#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub enum ReindeerBreed {
-    Chukotka,
-    Even,
-    Evenk,
-    Nentsi,
+breed_enum! {
+    /// An enum representing the different breeds of reindeer.
+    ///
+    /// Initial data from: <https://breeds.okstate.edu/other-breeds-of-livestock/reindeer/>
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::reindeer::ReindeerBreed;
+    ///
+    /// let breed = ReindeerBreed::Chukotka;
+    /// println!("{:?}", breed);
+    /// ```
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum ReindeerBreed {
+        Chukotka,
+        Even,
+        Evenk,
+        Nentsi,
+    }
 }
 
-impl ToString for ReindeerBreed {
+impl fmt::Display for ReindeerBreed {
     /// Converts the ReindeerBreed enum to a human readable string.
     ///
     /// # Examples
@@ -30,12 +37,12 @@ impl ToString for ReindeerBreed {
     /// let evenk = ReindeerBreed::Evenk;
     /// println!("{}", evenk.to_string());
     /// ```
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ReindeerBreed::Chukotka => "Chukotka".to_string(),
-            ReindeerBreed::Even => "Even".to_string(),
-            ReindeerBreed::Evenk => "Evenk".to_string(),
-            ReindeerBreed::Nentsi => "Nentsi".to_string(),
+            ReindeerBreed::Chukotka => f.write_str("Chukotka"),
+            ReindeerBreed::Even => f.write_str("Even"),
+            ReindeerBreed::Evenk => f.write_str("Evenk"),
+            ReindeerBreed::Nentsi => f.write_str("Nentsi"),
         }
     }
 }
@@ -50,23 +57,154 @@ impl ToString for ReindeerBreed {
 /// let breed = ReindeerBreed::from_str("evenk").unwrap();
 /// println!("{:?}", breed);
 /// ```
-
 impl FromStr for ReindeerBreed {
-    type Err = std::io::Error;
+    type Err = BreedParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "chukotka" => Ok(ReindeerBreed::Chukotka),
             "even" => Ok(ReindeerBreed::Even),
             "evenk" => Ok(ReindeerBreed::Evenk),
             "nentsi" => Ok(ReindeerBreed::Nentsi),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Invalid reindeer breed",
-            )),
+            _ => fuzzy::resolve(VARIANTS, s),
         }
     }
 }
 
+impl ReindeerBreed {
+    /// Returns every reindeer breed, in declaration order.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::reindeer::ReindeerBreed;
+    ///
+    /// let breeds = ReindeerBreed::all();
+    /// assert_eq!(breeds.len(), ReindeerBreed::count());
+    /// ```
+    pub fn all() -> &'static [ReindeerBreed] {
+        VARIANTS
+    }
+
+    /// Returns an iterator over every reindeer breed, in declaration order.
+    pub fn iter() -> impl Iterator<Item = ReindeerBreed> {
+        VARIANTS.iter().copied()
+    }
+
+    /// Returns the number of reindeer breeds.
+    pub fn count() -> usize {
+        VARIANTS.len()
+    }
+
+    /// Returns the reindeer breeds whose names closely match the query, best match first.
+    ///
+    /// Case, diacritics and punctuation are ignored, and small typos are tolerated.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::reindeer::ReindeerBreed;
+    ///
+    /// let matches = ReindeerBreed::fuzzy_search("Evenki");
+    /// assert_eq!(matches[0].breed, ReindeerBreed::Evenk);
+    /// ```
+    pub fn fuzzy_search(query: &str) -> Vec<BreedMatch<ReindeerBreed>> {
+        fuzzy::search(VARIANTS, query)
+    }
+
+    /// Returns the reindeer breeds raised primarily for the given purpose.
+    ///
+    /// Metadata has only been compiled for some reindeer breeds, those returned by
+    /// [`ReindeerBreed::with_metadata`], so other breeds are left out even if they would match.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::reindeer::ReindeerBreed;
+    /// use livestock_rs::breeds::metadata::Purpose;
+    ///
+    /// let breeds = ReindeerBreed::by_purpose(Purpose::Pack);
+    /// assert!(breeds.contains(&ReindeerBreed::Even));
+    /// ```
+    pub fn by_purpose(purpose: Purpose) -> Vec<ReindeerBreed> {
+        metadata::filter(METADATA, |metadata| metadata.purpose == purpose)
+    }
+
+    /// Returns the reindeer breeds that originated in the given region.
+    ///
+    /// Metadata has only been compiled for some reindeer breeds, those returned by
+    /// [`ReindeerBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_region(region: Region) -> Vec<ReindeerBreed> {
+        metadata::filter(METADATA, |metadata| metadata.region == region)
+    }
+
+    /// Returns the reindeer breeds of the given size class.
+    ///
+    /// Metadata has only been compiled for some reindeer breeds, those returned by
+    /// [`ReindeerBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_size_class(size_class: SizeClass) -> Vec<ReindeerBreed> {
+        metadata::filter(METADATA, |metadata| metadata.size_class == size_class)
+    }
+
+    /// Returns the reindeer breeds that have metadata.
+    ///
+    /// Metadata covers the better documented breeds first, so this is usually a subset of
+    /// [`ReindeerBreed::all`].
+    pub fn with_metadata() -> Vec<ReindeerBreed> {
+        METADATA.iter().map(|(breed, _)| *breed).collect()
+    }
+}
+
+impl Breed for ReindeerBreed {
+    fn species(&self) -> LivestockType {
+        LivestockType::Reindeer
+    }
+
+    fn metadata(&self) -> Option<BreedMetadata> {
+        metadata::lookup(METADATA, self)
+    }
+}
+
+/// Metadata for the reindeer breeds with well documented characteristics.
+const METADATA: &[(ReindeerBreed, BreedMetadata)] = &[
+    (
+        ReindeerBreed::Chukotka,
+        BreedMetadata::new(
+            "Russia",
+            Region::Asia,
+            Purpose::Meat,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        ReindeerBreed::Even,
+        BreedMetadata::new(
+            "Russia",
+            Region::Asia,
+            Purpose::Pack,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ReindeerBreed::Evenk,
+        BreedMetadata::new(
+            "Russia",
+            Region::Asia,
+            Purpose::Pack,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        ReindeerBreed::Nentsi,
+        BreedMetadata::new(
+            "Russia",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,7 +233,49 @@ mod tests {
         ];
 
         for (breed, expected) in breeds.iter() {
-            assert_eq!(ReindeerBreed::from_str(*breed).unwrap(), *expected);
+            assert_eq!(ReindeerBreed::from_str(breed).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = ReindeerBreed::Chukotka.metadata().unwrap();
+        assert_eq!(metadata.origin, "Russia");
+        assert_eq!(metadata.purpose, Purpose::Meat);
+        assert_eq!(metadata.size_class, SizeClass::Small);
+
+        for breed in ReindeerBreed::by_purpose(Purpose::Pack) {
+            assert_eq!(breed.metadata().unwrap().purpose, Purpose::Pack);
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let matches = ReindeerBreed::fuzzy_search("Evenki");
+        assert_eq!(matches[0].breed, ReindeerBreed::Evenk);
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_from_str_suggestions() {
+        let err = ReindeerBreed::from_str("Evenki").unwrap_err();
+        assert_eq!(err.suggestions[0], ReindeerBreed::Evenk.to_string());
+    }
+
+    #[test]
+    fn test_variant_names_are_unique() {
+        for breed in ReindeerBreed::iter() {
+            assert_eq!(ReindeerBreed::from_str(&breed.to_string()).unwrap(), breed);
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        // Variants are numbered in declaration order, so each one is listed at its own index.
+        for (index, breed) in ReindeerBreed::iter().enumerate() {
+            assert_eq!(breed as usize, index);
         }
     }
 }
@@ -1,270 +1,277 @@
+use super::fuzzy::{self, BreedMatch};
+use super::metadata::{self, BreedMetadata, Horns, Purpose, Region, SizeClass};
+use super::{Breed, BreedParseError};
+use crate::types::LivestockType;
 use inflector::Inflector;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
-/// An enum representing the different breeds of sheep.
-///
-/// Initial data from: <https://breeds.okstate.edu/sheep/>
-///
-/// # Examples
-/// ``` rust
-/// use livestock_rs::breeds::sheep::SheepBreed;
-///
-/// let breed = SheepBreed::Dorper;
-/// println!("{:?}", breed);
-/// ```
-#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub enum SheepBreed {
-    Acipayam,
-    Adal,
-    AfghanArabi,
-    Africana,
-    Alai,
-    Alcarrena,
-    AlgarveChurro,
-    AlgerianArab,
-    Altai,
-    Altay,
-    AmericanBlackbelly,
-    Apennine,
-    Arabi,
-    ArapawaIsland,
-    Awassi,
-    Balkhi,
-    Baluchi,
-    BalwenWelshMountain,
-    BarbadosBlackbelly,
-    BavarianForest,
-    BentheimerLandschaf,
-    Bergamasca,
-    BeulahSpeckledFace,
-    Bibrik,
-    Biellese,
-    BlackWelshMountain,
-    BlackheadPersian,
-    BleuDuMaine,
-    BluefacedLeicester,
-    Bond,
-    BooroolaMerino,
-    BorderLeicester,
-    Boreray,
-    Bovska,
-    BraunesBergschaf,
-    BrazilianSomali,
-    BrecknockHillCheviot,
-    BritishMilk,
-    Brillenschaf,
-    BundnerOberland,
-    CaliforniaRed,
-    CaliforniaVariegatedMutant,
-    CampanianBarbary,
-    CastlemilkMoorit,
-    Charollais,
-    Cheviot,
-    Chios,
-    Cholistani,
-    ClunForest,
-    CoburgerFuchsschaf,
-    Columbia,
-    Comeback,
-    Comisana,
-    Coopworth,
-    Cormo,
-    Corriedale,
-    Cotswold,
-    Criollo,
-    Daglic,
-    Dala,
-    Dalesbred,
-    Damani,
-    Damara,
-    DanishLandrace,
-    Dartmoor,
-    Debouillet,
-    DelaineMerino,
-    DerbyshireGritstone,
-    Dorper,
-    DevonClosewool,
-    DeutschesBlaukoepfigesFleischschaf,
-    DorsetDown,
-    Dorset,
-    Drysdale,
-    Elliottdale,
-    ExmoorHorn,
-    Fabrianese,
-    Faeroes,
-    Finnsheep,
-    FonthillMerino,
-    FriesianMilk,
-    Galway,
-    GansuAlpineFineWool,
-    GentileDiPuglia,
-    GermanBlackheadedMutton,
-    GermanMountain,
-    GermanMuttonMerino,
-    GermanWhiteheadedMutton,
-    Gotland,
-    GraueGehoernteHeidschnucke,
-    Gromark,
-    GulfCoastNative,
-    Gute,
-    Hampshire,
-    Han,
-    Harnai,
-    HashtNagri,
-    Hazaragie,
-    Hebridean,
-    Herdwick,
-    HillRadnor,
-    HogIsland,
-    Hu,
-    Icelandic,
-    IleDeFrance,
-    IstrianPramenka,
-    Jacob,
-    JezerskoSolcava,
-    Kachhi,
-    Kajli,
-    Karakul,
-    Katahdin,
-    KerryHill,
-    Kooka,
-    Langhe,
-    Lati,
-    LeicesterLongwool,
-    Leineschaf,
-    Lincoln,
-    Llanwenog,
-    Lleyn,
-    Lohi,
-    Lonk,
-    Luzein,
-    ManxLoaghtan,
-    Masai,
-    Massese,
-    MediumWoolMerino,
-    Mehraban,
-    Merinolandschaf,
-    Moghani,
-    Montadale,
-    MoradaNova,
-    Mouflon,
-    NavajoChurro,
-    NorfolkHorn,
-    NorthCountryCheviot,
-    NorwegianFur,
-    OldNorwegian,
-    Orkney,
-    Ossimi,
-    Oxford,
-    Pagliarola,
-    Pelibuey,
-    Perendale,
-    Pinzirita,
-    PittIsland,
-    PollMerino,
-    Polwarth,
-    Polypay,
-    PomeranianCoarsewool,
-    Portland,
-    Priangan,
-    Qashqai,
-    QinghaiBlackTibetan,
-    QinghaiSemifinewool,
-    Quadrella,
-    QuanglinLargeTail,
-    RaboLargo,
-    Racka,
-    Rambouillet,
-    RasaAragonesa,
-    RedEngadine,
-    Rhoenschaf,
-    RideauArcott,
-    Romanov,
-    Romney,
-    RougeDeIQuest,
-    RoughFell,
-    RoyalWhite,
-    Rya,
-    Ryeland,
-    Rygja,
-    Sahel,
-    SantaCruz,
-    SantaInes,
-    Sardinian,
-    SarPlanina,
-    ScottishBlackface,
-    SicilianBarbary,
-    Shetland,
-    Shropshire,
-    Skudde,
-    Soay,
-    Somali,
-    Sopravissana,
-    SouthAfricanMerino,
-    SouthAfrianMuttonMerino,
-    SouthSuffolk,
-    Southdown,
-    SouthWalesMountain,
-    Spaeslau,
-    Spiegel,
-    StCroix,
-    Steigar,
-    Steinschaf,
-    StrongWoolMerino,
-    Suffolk,
-    Sumavska,
-    Swaledale,
-    SwedishFur,
-    Targhee,
-    Teeswater,
-    Texel,
-    Thalli,
-    Tong,
-    Touabire,
-    Tsurcana,
-    Tunis,
-    TyrolMountain,
-    Uda,
-    Ujumqin,
-    Ushant,
-    ValaisBlacknose,
-    Vendeen,
-    Walachenschaf,
-    WallisCountry,
-    Waziri,
-    WeisseHornloseHeidschnucke,
-    WelshHillSpeckledFace,
-    WelshMountain,
-    WelshMountainBadgerFaced,
-    Wensleydale,
-    WestAfricanDwarf,
-    WhiteSuffolk,
-    WhitefaceDartmoor,
-    WhitefaceWoodland,
-    WiltshireHorn,
-    XinjiangFinewool,
-    Yankasa,
-    YemenWhite,
-    Yemeni,
-    Yiecheng,
-    Yoroo,
-    YunnanSemifinewool,
-    Zaghawa,
-    Zagoria,
-    Zaian,
-    ZaireLongLegged,
-    Zakynthos,
-    ZeelandMilk,
-    Zel,
-    Zelazna,
-    Zemmour,
-    ZetaYellow,
-    Zlatusha,
-    Zoulay,
+breed_enum! {
+    /// An enum representing the different breeds of sheep.
+    ///
+    /// Initial data from: <https://breeds.okstate.edu/sheep/>
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::sheep::SheepBreed;
+    ///
+    /// let breed = SheepBreed::Dorper;
+    /// println!("{:?}", breed);
+    /// ```
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum SheepBreed {
+        Acipayam,
+        Adal,
+        AfghanArabi,
+        Africana,
+        Alai,
+        Alcarrena,
+        AlgarveChurro,
+        AlgerianArab,
+        Altai,
+        Altay,
+        AmericanBlackbelly,
+        Apennine,
+        Arabi,
+        ArapawaIsland,
+        Awassi,
+        Balkhi,
+        Baluchi,
+        BalwenWelshMountain,
+        BarbadosBlackbelly,
+        BavarianForest,
+        BentheimerLandschaf,
+        Bergamasca,
+        BeulahSpeckledFace,
+        Bibrik,
+        Biellese,
+        BlackWelshMountain,
+        BlackheadPersian,
+        BleuDuMaine,
+        BluefacedLeicester,
+        Bond,
+        BooroolaMerino,
+        BorderLeicester,
+        Boreray,
+        Bovska,
+        BraunesBergschaf,
+        BrazilianSomali,
+        BrecknockHillCheviot,
+        BritishMilk,
+        Brillenschaf,
+        BundnerOberland,
+        CaliforniaRed,
+        CaliforniaVariegatedMutant,
+        CampanianBarbary,
+        CastlemilkMoorit,
+        Charollais,
+        Cheviot,
+        Chios,
+        Cholistani,
+        ClunForest,
+        CoburgerFuchsschaf,
+        Columbia,
+        Comeback,
+        Comisana,
+        Coopworth,
+        Cormo,
+        Corriedale,
+        Cotswold,
+        Criollo,
+        Daglic,
+        Dala,
+        Dalesbred,
+        Damani,
+        Damara,
+        DanishLandrace,
+        Dartmoor,
+        Debouillet,
+        DelaineMerino,
+        DerbyshireGritstone,
+        Dorper,
+        DevonClosewool,
+        DeutschesBlaukoepfigesFleischschaf,
+        DorsetDown,
+        Dorset,
+        Drysdale,
+        Elliottdale,
+        ExmoorHorn,
+        Fabrianese,
+        Faeroes,
+        Finnsheep,
+        FonthillMerino,
+        FriesianMilk,
+        Galway,
+        GansuAlpineFineWool,
+        GentileDiPuglia,
+        GermanBlackheadedMutton,
+        GermanMountain,
+        GermanMuttonMerino,
+        GermanWhiteheadedMutton,
+        Gotland,
+        GraueGehoernteHeidschnucke,
+        Gromark,
+        GulfCoastNative,
+        Gute,
+        Hampshire,
+        Han,
+        Harnai,
+        HashtNagri,
+        Hazaragie,
+        Hebridean,
+        Herdwick,
+        HillRadnor,
+        HogIsland,
+        Hu,
+        Icelandic,
+        IleDeFrance,
+        IstrianPramenka,
+        Jacob,
+        JezerskoSolcava,
+        Kachhi,
+        Kajli,
+        Karakul,
+        Katahdin,
+        KerryHill,
+        Kooka,
+        Langhe,
+        Lati,
+        LeicesterLongwool,
+        Leineschaf,
+        Lincoln,
+        Llanwenog,
+        Lleyn,
+        Lohi,
+        Lonk,
+        Luzein,
+        ManxLoaghtan,
+        Masai,
+        Massese,
+        MediumWoolMerino,
+        Mehraban,
+        Merinolandschaf,
+        Moghani,
+        Montadale,
+        MoradaNova,
+        Mouflon,
+        NavajoChurro,
+        NorfolkHorn,
+        NorthCountryCheviot,
+        NorwegianFur,
+        OldNorwegian,
+        Orkney,
+        Ossimi,
+        Oxford,
+        Pagliarola,
+        Pelibuey,
+        Perendale,
+        Pinzirita,
+        PittIsland,
+        PollMerino,
+        Polwarth,
+        Polypay,
+        PomeranianCoarsewool,
+        Portland,
+        Priangan,
+        Qashqai,
+        QinghaiBlackTibetan,
+        QinghaiSemifinewool,
+        Quadrella,
+        QuanglinLargeTail,
+        RaboLargo,
+        Racka,
+        Rambouillet,
+        RasaAragonesa,
+        RedEngadine,
+        Rhoenschaf,
+        RideauArcott,
+        Romanov,
+        Romney,
+        RougeDeIQuest,
+        RoughFell,
+        RoyalWhite,
+        Rya,
+        Ryeland,
+        Rygja,
+        Sahel,
+        SantaCruz,
+        SantaInes,
+        Sardinian,
+        SarPlanina,
+        ScottishBlackface,
+        SicilianBarbary,
+        Shetland,
+        Shropshire,
+        Skudde,
+        Soay,
+        Somali,
+        Sopravissana,
+        SouthAfricanMerino,
+        SouthAfrianMuttonMerino,
+        SouthSuffolk,
+        Southdown,
+        SouthWalesMountain,
+        Spaeslau,
+        Spiegel,
+        StCroix,
+        Steigar,
+        Steinschaf,
+        StrongWoolMerino,
+        Suffolk,
+        Sumavska,
+        Swaledale,
+        SwedishFur,
+        Targhee,
+        Teeswater,
+        Texel,
+        Thalli,
+        Tong,
+        Touabire,
+        Tsurcana,
+        Tunis,
+        TyrolMountain,
+        Uda,
+        Ujumqin,
+        Ushant,
+        ValaisBlacknose,
+        Vendeen,
+        Walachenschaf,
+        WallisCountry,
+        Waziri,
+        WeisseHornloseHeidschnucke,
+        WelshHillSpeckledFace,
+        WelshMountain,
+        WelshMountainBadgerFaced,
+        Wensleydale,
+        WestAfricanDwarf,
+        WhiteSuffolk,
+        WhitefaceDartmoor,
+        WhitefaceWoodland,
+        WiltshireHorn,
+        XinjiangFinewool,
+        Yankasa,
+        YemenWhite,
+        Yemeni,
+        Yiecheng,
+        Yoroo,
+        YunnanSemifinewool,
+        Zaghawa,
+        Zagoria,
+        Zaian,
+        ZaireLongLegged,
+        Zakynthos,
+        ZeelandMilk,
+        Zel,
+        Zelazna,
+        Zemmour,
+        ZetaYellow,
+        Zlatusha,
+        Zoulay,
+    }
 }
 
-impl ToString for SheepBreed {
+impl fmt::Display for SheepBreed {
     /// Converts the SheepBreed enum to a human readable string.
     ///
     /// # Examples
@@ -274,23 +281,23 @@ impl ToString for SheepBreed {
     /// let vendeen = SheepBreed::Vendeen;
     /// println!("{}", vendeen.to_string());
     /// ```
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            SheepBreed::BeulahSpeckledFace => "Beulah Speckled-Face".to_string(),
-            SheepBreed::BleuDuMaine => "Bleu du Maine".to_string(),
-            SheepBreed::BundnerOberland => "Bündner Oberland".to_string(),
-            SheepBreed::GansuAlpineFineWool => "Gansu Alpine Fine-Wool".to_string(),
-            SheepBreed::IleDeFrance => "Ile-de-France".to_string(),
-            SheepBreed::JezerskoSolcava => "Jezersko-Solčava".to_string(),
-            SheepBreed::NavajoChurro => "Navajo-Churro".to_string(),
-            SheepBreed::QuanglinLargeTail => "Quanglin Large-Tail".to_string(),
-            SheepBreed::RougeDeIQuest => "Rouge de l'Ouest".to_string(),
-            SheepBreed::Sahel => "Sahel-type".to_string(),
-            SheepBreed::SantaInes => "Santa Inês".to_string(),
-            SheepBreed::StCroix => "St. Croix (Virgin Island White)".to_string(),
-            SheepBreed::Vendeen => "Vendéen".to_string(),
-            SheepBreed::ZaireLongLegged => "Zaire Long-Legged".to_string(),
-            _ => format!("{:?}", self).to_title_case(),
+            SheepBreed::BeulahSpeckledFace => f.write_str("Beulah Speckled-Face"),
+            SheepBreed::BleuDuMaine => f.write_str("Bleu du Maine"),
+            SheepBreed::BundnerOberland => f.write_str("Bündner Oberland"),
+            SheepBreed::GansuAlpineFineWool => f.write_str("Gansu Alpine Fine-Wool"),
+            SheepBreed::IleDeFrance => f.write_str("Ile-de-France"),
+            SheepBreed::JezerskoSolcava => f.write_str("Jezersko-Solčava"),
+            SheepBreed::NavajoChurro => f.write_str("Navajo-Churro"),
+            SheepBreed::QuanglinLargeTail => f.write_str("Quanglin Large-Tail"),
+            SheepBreed::RougeDeIQuest => f.write_str("Rouge de l'Ouest"),
+            SheepBreed::Sahel => f.write_str("Sahel-type"),
+            SheepBreed::SantaInes => f.write_str("Santa Inês"),
+            SheepBreed::StCroix => f.write_str("St. Croix (Virgin Island White)"),
+            SheepBreed::Vendeen => f.write_str("Vendéen"),
+            SheepBreed::ZaireLongLegged => f.write_str("Zaire Long-Legged"),
+            _ => f.write_str(&format!("{:?}", self).to_title_case()),
         }
     }
 }
@@ -305,9 +312,8 @@ impl ToString for SheepBreed {
 /// let breed = SheepBreed::from_str("Rouge de l'Ouest").unwrap();
 /// println!("{:?}", breed);
 /// ```
-
 impl FromStr for SheepBreed {
-    type Err = std::io::Error;
+    type Err = BreedParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().replace("-", " ").as_str() {
             "acipayam" => Ok(SheepBreed::Acipayam),
@@ -332,7 +338,7 @@ impl FromStr for SheepBreed {
             "bavarian forest" => Ok(SheepBreed::BavarianForest),
             "bentheimer landschaf" => Ok(SheepBreed::BentheimerLandschaf),
             "bergamasca" => Ok(SheepBreed::Bergamasca),
-            "beulah speckled face" | "beulah speckle face" => Ok(SheepBreed::BeulahSpeckledFace),
+            "beulah speckled face" => Ok(SheepBreed::BeulahSpeckledFace),
             "bibrik" => Ok(SheepBreed::Bibrik),
             "biellese" => Ok(SheepBreed::Biellese),
             "blackwelshmountain" => Ok(SheepBreed::BlackWelshMountain),
@@ -349,7 +355,7 @@ impl FromStr for SheepBreed {
             "brecknock hill cheviot" => Ok(SheepBreed::BrecknockHillCheviot),
             "british milk" => Ok(SheepBreed::BritishMilk),
             "brillenschaf" => Ok(SheepBreed::Brillenschaf),
-            "bundner oberland" | "bündner oberland" => Ok(SheepBreed::BundnerOberland),
+            "bundner oberland" => Ok(SheepBreed::BundnerOberland),
             "california red" => Ok(SheepBreed::CaliforniaRed),
             "california variegated mutant" => Ok(SheepBreed::CaliforniaVariegatedMutant),
             "campanian barbary" => Ok(SheepBreed::CampanianBarbary),
@@ -399,7 +405,7 @@ impl FromStr for SheepBreed {
             "german blackheaded mutton" => Ok(SheepBreed::GermanBlackheadedMutton),
             "german mountain" => Ok(SheepBreed::GermanMountain),
             "german mutton merino" => Ok(SheepBreed::GermanMuttonMerino),
-            "german whiteheaded mutton" => Ok(SheepBreed::GermanBlackheadedMutton),
+            "german whiteheaded mutton" => Ok(SheepBreed::GermanWhiteheadedMutton),
             "gotland" => Ok(SheepBreed::Gotland),
             "graue gehoernte heidschnucke" => Ok(SheepBreed::GraueGehoernteHeidschnucke),
             "gromark" => Ok(SheepBreed::Gromark),
@@ -419,7 +425,7 @@ impl FromStr for SheepBreed {
             "ile de france" => Ok(SheepBreed::IleDeFrance),
             "istrian pramenka" => Ok(SheepBreed::IstrianPramenka),
             "jacob" => Ok(SheepBreed::Jacob),
-            "jezersko solcava" | "jezersko solčava" => Ok(SheepBreed::JezerskoSolcava),
+            "jezersko solcava" => Ok(SheepBreed::JezerskoSolcava),
             "kachhi" => Ok(SheepBreed::Kachhi),
             "kajli" => Ok(SheepBreed::Kajli),
             "karakul" => Ok(SheepBreed::Karakul),
@@ -446,7 +452,7 @@ impl FromStr for SheepBreed {
             "montadale" => Ok(SheepBreed::Montadale),
             "morada nova" => Ok(SheepBreed::MoradaNova),
             "mouflon" => Ok(SheepBreed::Mouflon),
-            "navajo churro" | "navajo-churro" => Ok(SheepBreed::NavajoChurro),
+            "navajo churro" => Ok(SheepBreed::NavajoChurro),
             "norfolk horn" => Ok(SheepBreed::NorfolkHorn),
             "north country cheviot" => Ok(SheepBreed::NorthCountryCheviot),
             "norwegian fur" => Ok(SheepBreed::NorwegianFur),
@@ -469,7 +475,7 @@ impl FromStr for SheepBreed {
             "qinghai black tibetan" => Ok(SheepBreed::QinghaiBlackTibetan),
             "qinghai semifinewool" => Ok(SheepBreed::QinghaiSemifinewool),
             "quadrella" => Ok(SheepBreed::Quadrella),
-            "quanglin large tail" | "quanglin large-tail" => Ok(SheepBreed::QuanglinLargeTail),
+            "quanglin large tail" => Ok(SheepBreed::QuanglinLargeTail),
             "rabo largo" => Ok(SheepBreed::RaboLargo),
             "racka" => Ok(SheepBreed::Racka),
             "rambouillet" => Ok(SheepBreed::Rambouillet),
@@ -485,9 +491,9 @@ impl FromStr for SheepBreed {
             "rya" => Ok(SheepBreed::Rya),
             "ryeland" => Ok(SheepBreed::Ryeland),
             "rygja" => Ok(SheepBreed::Rygja),
-            "sahel" | "sahel type" => Ok(SheepBreed::Sahel),
+            "sahel" => Ok(SheepBreed::Sahel),
             "santa cruz" => Ok(SheepBreed::SantaCruz),
-            "santa ines" | "santa inês" => Ok(SheepBreed::SantaInes),
+            "santa ines" => Ok(SheepBreed::SantaInes),
             "sardinian" => Ok(SheepBreed::Sardinian),
             "sar planina" => Ok(SheepBreed::SarPlanina),
             "scottish blackface" => Ok(SheepBreed::ScottishBlackface),
@@ -505,11 +511,7 @@ impl FromStr for SheepBreed {
             "south wales mountain" => Ok(SheepBreed::SouthWalesMountain),
             "spaeslau" => Ok(SheepBreed::Spaeslau),
             "spiegel" => Ok(SheepBreed::Spiegel),
-            "st croix"
-            | "virgin island white"
-            | "st. croix (virgin island white)"
-            | "st. croix"
-            | "saint croix" => Ok(SheepBreed::StCroix),
+            "st croix" => Ok(SheepBreed::StCroix),
             "steigar" => Ok(SheepBreed::Steigar),
             "steinschaf" => Ok(SheepBreed::Steinschaf),
             "strong wool merino" => Ok(SheepBreed::StrongWoolMerino),
@@ -530,7 +532,7 @@ impl FromStr for SheepBreed {
             "ujumqin" => Ok(SheepBreed::Ujumqin),
             "ushant" => Ok(SheepBreed::Ushant),
             "valais blacknose" => Ok(SheepBreed::ValaisBlacknose),
-            "vendeen" | "vendéen" => Ok(SheepBreed::Vendeen),
+            "vendeen" => Ok(SheepBreed::Vendeen),
             "walachenschaf" => Ok(SheepBreed::Walachenschaf),
             "wallis country" => Ok(SheepBreed::WallisCountry),
             "waziri" => Ok(SheepBreed::Waziri),
@@ -563,14 +565,393 @@ impl FromStr for SheepBreed {
             "zeta yellow" => Ok(SheepBreed::ZetaYellow),
             "zlatusha" => Ok(SheepBreed::Zlatusha),
             "zoulay" => Ok(SheepBreed::Zoulay),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Unknown sheep breed",
-            )),
+            _ => fuzzy::resolve(VARIANTS, s),
         }
     }
 }
 
+impl SheepBreed {
+    /// Returns every sheep breed, in declaration order.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::sheep::SheepBreed;
+    ///
+    /// let breeds = SheepBreed::all();
+    /// assert_eq!(breeds.len(), SheepBreed::count());
+    /// ```
+    pub fn all() -> &'static [SheepBreed] {
+        VARIANTS
+    }
+
+    /// Returns an iterator over every sheep breed, in declaration order.
+    pub fn iter() -> impl Iterator<Item = SheepBreed> {
+        VARIANTS.iter().copied()
+    }
+
+    /// Returns the number of sheep breeds.
+    pub fn count() -> usize {
+        VARIANTS.len()
+    }
+
+    /// Returns the sheep breeds whose names closely match the query, best match first.
+    ///
+    /// Case, diacritics and punctuation are ignored, and small typos are tolerated.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::sheep::SheepBreed;
+    ///
+    /// let matches = SheepBreed::fuzzy_search("Dorpr");
+    /// assert_eq!(matches[0].breed, SheepBreed::Dorper);
+    /// ```
+    pub fn fuzzy_search(query: &str) -> Vec<BreedMatch<SheepBreed>> {
+        fuzzy::search(VARIANTS, query)
+    }
+
+    /// Returns the sheep breeds raised primarily for the given purpose.
+    ///
+    /// Metadata has only been compiled for some sheep breeds, those returned by
+    /// [`SheepBreed::with_metadata`], so other breeds are left out even if they would match.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::sheep::SheepBreed;
+    /// use livestock_rs::breeds::metadata::Purpose;
+    ///
+    /// let breeds = SheepBreed::by_purpose(Purpose::Meat);
+    /// assert!(breeds.contains(&SheepBreed::AmericanBlackbelly));
+    /// ```
+    pub fn by_purpose(purpose: Purpose) -> Vec<SheepBreed> {
+        metadata::filter(METADATA, |metadata| metadata.purpose == purpose)
+    }
+
+    /// Returns the sheep breeds that originated in the given region.
+    ///
+    /// Metadata has only been compiled for some sheep breeds, those returned by
+    /// [`SheepBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_region(region: Region) -> Vec<SheepBreed> {
+        metadata::filter(METADATA, |metadata| metadata.region == region)
+    }
+
+    /// Returns the sheep breeds of the given size class.
+    ///
+    /// Metadata has only been compiled for some sheep breeds, those returned by
+    /// [`SheepBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_size_class(size_class: SizeClass) -> Vec<SheepBreed> {
+        metadata::filter(METADATA, |metadata| metadata.size_class == size_class)
+    }
+
+    /// Returns the sheep breeds that have metadata.
+    ///
+    /// Metadata covers the better documented breeds first, so this is usually a subset of
+    /// [`SheepBreed::all`].
+    pub fn with_metadata() -> Vec<SheepBreed> {
+        METADATA.iter().map(|(breed, _)| *breed).collect()
+    }
+}
+
+impl Breed for SheepBreed {
+    fn species(&self) -> LivestockType {
+        LivestockType::Sheep
+    }
+
+    fn metadata(&self) -> Option<BreedMetadata> {
+        metadata::lookup(METADATA, self)
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        ALIASES
+            .iter()
+            .filter(|(breed, _)| breed == self)
+            .map(|(_, alias)| *alias)
+            .collect()
+    }
+}
+
+/// Metadata for the sheep breeds with well documented characteristics.
+const METADATA: &[(SheepBreed, BreedMetadata)] = &[
+    (
+        SheepBreed::AmericanBlackbelly,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Medium,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        SheepBreed::Awassi,
+        BreedMetadata::new(
+            "Syria",
+            Region::MiddleEast,
+            Purpose::Dairy,
+            SizeClass::Medium,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        SheepBreed::BarbadosBlackbelly,
+        BreedMetadata::new(
+            "Barbados",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Columbia,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Dual,
+            SizeClass::Large,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Corriedale,
+        BreedMetadata::new(
+            "New Zealand",
+            Region::Oceania,
+            Purpose::Dual,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Dorper,
+        BreedMetadata::new(
+            "South Africa",
+            Region::Africa,
+            Purpose::Meat,
+            SizeClass::Large,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Dorset,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Medium,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        SheepBreed::Finnsheep,
+        BreedMetadata::new(
+            "Finland",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Small,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::FriesianMilk,
+        BreedMetadata::new(
+            "Germany",
+            Region::Europe,
+            Purpose::Dairy,
+            SizeClass::Large,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Hampshire,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Large,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Icelandic,
+        BreedMetadata::new(
+            "Iceland",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Small,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        SheepBreed::Jacob,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Small,
+            Some(Horns::Horned),
+        ),
+    ),
+    (
+        SheepBreed::Karakul,
+        BreedMetadata::new(
+            "Uzbekistan",
+            Region::Asia,
+            Purpose::Fur,
+            SizeClass::Medium,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        SheepBreed::Katahdin,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Lincoln,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Fiber,
+            SizeClass::Large,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Pelibuey,
+        BreedMetadata::new(
+            "Cuba",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Small,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Polypay,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Dual,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Rambouillet,
+        BreedMetadata::new(
+            "France",
+            Region::Europe,
+            Purpose::Fiber,
+            SizeClass::Large,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        SheepBreed::Romanov,
+        BreedMetadata::new(
+            "Russia",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Small,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Romney,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Dual,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Shetland,
+        BreedMetadata::new(
+            "Scotland",
+            Region::Europe,
+            Purpose::Fiber,
+            SizeClass::Small,
+            Some(Horns::Variable),
+        ),
+    ),
+    (
+        SheepBreed::Southdown,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Small,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::StCroix,
+        BreedMetadata::new(
+            "United States Virgin Islands",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Suffolk,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Large,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Targhee,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Dual,
+            SizeClass::Large,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::Texel,
+        BreedMetadata::new(
+            "Netherlands",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Medium,
+            Some(Horns::Polled),
+        ),
+    ),
+    (
+        SheepBreed::WiltshireHorn,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Large,
+            Some(Horns::Horned),
+        ),
+    ),
+];
+
+/// Alternative names a sheep breed is known by, in addition to its canonical name.
+const ALIASES: &[(SheepBreed, &str)] = &[
+    (SheepBreed::BeulahSpeckledFace, "beulah speckle face"),
+    (SheepBreed::Sahel, "sahel type"),
+    (SheepBreed::StCroix, "virgin island white"),
+    (SheepBreed::StCroix, "st. croix (virgin island white)"),
+    (SheepBreed::StCroix, "saint croix"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -622,4 +1003,46 @@ mod tests {
             assert_eq!(SheepBreed::from_str(breed).unwrap(), *expected);
         }
     }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = SheepBreed::AmericanBlackbelly.metadata().unwrap();
+        assert_eq!(metadata.origin, "United States");
+        assert_eq!(metadata.purpose, Purpose::Meat);
+        assert_eq!(metadata.size_class, SizeClass::Medium);
+
+        for breed in SheepBreed::by_purpose(Purpose::Meat) {
+            assert_eq!(breed.metadata().unwrap().purpose, Purpose::Meat);
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let matches = SheepBreed::fuzzy_search("Dorpr");
+        assert_eq!(matches[0].breed, SheepBreed::Dorper);
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_from_str_suggestions() {
+        let err = SheepBreed::from_str("Dorpr").unwrap_err();
+        assert_eq!(err.suggestions[0], SheepBreed::Dorper.to_string());
+    }
+
+    #[test]
+    fn test_variant_names_are_unique() {
+        for breed in SheepBreed::iter() {
+            assert_eq!(SheepBreed::from_str(&breed.to_string()).unwrap(), breed);
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        // Variants are numbered in declaration order, so each one is listed at its own index.
+        for (index, breed) in SheepBreed::iter().enumerate() {
+            assert_eq!(breed as usize, index);
+        }
+    }
 }
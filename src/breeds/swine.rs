@@ -1,91 +1,98 @@
+use super::fuzzy::{self, BreedMatch};
+use super::metadata::{self, BreedMetadata, Purpose, Region, SizeClass};
+use super::{Breed, BreedParseError};
+use crate::types::LivestockType;
 use inflector::Inflector;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
-/// An enum representing the different breeds of swine.
-///
-/// Initial data from: <https://breeds.okstate.edu/swine/>
-///
-/// # Examples
-/// ``` rust
-/// use livestock_rs::breeds::swine::SwineBreed;
-///
-/// let breed = SwineBreed::Kunekune;
-/// println!("{:?}", breed);
-/// ```
-#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
-pub enum SwineBreed {
-    AmericanLandrace,
-    AmericanYorkshire,
-    AngelnSaddleback,
-    ArapawaIsland,
-    BaXuyen,
-    Bantu,
-    BeijingBlack,
-    BelarusBlackPied,
-    BelgianLandrace,
-    Berkshire,
-    BritishLandrace,
-    BritishLop,
-    BulgarianWhite,
-    Cantonese,
-    ChesterWhite,
-    ChoctawHog,
-    CzechImprovedWhite,
-    DanishLandrace,
-    Duroc,
-    DutchLandrace,
-    Fengjing,
-    FinnishLandrace,
-    FrenchLandrace,
-    GermanLandrace,
-    GloucestershireOldSpot,
-    GuineaHog,
-    Hampshire,
-    Hereford,
-    Hezuo,
-    Iberian,
-    ItalianLandrace,
-    Jinhua,
-    Kele,
-    Krskopolje,
-    Kunekune,
-    Lacombe,
-    LargeBlack,
-    LargeBlackWhite,
-    LargeWhite,
-    Lithuanian,
-    Mangalitza,
-    Meishan,
-    MiddleWhite,
-    MongCai,
-    Minzhu,
-    MoraRomagnola,
-    Mukota,
-    Mulefoot,
-    Neijiang,
-    Ningxiang,
-    NorwegianLandrace,
-    OssabawIsland,
-    OxfordSandyAndBlack,
-    PhilippineNative,
-    Pietrain,
-    PolandChina,
-    RedWattle,
-    Saddleback,
-    Spotted,
-    SwedishLandrace,
-    Tamworth,
-    ThuocNhieu,
-    Tibetan,
-    Thuropolje,
-    VietnamesePotbelly,
-    Welsh,
-    Wuzhishan,
-    Yorkshire,
+breed_enum! {
+    /// An enum representing the different breeds of swine.
+    ///
+    /// Initial data from: <https://breeds.okstate.edu/swine/>
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::swine::SwineBreed;
+    ///
+    /// let breed = SwineBreed::Kunekune;
+    /// println!("{:?}", breed);
+    /// ```
+    #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+    pub enum SwineBreed {
+        AmericanLandrace,
+        AmericanYorkshire,
+        AngelnSaddleback,
+        ArapawaIsland,
+        BaXuyen,
+        Bantu,
+        BeijingBlack,
+        BelarusBlackPied,
+        BelgianLandrace,
+        Berkshire,
+        BritishLandrace,
+        BritishLop,
+        BulgarianWhite,
+        Cantonese,
+        ChesterWhite,
+        ChoctawHog,
+        CzechImprovedWhite,
+        DanishLandrace,
+        Duroc,
+        DutchLandrace,
+        Fengjing,
+        FinnishLandrace,
+        FrenchLandrace,
+        GermanLandrace,
+        GloucestershireOldSpot,
+        GuineaHog,
+        Hampshire,
+        Hereford,
+        Hezuo,
+        Iberian,
+        ItalianLandrace,
+        Jinhua,
+        Kele,
+        Krskopolje,
+        Kunekune,
+        Lacombe,
+        LargeBlack,
+        LargeBlackWhite,
+        LargeWhite,
+        Lithuanian,
+        Mangalitza,
+        Meishan,
+        MiddleWhite,
+        MongCai,
+        Minzhu,
+        MoraRomagnola,
+        Mukota,
+        Mulefoot,
+        Neijiang,
+        Ningxiang,
+        NorwegianLandrace,
+        OssabawIsland,
+        OxfordSandyAndBlack,
+        PhilippineNative,
+        Pietrain,
+        PolandChina,
+        RedWattle,
+        Saddleback,
+        Spotted,
+        SwedishLandrace,
+        Tamworth,
+        ThuocNhieu,
+        Tibetan,
+        Thuropolje,
+        VietnamesePotbelly,
+        Welsh,
+        Wuzhishan,
+        Yorkshire,
+    }
 }
 
-impl ToString for SwineBreed {
+impl fmt::Display for SwineBreed {
     /// Converts the SwineBreed enum to a human readable string.
     ///
     /// # Examples
@@ -95,10 +102,10 @@ impl ToString for SwineBreed {
     /// let kune = SwineBreed::Kunekune;
     /// println!("{}", kune.to_string());
     /// ```
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            SwineBreed::LargeBlackWhite => "Large Black-White".to_string(),
-            _ => format!("{:?}", self).to_title_case(),
+            SwineBreed::LargeBlackWhite => f.write_str("Large Black-White"),
+            _ => f.write_str(&format!("{:?}", self).to_title_case()),
         }
     }
 }
@@ -113,15 +120,14 @@ impl ToString for SwineBreed {
 /// let breed = SwineBreed::from_str("kunekune").unwrap();
 /// println!("{:?}", breed);
 /// ```
-
 impl FromStr for SwineBreed {
-    type Err = std::io::Error;
+    type Err = BreedParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "american landrace" => Ok(SwineBreed::AmericanLandrace),
             "american yorkshire" => Ok(SwineBreed::AmericanYorkshire),
             "angeln saddleback" => Ok(SwineBreed::AngelnSaddleback),
-            "arapawa island" | "arapawa" => Ok(SwineBreed::ArapawaIsland),
+            "arapawa island" => Ok(SwineBreed::ArapawaIsland),
             "ba xuyen" => Ok(SwineBreed::BaXuyen),
             "bantu" => Ok(SwineBreed::Bantu),
             "beijing black" => Ok(SwineBreed::BeijingBlack),
@@ -155,7 +161,7 @@ impl FromStr for SwineBreed {
             "kunekune" => Ok(SwineBreed::Kunekune),
             "lacombe" => Ok(SwineBreed::Lacombe),
             "large black" => Ok(SwineBreed::LargeBlack),
-            "large black-white" | "large black white" => Ok(SwineBreed::LargeBlackWhite),
+            "large black-white" => Ok(SwineBreed::LargeBlackWhite),
             "large white" => Ok(SwineBreed::LargeWhite),
             "lithuanian" => Ok(SwineBreed::Lithuanian),
             "mangalitza" => Ok(SwineBreed::Mangalitza),
@@ -170,9 +176,7 @@ impl FromStr for SwineBreed {
             "ningxiang" => Ok(SwineBreed::Ningxiang),
             "norwegian landrace" => Ok(SwineBreed::NorwegianLandrace),
             "ossabaw island" => Ok(SwineBreed::OssabawIsland),
-            "oxford sandy and black" | "oxford sandy & black" => {
-                Ok(SwineBreed::OxfordSandyAndBlack)
-            }
+            "oxford sandy and black" => Ok(SwineBreed::OxfordSandyAndBlack),
             "philippine native" => Ok(SwineBreed::PhilippineNative),
             "pietrain" => Ok(SwineBreed::Pietrain),
             "poland china" => Ok(SwineBreed::PolandChina),
@@ -184,20 +188,346 @@ impl FromStr for SwineBreed {
             "thuoc nhieu" => Ok(SwineBreed::ThuocNhieu),
             "tibetan" => Ok(SwineBreed::Tibetan),
             "thuropolje" => Ok(SwineBreed::Thuropolje),
-            "vietnamese potbelly" | "potbelly" | "potbellied" | "vietnamese potbellied" => {
-                Ok(SwineBreed::VietnamesePotbelly)
-            }
+            "vietnamese potbelly" => Ok(SwineBreed::VietnamesePotbelly),
             "welsh" => Ok(SwineBreed::Welsh),
             "wuzhishan" => Ok(SwineBreed::Wuzhishan),
             "yorkshire" => Ok(SwineBreed::Yorkshire),
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Invalid reindeer breed",
-            )),
+            _ => fuzzy::resolve(VARIANTS, s),
         }
     }
 }
 
+impl SwineBreed {
+    /// Returns every swine breed, in declaration order.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::swine::SwineBreed;
+    ///
+    /// let breeds = SwineBreed::all();
+    /// assert_eq!(breeds.len(), SwineBreed::count());
+    /// ```
+    pub fn all() -> &'static [SwineBreed] {
+        VARIANTS
+    }
+
+    /// Returns an iterator over every swine breed, in declaration order.
+    pub fn iter() -> impl Iterator<Item = SwineBreed> {
+        VARIANTS.iter().copied()
+    }
+
+    /// Returns the number of swine breeds.
+    pub fn count() -> usize {
+        VARIANTS.len()
+    }
+
+    /// Returns the swine breeds whose names closely match the query, best match first.
+    ///
+    /// Case, diacritics and punctuation are ignored, and small typos are tolerated.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::swine::SwineBreed;
+    ///
+    /// let matches = SwineBreed::fuzzy_search("Hampshir");
+    /// assert_eq!(matches[0].breed, SwineBreed::Hampshire);
+    /// ```
+    pub fn fuzzy_search(query: &str) -> Vec<BreedMatch<SwineBreed>> {
+        fuzzy::search(VARIANTS, query)
+    }
+
+    /// Returns the swine breeds raised primarily for the given purpose.
+    ///
+    /// Metadata has only been compiled for some swine breeds, those returned by
+    /// [`SwineBreed::with_metadata`], so other breeds are left out even if they would match.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::breeds::swine::SwineBreed;
+    /// use livestock_rs::breeds::metadata::Purpose;
+    ///
+    /// let breeds = SwineBreed::by_purpose(Purpose::Meat);
+    /// assert!(breeds.contains(&SwineBreed::AmericanLandrace));
+    /// ```
+    pub fn by_purpose(purpose: Purpose) -> Vec<SwineBreed> {
+        metadata::filter(METADATA, |metadata| metadata.purpose == purpose)
+    }
+
+    /// Returns the swine breeds that originated in the given region.
+    ///
+    /// Metadata has only been compiled for some swine breeds, those returned by
+    /// [`SwineBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_region(region: Region) -> Vec<SwineBreed> {
+        metadata::filter(METADATA, |metadata| metadata.region == region)
+    }
+
+    /// Returns the swine breeds of the given size class.
+    ///
+    /// Metadata has only been compiled for some swine breeds, those returned by
+    /// [`SwineBreed::with_metadata`], so other breeds are left out even if they would match.
+    pub fn by_size_class(size_class: SizeClass) -> Vec<SwineBreed> {
+        metadata::filter(METADATA, |metadata| metadata.size_class == size_class)
+    }
+
+    /// Returns the swine breeds that have metadata.
+    ///
+    /// Metadata covers the better documented breeds first, so this is usually a subset of
+    /// [`SwineBreed::all`].
+    pub fn with_metadata() -> Vec<SwineBreed> {
+        METADATA.iter().map(|(breed, _)| *breed).collect()
+    }
+}
+
+impl Breed for SwineBreed {
+    fn species(&self) -> LivestockType {
+        LivestockType::Swine
+    }
+
+    fn metadata(&self) -> Option<BreedMetadata> {
+        metadata::lookup(METADATA, self)
+    }
+
+    fn aliases(&self) -> Vec<&'static str> {
+        ALIASES
+            .iter()
+            .filter(|(breed, _)| breed == self)
+            .map(|(_, alias)| *alias)
+            .collect()
+    }
+}
+
+/// Metadata for the swine breeds with well documented characteristics.
+const METADATA: &[(SwineBreed, BreedMetadata)] = &[
+    (
+        SwineBreed::AmericanLandrace,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::Berkshire,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::ChesterWhite,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::DanishLandrace,
+        BreedMetadata::new(
+            "Denmark",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::Duroc,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::GloucestershireOldSpot,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::GuineaHog,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::Hampshire,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::Iberian,
+        BreedMetadata::new(
+            "Spain",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::Kunekune,
+        BreedMetadata::new(
+            "New Zealand",
+            Region::Oceania,
+            Purpose::Meat,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::LargeBlack,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::LargeWhite,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::Mangalitza,
+        BreedMetadata::new(
+            "Hungary",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::Meishan,
+        BreedMetadata::new(
+            "China",
+            Region::Asia,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::Mulefoot,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::OssabawIsland,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Small,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::Pietrain,
+        BreedMetadata::new(
+            "Belgium",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::PolandChina,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::RedWattle,
+        BreedMetadata::new(
+            "United States",
+            Region::NorthAmerica,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::Tamworth,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Medium,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::VietnamesePotbelly,
+        BreedMetadata::new(
+            "Vietnam",
+            Region::Asia,
+            Purpose::Companion,
+            SizeClass::Miniature,
+            None,
+        ),
+    ),
+    (
+        SwineBreed::Yorkshire,
+        BreedMetadata::new(
+            "England",
+            Region::Europe,
+            Purpose::Meat,
+            SizeClass::Large,
+            None,
+        ),
+    ),
+];
+
+/// Alternative names a swine breed is known by, in addition to its canonical name.
+const ALIASES: &[(SwineBreed, &str)] = &[
+    (SwineBreed::ArapawaIsland, "arapawa"),
+    (SwineBreed::VietnamesePotbelly, "potbelly"),
+    (SwineBreed::VietnamesePotbelly, "potbellied"),
+    (SwineBreed::VietnamesePotbelly, "vietnamese potbellied"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -265,7 +595,49 @@ mod tests {
         ];
 
         for (breed, expected) in breeds.iter() {
-            assert_eq!(SwineBreed::from_str(*breed).unwrap(), *expected);
+            assert_eq!(SwineBreed::from_str(breed).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let metadata = SwineBreed::AmericanLandrace.metadata().unwrap();
+        assert_eq!(metadata.origin, "United States");
+        assert_eq!(metadata.purpose, Purpose::Meat);
+        assert_eq!(metadata.size_class, SizeClass::Large);
+
+        for breed in SwineBreed::by_purpose(Purpose::Meat) {
+            assert_eq!(breed.metadata().unwrap().purpose, Purpose::Meat);
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let matches = SwineBreed::fuzzy_search("Hampshir");
+        assert_eq!(matches[0].breed, SwineBreed::Hampshire);
+        assert!(matches
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn test_from_str_suggestions() {
+        let err = SwineBreed::from_str("Hampshir").unwrap_err();
+        assert_eq!(err.suggestions[0], SwineBreed::Hampshire.to_string());
+    }
+
+    #[test]
+    fn test_variant_names_are_unique() {
+        for breed in SwineBreed::iter() {
+            assert_eq!(SwineBreed::from_str(&breed.to_string()).unwrap(), breed);
+        }
+    }
+
+    #[test]
+    fn test_all() {
+        // Variants are numbered in declaration order, so each one is listed at its own index.
+        for (index, breed) in SwineBreed::iter().enumerate() {
+            assert_eq!(breed as usize, index);
         }
     }
 }
@@ -1,5 +1,8 @@
-use anyhow::{ensure, Result};
-use crate::types::LivestockType;
+use crate::types::{FeedingPhase, LivestockType};
+use anyhow::{anyhow, ensure, Context, Result};
+use clap::ValueEnum;
+use serde::Deserialize;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum FeedEfficiencyRating {
@@ -12,78 +15,345 @@ pub enum FeedEfficiencyRating {
 pub struct FeedEfficiency {
     pub rating: FeedEfficiencyRating,
     pub value: f64,
+    /// The feeding phase the benchmark is for, or `None` for the species as a whole.
+    pub phase: Option<FeedingPhase>,
     pub avg_min_fcr: f64,
-    pub avg_max_fcr: f64
+    pub avg_max_fcr: f64,
+}
+
+/// The range of average FCR for a type of livestock, overall or in one feeding phase. An FCR
+/// below the range is good, and above it poor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FcrBenchmark {
+    pub livestock_type: LivestockType,
+    pub phase: Option<FeedingPhase>,
+    pub avg_min_fcr: f64,
+    pub avg_max_fcr: f64,
+}
+
+/// A table of FCR benchmarks, looked up by type of livestock and feeding phase.
+///
+/// The built-in table has an overall range for most types of livestock, and ranges for each
+/// feeding phase of swine, cattle and chickens. Its ranges can be overridden, and phases added for other species, for
+/// example with a farm's own records or a breed's targets, from a TOML file.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::feed::efficiency::FcrBenchmarks;
+/// use livestock_rs::types::{FeedingPhase, LivestockType};
+///
+/// let mut benchmarks = FcrBenchmarks::builtin();
+/// benchmarks.extend_from_toml("
+///     [[benchmark]]
+///     livestock_type = \"cattle\"
+///     phase = \"finisher\"
+///     min_fcr = 5.8
+///     max_fcr = 7.0
+/// ").unwrap();
+///
+/// let finisher = benchmarks.get(LivestockType::Cattle, Some(FeedingPhase::Finisher)).unwrap();
+/// assert_eq!((finisher.avg_min_fcr, finisher.avg_max_fcr), (5.8, 7.0));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FcrBenchmarks {
+    benchmarks: BTreeMap<(LivestockType, Option<FeedingPhase>), FcrBenchmark>,
+}
+
+impl FcrBenchmarks {
+    /// An empty table.
+    pub fn new() -> Self {
+        FcrBenchmarks::default()
+    }
+
+    /// The built-in table of FCR benchmarks, as fed.
+    ///
+    /// Overall ranges are from <https://www.farmbrite.com/post/feed-conversion-ratio-calculator>,
+    /// except for:
+    ///
+    /// - Horses: the dry matter feed:gain of a weanling of 6 months (216 kg, gaining 0.72
+    ///   kg/day) and a yearling (321 kg, gaining 0.50 kg/day) growing to 500 kg, from the NRC
+    ///   (2007) growth tables, eating 2.5% of body weight as growing horses do.
+    /// - Donkeys: the same weanling and yearling, with weights and gains scaled to a 200 kg
+    ///   mature donkey, eating 2.0% of body weight. Scaling leaves the feed:gain unchanged but
+    ///   for the lower intake.
+    /// - Camels: fattening young dromedaries, from Kamoun (1995), "Dromedary meat: production,
+    ///   qualitative aspects and acceptability for transformation", Options Méditerranéennes.
+    ///
+    /// There is no published FCR range for reindeer, so they have no benchmark until one is
+    /// added.
+    ///
+    /// Swine phase ranges are from the expected feed intake and gain of each weight class in
+    /// NRC (1998) Table 10-1, on diets at 90% dry matter. A phase's range runs from the
+    /// feed:gain of its lightest class to that of its heaviest, with the phases split by weight
+    /// as in [`FeedingPhase::for_weight`].
+    ///
+    /// Cattle phase ranges are from the dry matter intake NASEM (2016) predicts for medium-frame
+    /// steers on a diet that just meets their energy needs, as in
+    /// [`calculate_requirements`](crate::calculators::feed::requirements::calculate_requirements):
+    /// backgrounding steers of 250 to 350 kg gaining 0.9 kg/day, and finishing steers of 400 to
+    /// 550 kg gaining 1.6 kg/day. They are dry matter feed:gain, so multiply an as-fed FCR by
+    /// the diet's dry matter proportion to rate it against them.
+    ///
+    /// Chicken phase ranges are the cumulative FCR of Ross 308 broilers, as hatched, from the
+    /// Ross 308 Performance Objectives (Aviagen, 2022), at the first and last day of each phase
+    /// of the feeding program in [`FeedingPhase::for_age`]. Starter runs from day 7, as the
+    /// objectives' FCR starts there.
+    ///
+    /// Other species have no built-in phase ranges, as their phases and feeding programs differ
+    /// too much between farms; add them from a file.
+    pub fn builtin() -> Self {
+        use FeedingPhase::*;
+        use LivestockType::*;
+
+        let table = [
+            (Cattle, None, 8.0, 12.0),
+            // Dry matter feed:gain of the lightest and heaviest steer in the phase.
+            (Cattle, Some(Backgrounding), 6.66, 8.57),
+            (Cattle, Some(Finisher), 5.02, 6.38),
+            (Goat, None, 4.5, 5.5),
+            (Sheep, None, 4.5, 5.5),
+            (Swine, None, 3.0, 3.9),
+            // Feed intake over gain, in g/day, of the weight classes at either end of the phase.
+            (Swine, Some(Starter), 500.0 / 450.0, 250.0 / 175.0),
+            (Swine, Some(Grower), 1000.0 / 550.0, 1855.0 / 700.0),
+            (Swine, Some(Finisher), 2575.0 / 820.0, 3075.0 / 850.0),
+            (Chicken, None, 1.5, 2.0),
+            (Horse, None, 0.025 * 216.0 / 0.72, 0.025 * 321.0 / 0.50),
+            (Donkey, None, 0.02 * 216.0 / 0.72, 0.02 * 321.0 / 0.50),
+            (Camel, None, 6.0, 9.0),
+            // Cumulative FCR on the first and last day of the phase.
+            (Chicken, Some(Starter), 0.87, 0.95),
+            (Chicken, Some(Grower), 0.97, 1.30),
+            (Chicken, Some(Finisher), 1.32, 1.60),
+            (Rabbit, None, 3.5, 5.0),
+        ];
+
+        let mut benchmarks = FcrBenchmarks::new();
+        for (livestock_type, phase, avg_min_fcr, avg_max_fcr) in table {
+            benchmarks
+                .insert(FcrBenchmark {
+                    livestock_type,
+                    phase,
+                    avg_min_fcr,
+                    avg_max_fcr,
+                })
+                .expect("Built-in FCR benchmarks are valid.");
+        }
+        benchmarks
+    }
+
+    /// The benchmark for a type of livestock in a feeding phase, or overall if `phase` is `None`.
+    pub fn get(
+        &self,
+        livestock_type: LivestockType,
+        phase: Option<FeedingPhase>,
+    ) -> Option<&FcrBenchmark> {
+        self.benchmarks.get(&(livestock_type, phase))
+    }
+
+    /// Every benchmark in the table, by type of livestock and then phase.
+    pub fn iter(&self) -> impl Iterator<Item = &FcrBenchmark> {
+        self.benchmarks.values()
+    }
+
+    /// Add a benchmark to the table, replacing any for the same type of livestock and phase.
+    ///
+    /// # Returns
+    /// The benchmark it replaced, or an error if the range is not positive and in order.
+    pub fn insert(&mut self, benchmark: FcrBenchmark) -> Result<Option<FcrBenchmark>> {
+        ensure!(
+            benchmark.avg_min_fcr > 0.0 && benchmark.avg_min_fcr <= benchmark.avg_max_fcr,
+            "The FCR range of {:?} must be greater than 0 with the minimum at most the maximum, found {} to {}.",
+            benchmark.livestock_type, benchmark.avg_min_fcr, benchmark.avg_max_fcr
+        );
+
+        Ok(self
+            .benchmarks
+            .insert((benchmark.livestock_type, benchmark.phase), benchmark))
+    }
+
+    /// Add benchmarks from TOML, replacing any for the same type of livestock and phase.
+    ///
+    /// Each benchmark is a `[[benchmark]]` table, with `phase` left out for the overall range:
+    ///
+    /// ```toml
+    /// [[benchmark]]
+    /// livestock_type = "swine"
+    /// phase = "finisher"
+    /// min_fcr = 2.6
+    /// max_fcr = 3.1
+    /// ```
+    ///
+    /// # Returns
+    /// The number of benchmarks read, or an error naming the first invalid table.
+    pub fn extend_from_toml(&mut self, contents: &str) -> Result<usize> {
+        let file: BenchmarkFile =
+            toml::from_str(contents).context("Invalid FCR benchmark TOML.")?;
+        let benchmarks = file
+            .benchmark
+            .iter()
+            .enumerate()
+            .map(|(index, table)| {
+                table.to_benchmark().with_context(|| {
+                    format!("Invalid benchmark in [[benchmark]] table {}.", index + 1)
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Check every benchmark before replacing any.
+        let mut extended = self.clone();
+        for benchmark in &benchmarks {
+            extended.insert(*benchmark)?;
+        }
+        *self = extended;
+        Ok(benchmarks.len())
+    }
+}
+
+/// The `[[benchmark]]` tables of a TOML benchmark file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BenchmarkFile {
+    #[serde(default)]
+    benchmark: Vec<BenchmarkTable>,
+}
+
+/// A `[[benchmark]]` table. The type of livestock and phase are matched ignoring case, as on the
+/// command line.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BenchmarkTable {
+    livestock_type: String,
+    phase: Option<String>,
+    min_fcr: f64,
+    max_fcr: f64,
+}
+
+impl BenchmarkTable {
+    fn to_benchmark(&self) -> Result<FcrBenchmark> {
+        Ok(FcrBenchmark {
+            livestock_type: LivestockType::from_str(&self.livestock_type, true)
+                .map_err(|err| anyhow!(err))?,
+            phase: self
+                .phase
+                .as_deref()
+                .map(|phase| FeedingPhase::from_str(phase, true).map_err(|err| anyhow!(err)))
+                .transpose()?,
+            avg_min_fcr: self.min_fcr,
+            avg_max_fcr: self.max_fcr,
+        })
+    }
 }
 
 /// Calculate Feed Efficiency for livestock.
-/// 
+///
 /// # Arguments
 /// - `fcr`: Feed Conversion Ratio (FCR) of the animal.
 /// - `livestock_type`: The type of livestock.
-/// 
+///
 /// # Returns
 /// The feed efficiency of the animal.
-/// 
+///
 /// # Example
 /// ```
 /// use livestock_rs::calculators::feed::efficiency::{calculate_feed_efficiency, FeedEfficiency, FeedEfficiencyRating};
 /// use livestock_rs::types::LivestockType;
-/// 
+///
 /// let fcr = 10.0;
 /// let livestock_type = LivestockType::Cattle;
-/// 
+///
 /// let feed_efficiency = calculate_feed_efficiency(fcr, livestock_type).unwrap();
-/// 
+///
 /// // The feed efficiency is average for a FCR of 10.0 for cattle.
 /// assert_eq!(feed_efficiency.rating, FeedEfficiencyRating::Average);
-/// 
+///
 /// ```
-/// 
-pub fn calculate_feed_efficiency(fcr: f64, livestock_type: LivestockType) -> Result<FeedEfficiency> {
-    ensure!(fcr > 0.0, "Feed Conversion Ratio (FCR) must be greater than 0.");
-
-    // Feed efficiency values for different animals.
-    // 
-    // Source: <https://www.farmbrite.com/post/feed-conversion-ratio-calculator>
-    let (min_fcr, max_fcr) = match livestock_type {
-        LivestockType::Cattle => (8.0, 12.0),
-        LivestockType::Goat | LivestockType::Sheep => (4.5, 5.5),
-        LivestockType::Swine => (3.0, 3.9),
-        LivestockType::Chicken => (1.5, 2.0),
-        LivestockType::Rabbit => (3.5, 5.0),
-    };
+///
+pub fn calculate_feed_efficiency(
+    fcr: f64,
+    livestock_type: LivestockType,
+) -> Result<FeedEfficiency> {
+    rate_feed_efficiency(fcr, livestock_type, None, &FcrBenchmarks::builtin())
+}
 
-    let feed_efficiency = if fcr > max_fcr {
-        FeedEfficiency {
-            rating: FeedEfficiencyRating::Poor,
-            value: fcr,
-            avg_min_fcr: min_fcr,
-            avg_max_fcr: max_fcr
+/// Calculate Feed Efficiency for livestock in a feeding phase, against a table of benchmarks.
+///
+/// An animal's FCR rises as it grows, so a rating against the whole species can call a
+/// finishing animal poor that is average for its phase.
+///
+/// # Arguments
+/// - `fcr`: Feed Conversion Ratio (FCR) of the animal.
+/// - `livestock_type`: The type of livestock.
+/// - `phase`: The feeding phase of the animal, or `None` to rate it against the species.
+/// - `benchmarks`: The FCR benchmarks to rate against, e.g. [`FcrBenchmarks::builtin`].
+///
+/// # Returns
+/// The feed efficiency of the animal, or an error if there is no benchmark for the phase.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::feed::efficiency::{rate_feed_efficiency, FcrBenchmarks, FeedEfficiencyRating};
+/// use livestock_rs::types::{FeedingPhase, LivestockType};
+///
+/// let benchmarks = FcrBenchmarks::builtin();
+///
+/// // An FCR of 3.0 is average for swine overall, but poor for a grower pig.
+/// let overall = rate_feed_efficiency(3.0, LivestockType::Swine, None, &benchmarks).unwrap();
+/// assert_eq!(overall.rating, FeedEfficiencyRating::Average);
+/// let grower = rate_feed_efficiency(3.0, LivestockType::Swine, Some(FeedingPhase::Grower), &benchmarks).unwrap();
+/// assert_eq!(grower.rating, FeedEfficiencyRating::Poor);
+/// ```
+pub fn rate_feed_efficiency(
+    fcr: f64,
+    livestock_type: LivestockType,
+    phase: Option<FeedingPhase>,
+    benchmarks: &FcrBenchmarks,
+) -> Result<FeedEfficiency> {
+    ensure!(
+        fcr > 0.0,
+        "Feed Conversion Ratio (FCR) must be greater than 0."
+    );
+
+    let benchmark = benchmarks.get(livestock_type, phase).ok_or_else(|| {
+        let phases: Vec<String> = benchmarks.iter()
+            .filter(|benchmark| benchmark.livestock_type == livestock_type)
+            .filter_map(|benchmark| benchmark.phase)
+            .map(|phase| format!("{:?}", phase))
+            .collect();
+        match (phase, phases.is_empty()) {
+            (Some(phase), false) => anyhow!(
+                "There is no FCR benchmark for {:?} in the {:?} phase. Benchmarks cover the {} phases.",
+                livestock_type, phase, phases.join(", ")
+            ),
+            (Some(phase), true) => anyhow!("There is no FCR benchmark for {:?} in the {:?} phase.", livestock_type, phase),
+            (None, _) => anyhow!("There is no FCR benchmark for {:?}.", livestock_type),
         }
+    })?;
+    let (min_fcr, max_fcr) = (benchmark.avg_min_fcr, benchmark.avg_max_fcr);
+
+    let rating = if fcr > max_fcr {
+        FeedEfficiencyRating::Poor
     } else if fcr < min_fcr {
-        FeedEfficiency {
-            rating: FeedEfficiencyRating::Good,
-            value: fcr,
-            avg_min_fcr: min_fcr,
-            avg_max_fcr: max_fcr
-        }
+        FeedEfficiencyRating::Good
     } else {
-        FeedEfficiency {
-            rating: FeedEfficiencyRating::Average,
-            value: fcr,
-            avg_min_fcr: min_fcr,
-            avg_max_fcr: max_fcr
-        }
+        FeedEfficiencyRating::Average
     };
 
-    Ok(feed_efficiency)
+    Ok(FeedEfficiency {
+        rating,
+        value: fcr,
+        phase,
+        avg_min_fcr: min_fcr,
+        avg_max_fcr: max_fcr,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::calculators::feed::requirements::{calculate_requirements, RequirementInputs};
     use crate::types::LivestockType;
+    use crate::units::{Mass, MassRate};
 
     #[test]
     fn test_calculate_feed_efficiency() {
@@ -104,15 +374,266 @@ mod tests {
         ];
 
         for (fcr, livestock_type, expected) in feed_efficiency_test_cases.iter() {
-            let result = calculate_feed_efficiency(*fcr, livestock_type.clone());
+            let result = calculate_feed_efficiency(*fcr, *livestock_type);
             assert!(result.is_ok());
             assert_eq!(result.unwrap().rating, *expected);
         }
     }
 
+    #[test]
+    fn test_calculate_feed_efficiency_equines_and_camels() {
+        let benchmarks = FcrBenchmarks::builtin();
+        for livestock_type in LivestockType::all() {
+            assert_eq!(
+                benchmarks.get(*livestock_type, None).is_some(),
+                *livestock_type != LivestockType::Reindeer,
+                "{:?}",
+                livestock_type
+            );
+        }
+
+        let test_cases = [
+            (10.0, LivestockType::Horse, FeedEfficiencyRating::Average),
+            (20.0, LivestockType::Horse, FeedEfficiencyRating::Poor),
+            (5.0, LivestockType::Donkey, FeedEfficiencyRating::Good),
+            (7.0, LivestockType::Camel, FeedEfficiencyRating::Average),
+        ];
+        for (fcr, livestock_type, expected) in test_cases {
+            assert_eq!(
+                calculate_feed_efficiency(fcr, livestock_type)
+                    .unwrap()
+                    .rating,
+                expected
+            );
+        }
+    }
+
     #[test]
     fn test_calculate_feed_efficiency_zero_fcr() {
         let result = calculate_feed_efficiency(0.0, LivestockType::Cattle);
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_rate_feed_efficiency_by_phase() {
+        let benchmarks = FcrBenchmarks::builtin();
+        let test_cases = [
+            (
+                1.0,
+                LivestockType::Swine,
+                FeedingPhase::Starter,
+                FeedEfficiencyRating::Good,
+            ),
+            (
+                1.3,
+                LivestockType::Swine,
+                FeedingPhase::Starter,
+                FeedEfficiencyRating::Average,
+            ),
+            (
+                2.2,
+                LivestockType::Swine,
+                FeedingPhase::Grower,
+                FeedEfficiencyRating::Average,
+            ),
+            (
+                3.0,
+                LivestockType::Swine,
+                FeedingPhase::Grower,
+                FeedEfficiencyRating::Poor,
+            ),
+            (
+                3.0,
+                LivestockType::Swine,
+                FeedingPhase::Finisher,
+                FeedEfficiencyRating::Good,
+            ),
+            (
+                3.4,
+                LivestockType::Swine,
+                FeedingPhase::Finisher,
+                FeedEfficiencyRating::Average,
+            ),
+            (
+                7.5,
+                LivestockType::Cattle,
+                FeedingPhase::Backgrounding,
+                FeedEfficiencyRating::Average,
+            ),
+            (
+                7.5,
+                LivestockType::Cattle,
+                FeedingPhase::Finisher,
+                FeedEfficiencyRating::Poor,
+            ),
+            (
+                1.2,
+                LivestockType::Chicken,
+                FeedingPhase::Grower,
+                FeedEfficiencyRating::Average,
+            ),
+            (
+                1.2,
+                LivestockType::Chicken,
+                FeedingPhase::Finisher,
+                FeedEfficiencyRating::Good,
+            ),
+        ];
+
+        for (fcr, livestock_type, phase, expected) in test_cases {
+            let result =
+                rate_feed_efficiency(fcr, livestock_type, Some(phase), &benchmarks).unwrap();
+            assert_eq!(
+                result.rating, expected,
+                "{} for {:?} {:?}",
+                fcr, livestock_type, phase
+            );
+            assert_eq!(result.phase, Some(phase));
+        }
+
+        // Swine have no backgrounding phase.
+        let error = rate_feed_efficiency(
+            3.0,
+            LivestockType::Swine,
+            Some(FeedingPhase::Backgrounding),
+            &benchmarks,
+        )
+        .unwrap_err();
+        assert!(
+            error.to_string().contains("Starter, Grower, Finisher"),
+            "{}",
+            error
+        );
+        assert!(rate_feed_efficiency(
+            7.0,
+            LivestockType::Cattle,
+            Some(FeedingPhase::Starter),
+            &benchmarks
+        )
+        .is_err());
+        assert!(rate_feed_efficiency(
+            10.0,
+            LivestockType::Horse,
+            Some(FeedingPhase::Grower),
+            &benchmarks
+        )
+        .is_err());
+        assert!(
+            rate_feed_efficiency(10.0, LivestockType::Horse, None, &FcrBenchmarks::new()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_builtin_cattle_phases_follow_nasem() {
+        let benchmarks = FcrBenchmarks::builtin();
+        let feed_to_gain = |weight, adg| {
+            let inputs =
+                RequirementInputs::new(Mass::kilograms(weight), MassRate::kilograms_per_day(adg));
+            calculate_requirements(LivestockType::Cattle, &inputs)
+                .unwrap()
+                .dmi
+                .as_kilograms_per_day()
+                / adg
+        };
+        let cases = [
+            (FeedingPhase::Backgrounding, 250.0, 350.0, 0.9),
+            (FeedingPhase::Finisher, 400.0, 550.0, 1.6),
+        ];
+
+        for (phase, lightest, heaviest, adg) in cases {
+            let benchmark = benchmarks.get(LivestockType::Cattle, Some(phase)).unwrap();
+            assert!((benchmark.avg_min_fcr - feed_to_gain(lightest, adg)).abs() < 0.01);
+            assert!((benchmark.avg_max_fcr - feed_to_gain(heaviest, adg)).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_builtin_phases_overlap_overall_range() {
+        // A whole-life FCR is an average of the phase FCRs, so the overall range must reach
+        // into the span of the phase ranges.
+        let benchmarks = FcrBenchmarks::builtin();
+        for overall in benchmarks
+            .iter()
+            .filter(|benchmark| benchmark.phase.is_none())
+        {
+            let phases: Vec<&FcrBenchmark> = benchmarks
+                .iter()
+                .filter(|benchmark| {
+                    benchmark.livestock_type == overall.livestock_type && benchmark.phase.is_some()
+                })
+                .collect();
+            if phases.is_empty() {
+                continue;
+            }
+            let lowest = phases
+                .iter()
+                .map(|phase| phase.avg_min_fcr)
+                .fold(f64::INFINITY, f64::min);
+            let highest = phases
+                .iter()
+                .map(|phase| phase.avg_max_fcr)
+                .fold(0.0, f64::max);
+            assert!(
+                overall.avg_min_fcr <= highest && overall.avg_max_fcr >= lowest,
+                "{:?}: {}-{} outside {}-{}",
+                overall.livestock_type,
+                overall.avg_min_fcr,
+                overall.avg_max_fcr,
+                lowest,
+                highest
+            );
+        }
+    }
+
+    #[test]
+    fn test_fcr_benchmarks_from_toml() {
+        let mut benchmarks = FcrBenchmarks::builtin();
+        let toml = "
+            # Our own closeout averages.
+            [[benchmark]]
+            livestock_type = \"swine\"
+            phase = \"finisher\"
+            min_fcr = 2.6
+            max_fcr = 3.1
+
+            [[benchmark]]
+            livestock_type = \"Horse\"
+            min_fcr = 7
+            max_fcr = 10
+        ";
+        assert_eq!(benchmarks.extend_from_toml(toml).unwrap(), 2);
+
+        let finisher = benchmarks
+            .get(LivestockType::Swine, Some(FeedingPhase::Finisher))
+            .unwrap();
+        assert_eq!((finisher.avg_min_fcr, finisher.avg_max_fcr), (2.6, 3.1));
+        let horse = benchmarks.get(LivestockType::Horse, None).unwrap();
+        assert_eq!((horse.avg_min_fcr, horse.avg_max_fcr), (7.0, 10.0));
+        // Other benchmarks are untouched.
+        assert!(
+            (benchmarks
+                .get(LivestockType::Swine, Some(FeedingPhase::Grower))
+                .unwrap()
+                .avg_max_fcr
+                - 2.65)
+                .abs()
+                < 0.01
+        );
+
+        let invalid = [
+            "[[benchmark]]\nlivestock_type = \"yak\"\nmin_fcr = 1\nmax_fcr = 2",
+            "[[benchmark]]\nlivestock_type = \"cattle\"\nphase = \"weaner\"\nmin_fcr = 1\nmax_fcr = 2",
+            "[[benchmark]]\nlivestock_type = \"cattle\"\nmin_fcr = 3\nmax_fcr = 2",
+            "[[benchmark]]\nlivestock_type = \"cattle\"\nmin_fcr = 3",
+            "[[benchmark]]\nlivestock_type = \"cattle\"\nmin_fcr = 1\nmax_fcr = 2\nbreed = \"Angus\"",
+            "livestock_type = \"cattle\"",
+        ];
+        for toml in invalid {
+            assert!(
+                FcrBenchmarks::new().extend_from_toml(toml).is_err(),
+                "{}",
+                toml
+            );
+        }
+    }
+}
@@ -1,31 +1,69 @@
+use crate::calculators::feed::library::Feed;
+use crate::units::Mass;
 use anyhow::{ensure, Result};
 
 /// Calculate Feed Conversion Ratio (FCR)
-/// 
+///
 /// # Arguments
-/// - `feed_intake`: Amount of feed consumed by the animal (in lb or kg).
-/// - `weight_gain`: Weight gain of the animal (in lb or kg).
-/// 
+/// - `feed_intake`: Amount of feed consumed by the animal.
+/// - `weight_gain`: Weight gain of the animal.
+///
 /// # Returns
-/// The feed conversion ratio (FCR) as a ratio of feed intake to weight gain.
-/// 
+/// The feed conversion ratio (FCR) as a unitless ratio of feed intake to weight gain.
+///
 /// # Example
 /// ```
 /// use livestock_rs::calculators::feed::fcr::calculate_fcr;
-/// let fcr = calculate_fcr(100.0, 300.0).unwrap();
-/// assert_eq!(fcr,  0.3333333333333333); // 0.33 <unit> of feed per kg of weight gain where <unit> is lb or kg.
+/// use livestock_rs::units::Mass;
+///
+/// let fcr = calculate_fcr(Mass::kilograms(100.0), Mass::kilograms(300.0)).unwrap();
+/// assert_eq!(fcr,  0.3333333333333333); // 0.33 kg of feed per kg of weight gain, or lb per lb.
 /// ```
-/// 
+///
 /// # Notes
 /// - A lower FCR indicates better feed efficiency.
 /// - FCR is calculated as `feed_intake / weight_gain`.
-pub fn calculate_fcr(feed_intake: f64, weight_gain: f64) -> Result<f64> {
-    ensure!(feed_intake > 0.0, "Feed intake must be greater than 0.");
-    ensure!(weight_gain > 0.0, "Weight gain must be greater than 0.");
+pub fn calculate_fcr(feed_intake: Mass, weight_gain: Mass) -> Result<f64> {
+    ensure!(
+        feed_intake.as_kilograms() > 0.0,
+        "Feed intake must be greater than 0."
+    );
+    ensure!(
+        weight_gain.as_kilograms() > 0.0,
+        "Weight gain must be greater than 0."
+    );
 
     Ok(feed_intake / weight_gain)
 }
 
+/// Calculate Feed Conversion Ratio (FCR) on a dry matter basis
+///
+/// Feed is weighed as fed, but its moisture varies, from under 10% for grain to over 60% for
+/// silage. A dry matter FCR can be compared across diets.
+///
+/// # Arguments
+/// - `feed_intake`: Amount of feed consumed by the animal, as fed.
+/// - `feed`: The feed, for its dry matter percentage.
+/// - `weight_gain`: Weight gain of the animal.
+///
+/// # Returns
+/// The feed conversion ratio (FCR) as a unitless ratio of dry matter intake to weight gain.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::feed::fcr::calculate_dry_matter_fcr;
+/// use livestock_rs::calculators::feed::library::FeedLibrary;
+/// use livestock_rs::units::Mass;
+///
+/// let library = FeedLibrary::builtin();
+/// let silage = library.get("corn silage").unwrap();
+/// let fcr = calculate_dry_matter_fcr(Mass::kilograms(1000.0), silage, Mass::kilograms(50.0)).unwrap();
+/// assert_eq!(fcr, 7.0); // 20 kg as fed, but 7 kg of dry matter, per kg of gain.
+/// ```
+pub fn calculate_dry_matter_fcr(feed_intake: Mass, feed: &Feed, weight_gain: Mass) -> Result<f64> {
+    calculate_fcr(feed.to_dry_matter(feed_intake), weight_gain)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,21 +78,41 @@ mod tests {
         ];
 
         for (feed_intake, weight_gain, expected) in fcr_test_cases.iter() {
-            let result = calculate_fcr(*feed_intake, *weight_gain);
+            let result =
+                calculate_fcr(Mass::kilograms(*feed_intake), Mass::kilograms(*weight_gain));
             assert!(result.is_ok());
             assert_eq!(result.unwrap(), *expected);
         }
     }
 
+    #[test]
+    fn test_calculate_fcr_mixed_units() {
+        let fcr = calculate_fcr(Mass::pounds(1000.0), Mass::kilograms(45.359237)).unwrap();
+        assert!((fcr - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_calculate_dry_matter_fcr() {
+        let library = crate::calculators::feed::library::FeedLibrary::builtin();
+        let corn = library.get("corn grain").unwrap();
+
+        let fcr =
+            calculate_dry_matter_fcr(Mass::kilograms(600.0), corn, Mass::kilograms(100.0)).unwrap();
+        assert!((fcr - 5.28).abs() < 1e-9);
+        assert!(
+            calculate_dry_matter_fcr(Mass::kilograms(600.0), corn, Mass::kilograms(0.0)).is_err()
+        );
+    }
+
     #[test]
     fn test_calculate_fcr_zero_feed_intake() {
-        let result = calculate_fcr(0.0, 150.0);
+        let result = calculate_fcr(Mass::kilograms(0.0), Mass::kilograms(150.0));
         assert!(result.is_err());
     }
 
     #[test]
     fn test_calculate_fcr_zero_weight_gain() {
-        let result = calculate_fcr(100.0, 0.0);
+        let result = calculate_fcr(Mass::kilograms(100.0), Mass::kilograms(0.0));
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+}
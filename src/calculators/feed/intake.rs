@@ -0,0 +1,524 @@
+use crate::calculators::feed::requirements::{
+    goat_daily_requirements, sheep_daily_requirements, GOAT_DIET_ME,
+};
+use crate::types::{LivestockType, ProductionStage};
+use crate::units::{Mass, MassRate};
+use anyhow::{anyhow, ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// A published equation for predicting dry matter intake (DMI).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum IntakeEquation {
+    /// Growing and finishing cattle, from NASEM (2016):
+    /// `DMI = SBW^0.75 * (0.2435 * NEm - 0.0466 * NEm^2 - 0.1128) / NEm`.
+    NasemGrowingCattle,
+    /// Mature beef cows, from NASEM (2016):
+    /// `DMI = SBW^0.75 * (0.04997 * NEm^2 + 0.04631) / NEm + 0.2 * milk_yield` for pregnant cows,
+    /// with `0.03840` in place of `0.04631` for cows that are not pregnant.
+    NasemBeefCow,
+    /// Lactating dairy cows, from NRC (2001):
+    /// `DMI = (0.372 * FCM + 0.0968 * BW^0.75) * (1 - e^(-0.192 * (WOL + 3.67)))`.
+    NrcLactatingDairyCow,
+    /// Growing and finishing pigs, from NRC (1998):
+    /// `DMI = 13.162 * (1 - e^(-0.0176 * BW)) / DE`.
+    ///
+    /// The equation predicts DE intake, in Mcal/day, so dividing by the DE of the diet's dry
+    /// matter gives DMI. NRC (1998) tabulates diets as fed, at about 90% DM; dividing by an
+    /// as-fed DE such as 3.4 Mcal/kg gives the as-fed intake instead.
+    NrcGrowingPig,
+    /// Sheep, from the dry matter of the NRC (1985) Table 1 requirements for ewes at
+    /// maintenance, in late gestation and in early lactation, and for finishing lambs,
+    /// interpolated between the tabled weights.
+    NrcSheep,
+    /// Goats, from NRC (1981): `DMI = ME / diet ME`, the dry matter that supplies the ME
+    /// requirement. Maintenance needs `ME = 0.10138 * BW^0.75` Mcal/day, plus 0.36 Mcal for
+    /// each 50 g/day of gain, 1.42 Mcal in late pregnancy and 1.20 Mcal for each kg of milk.
+    NrcGoat,
+    /// A fixed percentage of body weight for the species and stage of production, from the NRC
+    /// (2007) guidelines for horses.
+    BodyWeightPercent,
+}
+
+/// What is known about an animal and its diet, used to predict its intake.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntakeInputs {
+    /// Full (unshrunk) body weight.
+    pub body_weight: Mass,
+    pub stage: ProductionStage,
+    /// Energy density of the diet in Mcal/kg of dry matter: net energy for maintenance (NEm)
+    /// for cattle, digestible energy (DE) for swine, or metabolizable energy (ME) for goats,
+    /// which is 2.0 Mcal/kg if not given. Not used for other species.
+    ///
+    /// Both must be on a dry matter basis; divide an as-fed value by the diet's dry matter
+    /// proportion first.
+    pub energy_density: Option<f64>,
+    /// Daily milk yield of lactating cattle and goats, 4% fat-corrected for dairy cows and goats.
+    pub milk_yield: Option<MassRate>,
+    /// Average daily gain, for growing goats.
+    pub adg: Option<MassRate>,
+    /// Weeks since calving, for lactating dairy cows. Without it, lactating cattle are treated
+    /// as beef cows.
+    pub week_of_lactation: Option<f64>,
+    /// Whether the animal is pregnant, which raises the intake of beef cows. Set by
+    /// [`IntakeInputs::new`] for the gestation stage; set it for a lactating or maintenance cow
+    /// that has been rebred.
+    pub pregnant: bool,
+}
+
+impl IntakeInputs {
+    /// Inputs with only the body weight and stage of production known.
+    pub fn new(body_weight: Mass, stage: ProductionStage) -> Self {
+        IntakeInputs {
+            body_weight,
+            stage,
+            energy_density: None,
+            milk_yield: None,
+            adg: None,
+            week_of_lactation: None,
+            pregnant: stage == ProductionStage::Gestation,
+        }
+    }
+}
+
+/// A predicted daily dry matter intake.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IntakePrediction {
+    /// The equation used to predict intake.
+    pub equation: IntakeEquation,
+    pub dmi: MassRate,
+    /// The predicted intake as a percentage of body weight.
+    pub percent_of_body_weight: f64,
+}
+
+/// Predict the daily dry matter intake (DMI) of an animal, for planning rations before actual
+/// intake is known.
+///
+/// The equation is chosen by the type of livestock and stage of production:
+///
+/// - Cattle: NASEM (2016) for growing cattle and beef cows, or NRC (2001) for lactating dairy
+///   cows when the week of lactation is given. These require the diet's NEm.
+/// - Swine: NRC (1998) for growing pigs, which requires the DE of the diet's dry matter.
+/// - Sheep: the dry matter of the NRC (1985) requirement tables, for 50 to 90 kg ewes and 30 to
+///   50 kg finishing lambs.
+/// - Goats: NRC (1981), the dry matter that supplies the ME requirement, which requires the ADG
+///   of growing goats and the milk yield of lactating ones.
+/// - Horses and donkeys: a percentage of body weight for the stage of production.
+///
+/// # Arguments
+/// - `livestock_type`: The type of livestock.
+/// - `inputs`: The animal's body weight and stage of production, and what is known about its
+///   diet and milk yield.
+///
+/// # Returns
+/// The predicted intake, or an error if there is no equation for the type of livestock and
+/// stage of production or an input it needs is missing.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::feed::intake::{predict_dmi, IntakeEquation, IntakeInputs};
+/// use livestock_rs::types::{LivestockType, ProductionStage};
+/// use livestock_rs::units::Mass;
+///
+/// // A 350 kg steer on a diet with 2.0 Mcal/kg NEm.
+/// let inputs = IntakeInputs {
+///     energy_density: Some(2.0),
+///     ..IntakeInputs::new(Mass::kilograms(350.0), ProductionStage::Growing)
+/// };
+/// let prediction = predict_dmi(LivestockType::Cattle, &inputs).unwrap();
+/// assert_eq!(prediction.equation, IntakeEquation::NasemGrowingCattle);
+/// assert_eq!(format!("{:.2}", prediction.dmi.as_kilograms_per_day()), "7.37");
+///
+/// // A 70 kg ewe nursing lambs.
+/// let inputs = IntakeInputs::new(Mass::kilograms(70.0), ProductionStage::Lactation);
+/// let prediction = predict_dmi(LivestockType::Sheep, &inputs).unwrap();
+/// assert_eq!(prediction.equation, IntakeEquation::NrcSheep);
+/// assert_eq!(format!("{:.1}", prediction.dmi.as_kilograms_per_day()), "2.5");
+/// ```
+pub fn predict_dmi(
+    livestock_type: LivestockType,
+    inputs: &IntakeInputs,
+) -> Result<IntakePrediction> {
+    let body_weight = inputs.body_weight.as_kilograms();
+    ensure!(body_weight > 0.0, "Body weight must be greater than zero.");
+    if let Some(energy_density) = inputs.energy_density {
+        ensure!(
+            energy_density > 0.0,
+            "Diet energy density must be greater than zero."
+        );
+    }
+    if let Some(milk_yield) = inputs.milk_yield {
+        ensure!(
+            milk_yield.as_kilograms_per_day() >= 0.0,
+            "Milk yield must not be negative."
+        );
+    }
+    if let Some(adg) = inputs.adg {
+        ensure!(
+            adg.as_kilograms_per_day() >= 0.0,
+            "ADG must not be negative."
+        );
+    }
+
+    let (equation, dmi) = match (livestock_type, inputs.stage, inputs.week_of_lactation) {
+        (LivestockType::Cattle, ProductionStage::Growing, _) => {
+            let nem = require_energy_density(inputs, "NEm")?;
+            ensure!(
+                nem > 1.0,
+                "NEm of a growing cattle diet must be greater than 1.0 Mcal/kg."
+            );
+            let dmi = shrunk_metabolic_weight(body_weight)
+                * (0.2435 * nem - 0.0466 * nem.powi(2) - 0.1128)
+                / nem;
+            (IntakeEquation::NasemGrowingCattle, dmi)
+        }
+        (LivestockType::Cattle, ProductionStage::Lactation, Some(week)) => {
+            ensure!(week >= 0.0, "Week of lactation must not be negative.");
+            let milk_yield = inputs
+                .milk_yield
+                .ok_or_else(|| anyhow!("Lactating dairy cows require the milk yield."))?;
+            let dmi = (0.372 * milk_yield.as_kilograms_per_day() + 0.0968 * body_weight.powf(0.75))
+                * (1.0 - (-0.192 * (week + 3.67)).exp());
+            (IntakeEquation::NrcLactatingDairyCow, dmi)
+        }
+        (
+            LivestockType::Cattle,
+            ProductionStage::Maintenance | ProductionStage::Gestation | ProductionStage::Lactation,
+            _,
+        ) => {
+            let nem = require_energy_density(inputs, "NEm")?;
+            let milk_yield = match inputs.stage {
+                ProductionStage::Lactation => inputs
+                    .milk_yield
+                    .ok_or_else(|| anyhow!("Lactating beef cows require the milk yield."))?
+                    .as_kilograms_per_day(),
+                _ => 0.0,
+            };
+            let pregnancy = match inputs.pregnant {
+                true => 0.04631,
+                false => 0.03840,
+            };
+            let dmi = shrunk_metabolic_weight(body_weight) * (0.04997 * nem.powi(2) + pregnancy)
+                / nem
+                + 0.2 * milk_yield;
+            (IntakeEquation::NasemBeefCow, dmi)
+        }
+        (LivestockType::Swine, ProductionStage::Growing, _) => {
+            let de = require_energy_density(inputs, "DE")?;
+            let dmi = 13.162 * (1.0 - (-0.0176 * body_weight).exp()) / de;
+            (IntakeEquation::NrcGrowingPig, dmi)
+        }
+        (LivestockType::Sheep, stage, _) if stage != ProductionStage::Work => {
+            // Ewes are fed for late gestation, as the gestation stage is.
+            let [dmi, ..] = sheep_daily_requirements(body_weight, stage, true)?;
+            (IntakeEquation::NrcSheep, dmi)
+        }
+        (LivestockType::Goat, stage, _) if stage != ProductionStage::Work => {
+            let adg = match stage {
+                ProductionStage::Growing => inputs
+                    .adg
+                    .ok_or_else(|| anyhow!("Growing goats require the ADG."))?
+                    .as_kilograms_per_day(),
+                _ => 0.0,
+            };
+            let milk_yield = match stage {
+                ProductionStage::Lactation => inputs
+                    .milk_yield
+                    .ok_or_else(|| anyhow!("Lactating goats require the milk yield."))?
+                    .as_kilograms_per_day(),
+                _ => 0.0,
+            };
+            let [energy, ..] = goat_daily_requirements(
+                body_weight,
+                adg,
+                stage == ProductionStage::Gestation,
+                milk_yield,
+            )?;
+            let me = inputs.energy_density.unwrap_or(GOAT_DIET_ME);
+            (IntakeEquation::NrcGoat, energy / me)
+        }
+        _ => {
+            let percent = body_weight_percent(livestock_type, inputs.stage).ok_or_else(|| {
+                anyhow!(
+                    "There is no intake equation for {:?} at the {:?} stage.",
+                    livestock_type,
+                    inputs.stage
+                )
+            })?;
+            (
+                IntakeEquation::BodyWeightPercent,
+                body_weight * percent / 100.0,
+            )
+        }
+    };
+
+    Ok(IntakePrediction {
+        equation,
+        dmi: MassRate::kilograms_per_day(dmi),
+        percent_of_body_weight: dmi / body_weight * 100.0,
+    })
+}
+
+/// The typical daily dry matter intake, as a percentage of body weight, of horses and donkeys.
+///
+/// # Returns
+/// The percentage, or `None` if there is no guideline for the species and stage of production.
+pub fn body_weight_percent(livestock_type: LivestockType, stage: ProductionStage) -> Option<f64> {
+    let percent = match (livestock_type, stage) {
+        (LivestockType::Horse, ProductionStage::Maintenance) => 2.0,
+        (LivestockType::Horse, ProductionStage::Growing) => 2.5,
+        (LivestockType::Horse, ProductionStage::Gestation) => 1.75,
+        (LivestockType::Horse, ProductionStage::Lactation) => 2.5,
+        (LivestockType::Horse, ProductionStage::Work) => 2.25,
+        // Donkeys evolved on sparse forage and need less than horses of the same weight.
+        (LivestockType::Donkey, ProductionStage::Maintenance) => 1.5,
+        (LivestockType::Donkey, ProductionStage::Growing) => 2.0,
+        (LivestockType::Donkey, ProductionStage::Gestation) => 1.5,
+        (LivestockType::Donkey, ProductionStage::Lactation) => 2.0,
+        (LivestockType::Donkey, ProductionStage::Work) => 1.8,
+        _ => return None,
+    };
+
+    Some(percent)
+}
+
+fn require_energy_density(inputs: &IntakeInputs, name: &str) -> Result<f64> {
+    inputs.energy_density.with_context(|| {
+        format!(
+            "{:?} intake requires the diet's {} in Mcal/kg.",
+            inputs.stage, name
+        )
+    })
+}
+
+/// The shrunk metabolic body weight, `(0.96 * weight)^0.75` in kg.
+fn shrunk_metabolic_weight(weight: f64) -> f64 {
+    (0.96 * weight).powf(0.75)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(weight: f64, stage: ProductionStage) -> IntakeInputs {
+        IntakeInputs::new(Mass::kilograms(weight), stage)
+    }
+
+    fn predict(livestock_type: LivestockType, inputs: IntakeInputs) -> IntakePrediction {
+        predict_dmi(livestock_type, &inputs).unwrap()
+    }
+
+    #[test]
+    fn test_predict_dmi_cattle() {
+        let steer = predict(
+            LivestockType::Cattle,
+            IntakeInputs {
+                energy_density: Some(2.0),
+                ..inputs(350.0, ProductionStage::Growing)
+            },
+        );
+        assert_eq!(steer.equation, IntakeEquation::NasemGrowingCattle);
+        assert!((steer.dmi.as_kilograms_per_day() - 7.3692).abs() < 1e-3);
+
+        let cow = IntakeInputs {
+            energy_density: Some(1.2),
+            ..inputs(550.0, ProductionStage::Gestation)
+        };
+        let dry = predict(LivestockType::Cattle, cow);
+        assert_eq!(dry.equation, IntakeEquation::NasemBeefCow);
+        assert!((dry.dmi.as_kilograms_per_day() - 10.856).abs() < 1e-2);
+
+        // Milk adds 0.2 kg of intake per kg of milk.
+        let nursing = predict(
+            LivestockType::Cattle,
+            IntakeInputs {
+                stage: ProductionStage::Lactation,
+                milk_yield: Some(MassRate::kilograms_per_day(8.0)),
+                ..cow
+            },
+        );
+        assert!(
+            (nursing.dmi.as_kilograms_per_day() - dry.dmi.as_kilograms_per_day() - 1.6).abs()
+                < 1e-9
+        );
+
+        // Open cows eat less than pregnant ones.
+        let open = predict(
+            LivestockType::Cattle,
+            IntakeInputs {
+                energy_density: Some(1.2),
+                ..inputs(550.0, ProductionStage::Maintenance)
+            },
+        );
+        assert_eq!(open.equation, IntakeEquation::NasemBeefCow);
+        assert!((open.dmi.as_kilograms_per_day() - 10.130).abs() < 1e-2);
+        let open_nursing = predict(
+            LivestockType::Cattle,
+            IntakeInputs {
+                stage: ProductionStage::Lactation,
+                milk_yield: Some(MassRate::kilograms_per_day(8.0)),
+                pregnant: false,
+                ..cow
+            },
+        );
+        assert!(
+            (open_nursing.dmi.as_kilograms_per_day() - open.dmi.as_kilograms_per_day() - 1.6).abs()
+                < 1e-9
+        );
+
+        let dairy = predict(
+            LivestockType::Cattle,
+            IntakeInputs {
+                milk_yield: Some(MassRate::kilograms_per_day(35.0)),
+                week_of_lactation: Some(20.0),
+                ..inputs(650.0, ProductionStage::Lactation)
+            },
+        );
+        assert_eq!(dairy.equation, IntakeEquation::NrcLactatingDairyCow);
+        assert!((dairy.dmi.as_kilograms_per_day() - 25.21).abs() < 1e-2);
+        assert!((dairy.percent_of_body_weight - 3.879).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_predict_dmi_intake_falls_early_in_lactation() {
+        let cow = |week| IntakeInputs {
+            milk_yield: Some(MassRate::kilograms_per_day(35.0)),
+            week_of_lactation: Some(week),
+            ..inputs(650.0, ProductionStage::Lactation)
+        };
+        let early = predict(LivestockType::Cattle, cow(1.0));
+        let peak = predict(LivestockType::Cattle, cow(10.0));
+        assert!(early.dmi < peak.dmi);
+    }
+
+    #[test]
+    fn test_predict_dmi_swine() {
+        let pig = predict(
+            LivestockType::Swine,
+            IntakeInputs {
+                // 3.4 Mcal/kg as fed, at 90% DM.
+                energy_density: Some(3.4 / 0.9),
+                ..inputs(60.0, ProductionStage::Growing)
+            },
+        );
+        assert_eq!(pig.equation, IntakeEquation::NrcGrowingPig);
+        assert!((pig.dmi.as_kilograms_per_day() - 2.271).abs() < 1e-2);
+
+        // A denser diet is eaten in smaller amounts.
+        let dense = predict(
+            LivestockType::Swine,
+            IntakeInputs {
+                energy_density: Some(4.0),
+                ..inputs(60.0, ProductionStage::Growing)
+            },
+        );
+        assert!(dense.dmi < pig.dmi);
+    }
+
+    #[test]
+    fn test_predict_dmi_sheep() {
+        let cases = [
+            (ProductionStage::Maintenance, 70.0, 1.2),
+            (ProductionStage::Gestation, 65.0, 1.85),
+            (ProductionStage::Lactation, 70.0, 2.5),
+            (ProductionStage::Growing, 35.0, 1.45),
+        ];
+
+        for (stage, weight, expected) in cases.iter() {
+            let prediction = predict(LivestockType::Sheep, inputs(*weight, *stage));
+            assert_eq!(prediction.equation, IntakeEquation::NrcSheep);
+            assert!((prediction.dmi.as_kilograms_per_day() - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_predict_dmi_goat() {
+        let maintenance = 0.10138 * 50f64.powf(0.75);
+        let doe = predict(
+            LivestockType::Goat,
+            inputs(50.0, ProductionStage::Maintenance),
+        );
+        assert_eq!(doe.equation, IntakeEquation::NrcGoat);
+        assert!((doe.dmi.as_kilograms_per_day() - maintenance / 2.0).abs() < 1e-9);
+
+        // 2 kg of milk on a 2.4 Mcal/kg diet.
+        let milking = predict(
+            LivestockType::Goat,
+            IntakeInputs {
+                energy_density: Some(2.4),
+                milk_yield: Some(MassRate::kilograms_per_day(2.0)),
+                ..inputs(50.0, ProductionStage::Lactation)
+            },
+        );
+        assert!((milking.dmi.as_kilograms_per_day() - (maintenance + 2.4) / 2.4).abs() < 1e-9);
+
+        let kid = predict(
+            LivestockType::Goat,
+            IntakeInputs {
+                adg: Some(MassRate::kilograms_per_day(0.1)),
+                ..inputs(20.0, ProductionStage::Growing)
+            },
+        );
+        let energy = 0.10138 * 20f64.powf(0.75) + 0.72;
+        assert!((kid.dmi.as_kilograms_per_day() - energy / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_predict_dmi_body_weight_percent() {
+        let cases = [
+            (
+                LivestockType::Horse,
+                ProductionStage::Maintenance,
+                500.0,
+                10.0,
+            ),
+            (LivestockType::Donkey, ProductionStage::Work, 200.0, 3.6),
+        ];
+
+        for (livestock_type, stage, weight, expected) in cases.iter() {
+            let prediction = predict(*livestock_type, inputs(*weight, *stage));
+            assert_eq!(prediction.equation, IntakeEquation::BodyWeightPercent);
+            assert!((prediction.dmi.as_kilograms_per_day() - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_predict_dmi_invalid() {
+        let growing = inputs(350.0, ProductionStage::Growing);
+
+        // Energy-based equations need the diet's energy density.
+        assert!(predict_dmi(LivestockType::Cattle, &growing).is_err());
+        assert!(predict_dmi(LivestockType::Swine, &growing).is_err());
+        assert!(predict_dmi(
+            LivestockType::Cattle,
+            &IntakeInputs {
+                energy_density: Some(0.8),
+                ..growing
+            }
+        )
+        .is_err());
+
+        // Lactating cattle need the milk yield.
+        let lactating = IntakeInputs {
+            energy_density: Some(1.2),
+            ..inputs(550.0, ProductionStage::Lactation)
+        };
+        assert!(predict_dmi(LivestockType::Cattle, &lactating).is_err());
+
+        assert!(predict_dmi(LivestockType::Cattle, &inputs(550.0, ProductionStage::Work)).is_err());
+        assert!(predict_dmi(LivestockType::Chicken, &growing).is_err());
+        assert!(predict_dmi(LivestockType::Sheep, &inputs(0.0, ProductionStage::Growing)).is_err());
+
+        // Sheep outside the tables, and goats without the gain or milk their energy needs.
+        assert!(predict_dmi(
+            LivestockType::Sheep,
+            &inputs(120.0, ProductionStage::Maintenance)
+        )
+        .is_err());
+        assert!(predict_dmi(LivestockType::Goat, &inputs(30.0, ProductionStage::Growing)).is_err());
+        assert!(predict_dmi(
+            LivestockType::Goat,
+            &inputs(50.0, ProductionStage::Lactation)
+        )
+        .is_err());
+    }
+}
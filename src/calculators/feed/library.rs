@@ -0,0 +1,455 @@
+use crate::csv::read_rows;
+use anyhow::{ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::ops::Mul;
+
+/// The built-in feed table, in the same CSV layout accepted by [`FeedLibrary::extend_from_csv`].
+const BUILTIN_FEEDS: &str = include_str!("feeds.csv");
+
+/// Digestible energy, in Mcal/kg of dry matter, per % of TDN (NRC, 1996).
+pub(crate) const DE_PER_TDN: f64 = 0.04409;
+
+/// Metabolizable energy as a proportion of digestible energy (NRC, 1996).
+pub(crate) const ME_PER_DE: f64 = 0.82;
+
+/// The fields of a feed, in the column order of feed CSV files.
+const FIELDS: [&str; 10] = [
+    "name",
+    "dry_matter",
+    "crude_protein",
+    "tdn",
+    "nem",
+    "neg",
+    "adf",
+    "ndf",
+    "calcium",
+    "phosphorus",
+];
+
+/// The nutrient profile of a feed ingredient.
+///
+/// Dry matter is a percentage of the feed as fed. Every other nutrient is on a dry matter basis,
+/// so feeds of different moisture can be compared and mixed.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Feed {
+    pub name: String,
+    /// Dry matter, % of the feed as fed.
+    pub dry_matter: f64,
+    /// Crude protein (CP), % of dry matter.
+    pub crude_protein: f64,
+    /// Total digestible nutrients (TDN), % of dry matter.
+    pub tdn: f64,
+    /// Net energy for maintenance (NEm), Mcal/kg of dry matter.
+    pub nem: f64,
+    /// Net energy for gain (NEg), Mcal/kg of dry matter.
+    pub neg: f64,
+    /// Acid detergent fiber (ADF), % of dry matter.
+    pub adf: f64,
+    /// Neutral detergent fiber (NDF), % of dry matter.
+    pub ndf: f64,
+    /// Calcium, % of dry matter.
+    pub calcium: f64,
+    /// Phosphorus, % of dry matter.
+    pub phosphorus: f64,
+}
+
+impl Feed {
+    /// Convert an amount of this feed as fed to its dry matter, e.g. a mass or a daily intake.
+    ///
+    /// # Example
+    /// ```
+    /// use livestock_rs::calculators::feed::library::FeedLibrary;
+    /// use livestock_rs::units::Mass;
+    ///
+    /// let library = FeedLibrary::builtin();
+    /// let silage = library.get("corn silage").unwrap();
+    /// assert_eq!(format!("{:.0}", silage.to_dry_matter(Mass::kilograms(1000.0)).as_kilograms()), "350");
+    /// ```
+    pub fn to_dry_matter<T: Mul<f64, Output = T>>(&self, as_fed: T) -> T {
+        as_fed * (self.dry_matter / 100.0)
+    }
+
+    /// Convert an amount of this feed's dry matter to the amount as fed, e.g. a mass or a daily
+    /// intake.
+    ///
+    /// # Example
+    /// ```
+    /// use livestock_rs::calculators::feed::library::FeedLibrary;
+    /// use livestock_rs::units::MassRate;
+    ///
+    /// let library = FeedLibrary::builtin();
+    /// let silage = library.get("corn silage").unwrap();
+    /// let as_fed = silage.to_as_fed(MassRate::kilograms_per_day(7.0));
+    /// assert_eq!(format!("{:.0}", as_fed.as_kilograms_per_day()), "20");
+    /// ```
+    pub fn to_as_fed<T: Mul<f64, Output = T>>(&self, dry_matter: T) -> T {
+        dry_matter * (100.0 / self.dry_matter)
+    }
+
+    /// Digestible energy (DE) of the feed in Mcal/kg of dry matter, estimated from its TDN as
+    /// `DE = 0.04409 * TDN` (NRC, 1996).
+    ///
+    /// # Example
+    /// ```
+    /// use livestock_rs::calculators::feed::library::FeedLibrary;
+    ///
+    /// let library = FeedLibrary::builtin();
+    /// let corn = library.get("corn grain").unwrap();
+    /// assert_eq!(format!("{:.2}", corn.digestible_energy()), "3.88");
+    /// ```
+    pub fn digestible_energy(&self) -> f64 {
+        DE_PER_TDN * self.tdn
+    }
+
+    /// Metabolizable energy (ME) of the feed in Mcal/kg of dry matter, estimated as
+    /// `ME = 0.82 * DE` (NRC, 1996).
+    pub fn metabolizable_energy(&self) -> f64 {
+        ME_PER_DE * self.digestible_energy()
+    }
+
+    /// Build a feed from the fields of a CSV row, in the order of `FIELDS`.
+    fn from_csv_fields(fields: &[&str]) -> Result<Self> {
+        ensure!(
+            fields.len() == FIELDS.len(),
+            "Feeds must have {} fields, found {}.",
+            FIELDS.len(),
+            fields.len()
+        );
+        let value = |index: usize| -> Result<f64> {
+            fields[index].parse().with_context(|| {
+                format!(
+                    "Invalid number for \"{}\": {}.",
+                    FIELDS[index], fields[index]
+                )
+            })
+        };
+
+        let feed = Feed {
+            name: fields[0].to_string(),
+            dry_matter: value(1)?,
+            crude_protein: value(2)?,
+            tdn: value(3)?,
+            nem: value(4)?,
+            neg: value(5)?,
+            adf: value(6)?,
+            ndf: value(7)?,
+            calcium: value(8)?,
+            phosphorus: value(9)?,
+        };
+        feed.validate()?;
+        Ok(feed)
+    }
+
+    /// Check the feed has a name and its values are in range.
+    fn validate(&self) -> Result<()> {
+        ensure!(!self.name.trim().is_empty(), "Feeds must have a name.");
+        let values = [
+            self.dry_matter,
+            self.crude_protein,
+            self.tdn,
+            self.nem,
+            self.neg,
+            self.adf,
+            self.ndf,
+            self.calcium,
+            self.phosphorus,
+        ];
+        for (key, value) in FIELDS[1..].iter().zip(values) {
+            ensure!(
+                value.is_finite() && value >= 0.0,
+                "\"{}\" of {} must not be negative.",
+                key,
+                self.name
+            );
+        }
+        ensure!(
+            self.dry_matter > 0.0 && self.dry_matter <= 100.0,
+            "Dry matter of {} must be greater than 0% and at most 100%.",
+            self.name
+        );
+
+        Ok(())
+    }
+}
+
+/// The `[[feed]]` tables of a TOML feed file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FeedFile {
+    #[serde(default)]
+    feed: Vec<Feed>,
+}
+
+/// A collection of feeds, looked up by name.
+///
+/// The built-in library covers common grains, protein meals, forages and minerals. It can be
+/// extended, or its values overridden, with feeds from CSV or TOML files, such as the results of
+/// a forage test.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::feed::library::FeedLibrary;
+///
+/// let mut library = FeedLibrary::builtin();
+/// assert_eq!(library.get("Soybean meal").unwrap().crude_protein, 53.8);
+///
+/// // A forage test of this year's hay replaces the book values.
+/// library
+///     .extend_from_csv("Alfalfa hay,87,21.5,60,1.33,0.75,32,42,1.50,0.26")
+///     .unwrap();
+/// assert_eq!(library.get("alfalfa_hay").unwrap().crude_protein, 21.5);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FeedLibrary {
+    feeds: Vec<Feed>,
+}
+
+impl FeedLibrary {
+    /// An empty library.
+    pub fn new() -> Self {
+        FeedLibrary::default()
+    }
+
+    /// The built-in library of common feeds.
+    pub fn builtin() -> Self {
+        let mut library = FeedLibrary::new();
+        library
+            .extend_from_csv(BUILTIN_FEEDS)
+            .expect("Built-in feed table is valid.");
+        library
+    }
+
+    /// Every feed in the library, sorted by name.
+    pub fn feeds(&self) -> &[Feed] {
+        &self.feeds
+    }
+
+    /// Look up a feed by name, ignoring case and treating underscores and dashes as spaces.
+    pub fn get(&self, name: &str) -> Option<&Feed> {
+        let key = normalize(name);
+        self.feeds.iter().find(|feed| normalize(&feed.name) == key)
+    }
+
+    /// Add a feed to the library.
+    ///
+    /// # Returns
+    /// The feed it replaced, if the library already had a feed of the same name.
+    pub fn insert(&mut self, feed: Feed) -> Option<Feed> {
+        let key = normalize(&feed.name);
+        let replaced = match self
+            .feeds
+            .iter()
+            .position(|existing| normalize(&existing.name) == key)
+        {
+            Some(index) => Some(std::mem::replace(&mut self.feeds[index], feed)),
+            None => {
+                self.feeds.push(feed);
+                None
+            }
+        };
+        self.feeds.sort_by_key(|feed| normalize(&feed.name));
+        replaced
+    }
+
+    /// Add feeds from CSV, replacing any of the same name.
+    ///
+    /// Each row is `name,dry_matter,crude_protein,tdn,nem,neg,adf,ndf,calcium,phosphorus`, with
+    /// dry matter as % as fed, energy in Mcal/kg of dry matter and the rest as % of dry matter.
+    /// Blank lines, `#` comments and a header row are skipped.
+    ///
+    /// # Returns
+    /// The number of feeds read, or an error naming the first invalid line.
+    pub fn extend_from_csv(&mut self, contents: &str) -> Result<usize> {
+        // The first row may be a header such as `name,dry_matter,...`.
+        let rows = read_rows(contents, |fields| fields[0].eq_ignore_ascii_case("name"));
+        let feeds = rows
+            .iter()
+            .map(|row| {
+                Feed::from_csv_fields(&row.fields)
+                    .with_context(|| format!("Invalid feed on line {}.", row.line))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(self.extend(feeds))
+    }
+
+    /// Add feeds from TOML, replacing any of the same name.
+    ///
+    /// Each feed is a `[[feed]]` table with the same fields as a CSV row, for example:
+    ///
+    /// ```toml
+    /// [[feed]]
+    /// name = "Cottonseed hulls"
+    /// dry_matter = 90
+    /// crude_protein = 4.1
+    /// tdn = 45
+    /// nem = 0.93
+    /// neg = 0.20
+    /// adf = 68
+    /// ndf = 85
+    /// calcium = 0.15
+    /// phosphorus = 0.09
+    /// ```
+    ///
+    /// # Returns
+    /// The number of feeds read, or an error naming the first invalid feed.
+    pub fn extend_from_toml(&mut self, contents: &str) -> Result<usize> {
+        let file: FeedFile = toml::from_str(contents).context("Invalid feed TOML.")?;
+        for (index, feed) in file.feed.iter().enumerate() {
+            feed.validate()
+                .with_context(|| format!("Invalid feed in [[feed]] table {}.", index + 1))?;
+        }
+        Ok(self.extend(file.feed))
+    }
+
+    fn extend(&mut self, feeds: Vec<Feed>) -> usize {
+        let count = feeds.len();
+        for feed in feeds {
+            self.insert(feed);
+        }
+        count
+    }
+}
+
+/// The form of a feed name used to look it up.
+fn normalize(name: &str) -> String {
+    name.trim()
+        .to_lowercase()
+        .replace(['_', '-'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::{Mass, MassRate};
+
+    #[test]
+    fn test_builtin_library() {
+        let library = FeedLibrary::builtin();
+        assert_eq!(library.feeds().len(), 15);
+
+        let corn = library.get("corn grain").unwrap();
+        assert_eq!(corn.dry_matter, 88.0);
+        assert_eq!(corn.nem, 2.18);
+        for name in ["Corn Grain", "corn_grain", "corn-grain", " corn  grain "] {
+            assert_eq!(library.get(name), Some(corn));
+        }
+        assert!(library.get("corn").is_none());
+
+        let names: Vec<&str> = library
+            .feeds()
+            .iter()
+            .map(|feed| feed.name.as_str())
+            .collect();
+        let mut sorted = names.clone();
+        sorted.sort_by_key(|name| name.to_lowercase());
+        assert_eq!(names, sorted);
+    }
+
+    #[test]
+    fn test_dry_matter_conversions() {
+        let library = FeedLibrary::builtin();
+        let silage = library.get("corn silage").unwrap();
+
+        let dry_matter = silage.to_dry_matter(Mass::kilograms(20.0));
+        assert!((dry_matter.as_kilograms() - 7.0).abs() < 1e-9);
+        assert!((silage.to_as_fed(dry_matter).as_kilograms() - 20.0).abs() < 1e-9);
+
+        let intake = silage.to_dry_matter(MassRate::pounds_per_day(40.0));
+        assert!((intake.as_pounds_per_day() - 14.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_extend_from_csv() {
+        let mut library = FeedLibrary::builtin();
+        let csv = "
+            # This year's forage tests
+            name,dry_matter,crude_protein,tdn,nem,neg,adf,ndf,calcium,phosphorus
+            Alfalfa hay,87,21.5,60,1.33,0.75,32,42,1.50,0.26
+
+            Sorghum silage,30,7.5,60,1.32,0.76,35,58,0.35,0.21
+        ";
+        assert_eq!(library.extend_from_csv(csv).unwrap(), 2);
+        assert_eq!(library.feeds().len(), 16);
+        assert_eq!(library.get("alfalfa hay").unwrap().crude_protein, 21.5);
+        assert_eq!(library.get("sorghum silage").unwrap().dry_matter, 30.0);
+    }
+
+    #[test]
+    fn test_extend_from_csv_invalid() {
+        let cases = [
+            "Hay,87,21.5,60,1.33,0.75,32,42,1.50",
+            "Hay,87,21.5,sixty,1.33,0.75,32,42,1.50,0.26",
+            "Hay,0,21.5,60,1.33,0.75,32,42,1.50,0.26",
+            "Hay,120,21.5,60,1.33,0.75,32,42,1.50,0.26",
+            "Hay,87,-1,60,1.33,0.75,32,42,1.50,0.26",
+            ",87,21.5,60,1.33,0.75,32,42,1.50,0.26",
+        ];
+
+        for csv in cases.iter() {
+            let mut library = FeedLibrary::new();
+            assert!(library.extend_from_csv(csv).is_err(), "{}", csv);
+            assert!(library.feeds().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_extend_from_toml() {
+        let mut library = FeedLibrary::new();
+        let toml = r#"
+            # Feeds bought this season
+            [[feed]]
+            name = "Cottonseed hulls # bulk"
+            dry_matter = 90
+            crude_protein = 4.1
+            tdn = 45
+            nem = 0.93
+            neg = 0.20
+            adf = 68
+            ndf = 85
+            calcium = 0.15
+            phosphorus = 0.09 # from the supplier
+
+            [[feed]]
+            name = 'Soybean hulls'
+            dry_matter = 91
+            crude_protein = 12.2
+            tdn = 77
+            nem = 1.85
+            neg = 1.21
+            adf = 47
+            ndf = 64
+            calcium = 0.55
+            phosphorus = 0.17
+        "#;
+
+        assert_eq!(library.extend_from_toml(toml).unwrap(), 2);
+        let hulls = library.get("cottonseed hulls # bulk").unwrap();
+        assert_eq!(hulls.phosphorus, 0.09);
+        assert_eq!(library.get("soybean hulls").unwrap().tdn, 77.0);
+    }
+
+    #[test]
+    fn test_extend_from_toml_invalid() {
+        let cases = [
+            "name = \"Hay\"",
+            "[[feed]]\nname = \"Hay\"\ndry_matter = 87",
+            "[[feed]]\nname = \"Hay\nphosphorus = 0.2",
+            "[[feed]]\nname = \"Hay\"\ncolor = \"green\"",
+            "[feed]\nname = \"Hay\"",
+            "[[feed]]\nname = \"Hay\"\ndry_matter = 87\ncrude_protein = -1\ntdn = 60\nnem = 1.33\nneg = 0.75\nadf = 32\nndf = 42\ncalcium = 1.5\nphosphorus = 0.26",
+        ];
+
+        for toml in cases.iter() {
+            assert!(
+                FeedLibrary::new().extend_from_toml(toml).is_err(),
+                "{}",
+                toml
+            );
+        }
+    }
+}
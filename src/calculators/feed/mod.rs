@@ -1,2 +1,7 @@
 pub mod efficiency;
-pub mod fcr;
\ No newline at end of file
+pub mod fcr;
+pub mod intake;
+pub mod library;
+pub mod ration;
+pub mod requirements;
+pub mod rfi;
@@ -0,0 +1,734 @@
+use crate::calculators::feed::library::{Feed, DE_PER_TDN, ME_PER_DE};
+use crate::calculators::feed::requirements::{EnergyRequirement, Requirements};
+use crate::calculators::simplex::{minimize, Constraint, LinearSolution, Relation};
+use crate::units::MassRate;
+use anyhow::{anyhow, ensure, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+
+/// Inclusions and nutrient concentrations this close to a limit are treated as meeting it.
+const TOLERANCE: f64 = 1e-6;
+
+/// A nutrient a ration can be constrained on, measured as a concentration in dry matter.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize, ValueEnum)]
+pub enum Nutrient {
+    CrudeProtein,
+    Tdn,
+    Nem,
+    Neg,
+    Adf,
+    Ndf,
+    Calcium,
+    Phosphorus,
+}
+
+impl Nutrient {
+    /// The concentration of the nutrient in a feed's dry matter.
+    pub fn in_feed(&self, feed: &Feed) -> f64 {
+        match self {
+            Nutrient::CrudeProtein => feed.crude_protein,
+            Nutrient::Tdn => feed.tdn,
+            Nutrient::Nem => feed.nem,
+            Nutrient::Neg => feed.neg,
+            Nutrient::Adf => feed.adf,
+            Nutrient::Ndf => feed.ndf,
+            Nutrient::Calcium => feed.calcium,
+            Nutrient::Phosphorus => feed.phosphorus,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Nutrient::CrudeProtein => "Crude protein",
+            Nutrient::Tdn => "TDN",
+            Nutrient::Nem => "NEm",
+            Nutrient::Neg => "NEg",
+            Nutrient::Adf => "ADF",
+            Nutrient::Ndf => "NDF",
+            Nutrient::Calcium => "Calcium",
+            Nutrient::Phosphorus => "Phosphorus",
+        }
+    }
+
+    /// The unit of the nutrient's concentration.
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Nutrient::Nem | Nutrient::Neg => "Mcal/kg DM",
+            _ => "% of DM",
+        }
+    }
+}
+
+/// Limits on the concentration of a nutrient in a ration's dry matter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NutrientBound {
+    pub nutrient: Nutrient,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl NutrientBound {
+    pub fn at_least(nutrient: Nutrient, min: f64) -> Self {
+        NutrientBound {
+            nutrient,
+            min: Some(min),
+            max: None,
+        }
+    }
+
+    pub fn at_most(nutrient: Nutrient, max: f64) -> Self {
+        NutrientBound {
+            nutrient,
+            min: None,
+            max: Some(max),
+        }
+    }
+}
+
+/// What a ration must supply: its daily dry matter and the nutrient concentrations within it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RationTargets {
+    /// Dry matter fed per head per day.
+    pub dmi: MassRate,
+    pub bounds: Vec<NutrientBound>,
+}
+
+impl RationTargets {
+    /// Targets that meet an animal's requirements at its predicted intake.
+    ///
+    /// The ration must reach the energy densities the requirements were found at, and enough
+    /// protein, calcium and phosphorus per kg of dry matter to supply the daily requirements.
+    /// Metabolizable and digestible energy are met through the TDN they are estimated from.
+    pub fn from_requirements(requirements: &Requirements) -> Self {
+        let dmi = requirements.dmi.as_kilograms_per_day();
+        let percent = |amount: MassRate| amount.as_kilograms_per_day() / dmi * 100.0;
+
+        let mut bounds = match requirements.energy {
+            EnergyRequirement::Net {
+                diet_nem, diet_neg, ..
+            } => vec![
+                NutrientBound::at_least(Nutrient::Nem, diet_nem),
+                NutrientBound::at_least(Nutrient::Neg, diet_neg),
+            ],
+            EnergyRequirement::Metabolizable { diet, .. } => {
+                vec![NutrientBound::at_least(
+                    Nutrient::Tdn,
+                    diet / ME_PER_DE / DE_PER_TDN,
+                )]
+            }
+            EnergyRequirement::Digestible { diet, .. } => {
+                vec![NutrientBound::at_least(Nutrient::Tdn, diet / DE_PER_TDN)]
+            }
+        };
+        bounds.extend([
+            NutrientBound::at_least(Nutrient::CrudeProtein, percent(requirements.crude_protein)),
+            NutrientBound::at_least(Nutrient::Calcium, percent(requirements.calcium)),
+            NutrientBound::at_least(Nutrient::Phosphorus, percent(requirements.phosphorus)),
+        ]);
+
+        RationTargets {
+            dmi: requirements.dmi,
+            bounds,
+        }
+    }
+}
+
+/// A feed available to a ration, with its price and limits on how much of the ration it makes
+/// up.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RationFeed {
+    pub feed: Feed,
+    /// Price per kg as fed, in any currency.
+    pub price: f64,
+    /// Minimum inclusion, % of the ration's dry matter.
+    pub min_inclusion: Option<f64>,
+    /// Maximum inclusion, % of the ration's dry matter.
+    pub max_inclusion: Option<f64>,
+}
+
+impl RationFeed {
+    /// A feed that may make up any share of the ration.
+    pub fn new(feed: Feed, price: f64) -> Self {
+        RationFeed {
+            feed,
+            price,
+            min_inclusion: None,
+            max_inclusion: None,
+        }
+    }
+}
+
+/// One feed in a formulated ration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RationIngredient {
+    pub name: String,
+    /// Share of the ration's dry matter, in %.
+    pub inclusion: f64,
+    /// Dry matter fed per head per day.
+    pub dry_matter: MassRate,
+    /// Feed as fed per head per day.
+    pub as_fed: MassRate,
+    /// Cost per head per day.
+    pub cost: f64,
+}
+
+/// A least-cost ration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ration {
+    /// The feeds in the ration, largest inclusion first. Feeds left out are not listed.
+    pub ingredients: Vec<RationIngredient>,
+    /// Dry matter fed per head per day.
+    pub dmi: MassRate,
+    /// Feed as fed per head per day.
+    pub as_fed: MassRate,
+    /// Cost per head per day.
+    pub cost_per_day: f64,
+    /// Cost per kg of dry matter.
+    pub cost_per_dry_matter: f64,
+    /// The concentration of each nutrient in the ration's dry matter.
+    pub nutrients: Vec<(Nutrient, f64)>,
+}
+
+/// The error returned when no ration meets every constraint, with the reasons why.
+///
+/// It is returned inside an [`anyhow::Error`], and can be recovered with `downcast_ref`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InfeasibleRation {
+    pub reasons: Vec<String>,
+}
+
+impl fmt::Display for InfeasibleRation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No ration meets every constraint:")?;
+        for reason in &self.reasons {
+            write!(f, "\n- {}", reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for InfeasibleRation {}
+
+/// Formulate the least-cost ration from a set of feeds, as a linear program.
+///
+/// Each feed's share of the ration's dry matter is chosen to minimize the cost of the ration
+/// while keeping every nutrient within its bounds and every feed within its inclusion limits.
+///
+/// # Arguments
+/// - `feeds`: The feeds available, with their prices and inclusion limits.
+/// - `targets`: The daily dry matter to feed and the nutrient bounds the ration must meet.
+///
+/// # Returns
+/// The least-cost ration, or an [`InfeasibleRation`] error explaining which constraints cannot
+/// be met together.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::feed::library::FeedLibrary;
+/// use livestock_rs::calculators::feed::ration::{
+///     formulate_least_cost_ration, Nutrient, NutrientBound, RationFeed, RationTargets,
+/// };
+/// use livestock_rs::units::MassRate;
+///
+/// let library = FeedLibrary::builtin();
+/// let feeds = vec![
+///     RationFeed::new(library.get("corn grain").unwrap().clone(), 0.22),
+///     RationFeed::new(library.get("soybean meal").unwrap().clone(), 0.45),
+///     RationFeed {
+///         min_inclusion: Some(15.0),
+///         ..RationFeed::new(library.get("grass hay").unwrap().clone(), 0.12)
+///     },
+/// ];
+/// let targets = RationTargets {
+///     dmi: MassRate::kilograms_per_day(8.0),
+///     bounds: vec![
+///         NutrientBound::at_least(Nutrient::CrudeProtein, 13.0),
+///         NutrientBound::at_least(Nutrient::Nem, 1.9),
+///     ],
+/// };
+///
+/// let ration = formulate_least_cost_ration(&feeds, &targets).unwrap();
+/// assert_eq!(ration.ingredients[0].name, "Corn grain");
+/// assert_eq!(format!("{:.2}", ration.cost_per_day), "1.93");
+/// ```
+pub fn formulate_least_cost_ration(
+    feeds: &[RationFeed],
+    targets: &RationTargets,
+) -> Result<Ration> {
+    ensure!(!feeds.is_empty(), "At least one feed is needed.");
+    ensure!(
+        targets.dmi.as_kilograms_per_day() > 0.0,
+        "Dry matter intake must be greater than zero."
+    );
+    for feed in feeds {
+        ensure!(
+            feed.price >= 0.0,
+            "Price of {} must not be negative.",
+            feed.feed.name
+        );
+        let min = feed.min_inclusion.unwrap_or(0.0);
+        let max = feed.max_inclusion.unwrap_or(100.0);
+        ensure!(
+            (0.0..=100.0).contains(&min) && (0.0..=100.0).contains(&max) && min <= max,
+            "Inclusion limits of {} must be between 0% and 100%, minimum first.",
+            feed.feed.name
+        );
+    }
+    for bound in &targets.bounds {
+        if let (Some(min), Some(max)) = (bound.min, bound.max) {
+            ensure!(
+                min <= max,
+                "Minimum {} must not be more than the maximum.",
+                bound.nutrient.name()
+            );
+        }
+    }
+
+    let mut reasons = Vec::new();
+    let min_total: f64 = feeds.iter().filter_map(|feed| feed.min_inclusion).sum();
+    if min_total > 100.0 + TOLERANCE {
+        reasons.push(format!(
+            "Minimum inclusions total {:.1}%, more than the whole ration.",
+            min_total
+        ));
+    }
+    let max_total: f64 = feeds
+        .iter()
+        .map(|feed| feed.max_inclusion.unwrap_or(100.0))
+        .sum();
+    if max_total < 100.0 - TOLERANCE {
+        reasons.push(format!(
+            "Maximum inclusions total only {:.1}%, less than the whole ration.",
+            max_total
+        ));
+    }
+    if !reasons.is_empty() {
+        return Err(InfeasibleRation { reasons }.into());
+    }
+
+    // The variables are each feed's share of the ration's dry matter, with costs per kg of dry
+    // matter. Each constraint is kept with a description of what it requires.
+    let costs: Vec<f64> = feeds
+        .iter()
+        .map(|feed| feed.feed.to_as_fed(feed.price))
+        .collect();
+    let unit = |index: usize| {
+        let mut coefficients = vec![0.0; feeds.len()];
+        coefficients[index] = 1.0;
+        coefficients
+    };
+    let mut constraints = vec![(
+        Constraint::new(vec![1.0; feeds.len()], Relation::Equal, 1.0),
+        "Feeds must make up the whole ration".to_string(),
+    )];
+    for (index, feed) in feeds.iter().enumerate() {
+        if let Some(min) = feed.min_inclusion {
+            constraints.push((
+                Constraint::new(unit(index), Relation::AtLeast, min / 100.0),
+                format!(
+                    "{} must be at least {:.1}% of the ration",
+                    feed.feed.name, min
+                ),
+            ));
+        }
+        if let Some(max) = feed.max_inclusion {
+            constraints.push((
+                Constraint::new(unit(index), Relation::AtMost, max / 100.0),
+                format!(
+                    "{} must be at most {:.1}% of the ration",
+                    feed.feed.name, max
+                ),
+            ));
+        }
+    }
+    let structural = constraints.len();
+    for bound in &targets.bounds {
+        let nutrient = bound.nutrient;
+        let coefficients: Vec<f64> = feeds
+            .iter()
+            .map(|feed| nutrient.in_feed(&feed.feed))
+            .collect();
+        if let Some(min) = bound.min {
+            constraints.push((
+                Constraint::new(coefficients.clone(), Relation::AtLeast, min),
+                format!(
+                    "{} must be at least {:.2} {}",
+                    nutrient.name(),
+                    min,
+                    nutrient.unit()
+                ),
+            ));
+        }
+        if let Some(max) = bound.max {
+            constraints.push((
+                Constraint::new(coefficients, Relation::AtMost, max),
+                format!(
+                    "{} must be at most {:.2} {}",
+                    nutrient.name(),
+                    max,
+                    nutrient.unit()
+                ),
+            ));
+        }
+    }
+
+    let (constraints, descriptions): (Vec<Constraint>, Vec<String>) =
+        constraints.into_iter().unzip();
+    let shares = match minimize(&costs, &constraints) {
+        LinearSolution::Optimal { values, .. } => values,
+        LinearSolution::Infeasible => {
+            let reasons = infeasibility_reasons(&constraints, &descriptions, structural);
+            return Err(InfeasibleRation { reasons }.into());
+        }
+        LinearSolution::Unbounded => return Err(anyhow!("The ration's cost is unbounded.")),
+    };
+
+    let dmi = targets.dmi;
+    let mut ingredients: Vec<RationIngredient> = feeds
+        .iter()
+        .zip(&shares)
+        .filter(|(_, share)| **share > TOLERANCE)
+        .map(|(feed, share)| {
+            let dry_matter = dmi * *share;
+            let as_fed = feed.feed.to_as_fed(dry_matter);
+            RationIngredient {
+                name: feed.feed.name.clone(),
+                inclusion: share * 100.0,
+                dry_matter,
+                as_fed,
+                cost: as_fed.as_kilograms_per_day() * feed.price,
+            }
+        })
+        .collect();
+    ingredients.sort_by(|a, b| b.inclusion.total_cmp(&a.inclusion));
+
+    let nutrients = [
+        Nutrient::CrudeProtein,
+        Nutrient::Tdn,
+        Nutrient::Nem,
+        Nutrient::Neg,
+        Nutrient::Adf,
+        Nutrient::Ndf,
+        Nutrient::Calcium,
+        Nutrient::Phosphorus,
+    ]
+    .into_iter()
+    .map(|nutrient| {
+        let concentration = feeds
+            .iter()
+            .zip(&shares)
+            .map(|(feed, share)| nutrient.in_feed(&feed.feed) * share)
+            .sum();
+        (nutrient, concentration)
+    })
+    .collect();
+
+    let cost_per_day: f64 = ingredients.iter().map(|ingredient| ingredient.cost).sum();
+    Ok(Ration {
+        as_fed: MassRate::kilograms_per_day(
+            ingredients
+                .iter()
+                .map(|ingredient| ingredient.as_fed.as_kilograms_per_day())
+                .sum(),
+        ),
+        cost_per_dry_matter: cost_per_day / dmi.as_kilograms_per_day(),
+        cost_per_day,
+        dmi,
+        ingredients,
+        nutrients,
+    })
+}
+
+/// Explain why no ration meets a set of constraints, the first `structural` of which (the whole
+/// ration and the inclusion limits) can be met on their own.
+///
+/// Each nutrient bound that no mix of the feeds can reach is reported with the best
+/// concentration the feeds can reach. If every bound can be reached on its own, a minimal set of
+/// bounds that cannot be met together is found instead, by dropping each bound the others are
+/// still infeasible without.
+fn infeasibility_reasons(
+    constraints: &[Constraint],
+    descriptions: &[String],
+    structural: usize,
+) -> Vec<String> {
+    let (base, bounds) = constraints.split_at(structural);
+    let variables = base[0].coefficients.len();
+
+    let mut reasons = Vec::new();
+    for (bound, description) in bounds.iter().zip(&descriptions[structural..]) {
+        let (sign, extreme) = match bound.relation {
+            Relation::AtLeast => (-1.0, "most"),
+            Relation::AtMost => (1.0, "least"),
+            Relation::Equal => continue,
+        };
+        let objective: Vec<f64> = bound.coefficients.iter().map(|c| sign * c).collect();
+        if let LinearSolution::Optimal { objective, .. } = minimize(&objective, base) {
+            let best = sign * objective;
+            if sign * (best - bound.bound) > TOLERANCE {
+                reasons.push(format!(
+                    "{}, but the {} any mix of the feeds within their inclusion limits reaches \
+                     is {:.2}.",
+                    description, extreme, best
+                ));
+            }
+        }
+    }
+    if !reasons.is_empty() {
+        return reasons;
+    }
+
+    let feasible = |kept: &[usize]| {
+        let constraints: Vec<Constraint> = base
+            .iter()
+            .chain(kept.iter().map(|index| &bounds[*index]))
+            .cloned()
+            .collect();
+        !matches!(
+            minimize(&vec![0.0; variables], &constraints),
+            LinearSolution::Infeasible
+        )
+    };
+    let mut conflict: Vec<usize> = (0..bounds.len()).collect();
+    let mut position = 0;
+    while position < conflict.len() {
+        let mut without = conflict.clone();
+        without.remove(position);
+        if feasible(&without) {
+            position += 1;
+        } else {
+            conflict = without;
+        }
+    }
+
+    match conflict.as_slice() {
+        [] => vec!["The constraints conflict.".to_string()],
+        _ => {
+            let descriptions: Vec<&str> = conflict
+                .iter()
+                .map(|index| descriptions[structural + index].as_str())
+                .collect();
+            vec![format!(
+                "{} cannot be met together by any mix of the feeds within their inclusion limits.",
+                descriptions.join("; ")
+            )]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculators::feed::library::FeedLibrary;
+    use crate::calculators::feed::requirements::{calculate_requirements, RequirementInputs};
+    use crate::types::LivestockType;
+    use crate::units::Mass;
+
+    fn feed(name: &str, price: f64) -> RationFeed {
+        RationFeed::new(FeedLibrary::builtin().get(name).unwrap().clone(), price)
+    }
+
+    fn concentration(ration: &Ration, nutrient: Nutrient) -> f64 {
+        ration
+            .nutrients
+            .iter()
+            .find(|(n, _)| *n == nutrient)
+            .unwrap()
+            .1
+    }
+
+    #[test]
+    fn test_formulate_least_cost_ration() {
+        // Corn and soybean meal mixed to exactly 16% crude protein, the cheapest way to get it.
+        let feeds = [feed("corn grain", 0.20), feed("soybean meal", 0.40)];
+        let targets = RationTargets {
+            dmi: MassRate::kilograms_per_day(10.0),
+            bounds: vec![NutrientBound::at_least(Nutrient::CrudeProtein, 16.0)],
+        };
+
+        let ration = formulate_least_cost_ration(&feeds, &targets).unwrap();
+        let soybean_share = (16.0 - 9.0) / (53.8 - 9.0);
+        assert_eq!(ration.ingredients.len(), 2);
+        assert_eq!(ration.ingredients[0].name, "Corn grain");
+        assert!((ration.ingredients[1].inclusion - soybean_share * 100.0).abs() < 1e-6);
+        assert!((concentration(&ration, Nutrient::CrudeProtein) - 16.0).abs() < 1e-6);
+
+        let dry_matter: f64 = ration
+            .ingredients
+            .iter()
+            .map(|ingredient| ingredient.dry_matter.as_kilograms_per_day())
+            .sum();
+        assert!((dry_matter - 10.0).abs() < 1e-9);
+
+        // Both feeds are 88-90% dry matter, so as fed is a little more than the DMI.
+        let expected_cost =
+            10.0 * (1.0 - soybean_share) / 0.88 * 0.20 + 10.0 * soybean_share / 0.90 * 0.40;
+        assert!((ration.cost_per_day - expected_cost).abs() < 1e-9);
+        assert!((ration.cost_per_dry_matter - expected_cost / 10.0).abs() < 1e-9);
+        assert!(ration.as_fed > ration.dmi);
+    }
+
+    #[test]
+    fn test_formulate_least_cost_ration_inclusion_limits() {
+        let feeds = [
+            RationFeed {
+                max_inclusion: Some(60.0),
+                ..feed("corn grain", 0.20)
+            },
+            feed("barley grain", 0.25),
+            RationFeed {
+                min_inclusion: Some(10.0),
+                ..feed("alfalfa hay", 0.30)
+            },
+        ];
+        let targets = RationTargets {
+            dmi: MassRate::kilograms_per_day(10.0),
+            bounds: vec![NutrientBound::at_most(Nutrient::Ndf, 20.0)],
+        };
+
+        let ration = formulate_least_cost_ration(&feeds, &targets).unwrap();
+        let inclusion = |name: &str| {
+            ration
+                .ingredients
+                .iter()
+                .find(|ingredient| ingredient.name == name)
+                .map_or(0.0, |ingredient| ingredient.inclusion)
+        };
+        assert!((inclusion("Corn grain") - 60.0).abs() < 1e-6);
+        assert!((inclusion("Alfalfa hay") - 10.0).abs() < 1e-6);
+        assert!((inclusion("Barley grain") - 30.0).abs() < 1e-6);
+        assert!(concentration(&ration, Nutrient::Ndf) <= 20.0 + 1e-6);
+    }
+
+    #[test]
+    fn test_formulate_least_cost_ration_for_requirements() {
+        let inputs =
+            RequirementInputs::new(Mass::kilograms(300.0), MassRate::kilograms_per_day(1.3));
+        let requirements = calculate_requirements(LivestockType::Cattle, &inputs).unwrap();
+        let targets = RationTargets::from_requirements(&requirements);
+        let feeds = [
+            feed("corn grain", 0.22),
+            feed("corn silage", 0.06),
+            feed("soybean meal", 0.45),
+            feed("alfalfa hay", 0.20),
+            feed("limestone", 0.10),
+            feed("dicalcium phosphate", 0.90),
+        ];
+
+        let ration = formulate_least_cost_ration(&feeds, &targets).unwrap();
+        for bound in &targets.bounds {
+            let supplied = concentration(&ration, bound.nutrient);
+            assert!(
+                supplied >= bound.min.unwrap() - 1e-6,
+                "{:?}: {} < {:?}",
+                bound.nutrient,
+                supplied,
+                bound.min
+            );
+        }
+    }
+
+    #[test]
+    fn test_formulate_least_cost_ration_infeasible() {
+        // Grass hay and corn can never reach 20% crude protein.
+        let feeds = [feed("grass hay", 0.12), feed("corn grain", 0.20)];
+        let targets = RationTargets {
+            dmi: MassRate::kilograms_per_day(10.0),
+            bounds: vec![
+                NutrientBound::at_least(Nutrient::CrudeProtein, 20.0),
+                NutrientBound::at_least(Nutrient::Nem, 1.5),
+            ],
+        };
+        let err = formulate_least_cost_ration(&feeds, &targets).unwrap_err();
+        let infeasible = err.downcast_ref::<InfeasibleRation>().unwrap();
+        assert_eq!(infeasible.reasons.len(), 1);
+        assert!(infeasible.reasons[0].starts_with("Crude protein must be at least 20.00"));
+
+        let feeds = [
+            RationFeed {
+                min_inclusion: Some(70.0),
+                ..feed("grass hay", 0.12)
+            },
+            RationFeed {
+                min_inclusion: Some(40.0),
+                ..feed("corn grain", 0.20)
+            },
+        ];
+        let targets = RationTargets {
+            dmi: MassRate::kilograms_per_day(10.0),
+            bounds: Vec::new(),
+        };
+        let err = formulate_least_cost_ration(&feeds, &targets).unwrap_err();
+        assert!(err.to_string().contains("Minimum inclusions total 110.0%"));
+
+        let feeds = [RationFeed {
+            max_inclusion: Some(50.0),
+            ..feed("grass hay", 0.12)
+        }];
+        let err = formulate_least_cost_ration(&feeds, &targets).unwrap_err();
+        assert!(err.downcast_ref::<InfeasibleRation>().is_some());
+    }
+
+    #[test]
+    fn test_formulate_least_cost_ration_infeasible_nutrient() {
+        // Limestone covers calcium and corn the energy and phosphorus, so protein is the only
+        // requirement no mix of them can meet.
+        let inputs =
+            RequirementInputs::new(Mass::kilograms(300.0), MassRate::kilograms_per_day(1.3));
+        let requirements = calculate_requirements(LivestockType::Cattle, &inputs).unwrap();
+        let targets = RationTargets::from_requirements(&requirements);
+        let feeds = [feed("corn grain", 0.22), feed("limestone", 0.10)];
+
+        let err = formulate_least_cost_ration(&feeds, &targets).unwrap_err();
+        let infeasible = err.downcast_ref::<InfeasibleRation>().unwrap();
+        assert_eq!(infeasible.reasons.len(), 1, "{:?}", infeasible.reasons);
+        assert!(infeasible.reasons[0].starts_with("Crude protein must be at least"));
+        assert!(infeasible.reasons[0].ends_with("reaches is 9.00."));
+    }
+
+    #[test]
+    fn test_formulate_least_cost_ration_conflicting_nutrients() {
+        // Either bound can be met alone: protein needs at least half soybean meal, and energy
+        // at most 27% of it.
+        let feeds = [feed("corn grain", 0.20), feed("soybean meal", 0.40)];
+        let targets = RationTargets {
+            dmi: MassRate::kilograms_per_day(10.0),
+            bounds: vec![
+                NutrientBound::at_least(Nutrient::Phosphorus, 0.3),
+                NutrientBound::at_least(Nutrient::CrudeProtein, 31.4),
+                NutrientBound::at_least(Nutrient::Nem, 2.15),
+            ],
+        };
+
+        let err = formulate_least_cost_ration(&feeds, &targets).unwrap_err();
+        let infeasible = err.downcast_ref::<InfeasibleRation>().unwrap();
+        assert_eq!(infeasible.reasons.len(), 1);
+        let reason = &infeasible.reasons[0];
+        assert!(
+            reason.contains("Crude protein must be at least 31.40"),
+            "{}",
+            reason
+        );
+        assert!(reason.contains("NEm must be at least 2.15"), "{}", reason);
+        assert!(!reason.contains("Phosphorus"), "{}", reason);
+    }
+
+    #[test]
+    fn test_formulate_least_cost_ration_invalid() {
+        let targets = RationTargets {
+            dmi: MassRate::kilograms_per_day(10.0),
+            bounds: Vec::new(),
+        };
+        assert!(formulate_least_cost_ration(&[], &targets).is_err());
+        assert!(formulate_least_cost_ration(&[feed("oats", -1.0)], &targets).is_err());
+
+        let backwards = RationFeed {
+            min_inclusion: Some(50.0),
+            max_inclusion: Some(20.0),
+            ..feed("oats", 0.2)
+        };
+        let err = formulate_least_cost_ration(&[backwards], &targets).unwrap_err();
+        assert!(err.downcast_ref::<InfeasibleRation>().is_none());
+    }
+}
@@ -0,0 +1,1358 @@
+use crate::calculators::feed::intake::{predict_dmi, IntakeInputs};
+use crate::calculators::feed::library::Feed;
+use crate::types::{LivestockType, ProductionStage};
+use crate::units::{Duration, Mass, MassRate};
+use anyhow::{anyhow, bail, ensure, Result};
+
+/// Standard reference weight of cattle, the shrunk weight at 28% empty body fat, in kg.
+const STANDARD_REFERENCE_WEIGHT: f64 = 478.0;
+
+/// Shrunk finished weight of a medium-frame steer, in kg.
+const FINISHED_SHRUNK_WEIGHT: f64 = 576.0;
+
+/// Metabolizable protein as a proportion of crude protein in typical cattle diets.
+const METABOLIZABLE_PROTEIN_PER_CRUDE_PROTEIN: f64 = 0.67;
+
+/// Lowest diet NEm, in Mcal/kg, considered for growing cattle. The growing cattle intake
+/// equation only covers diets above 1.0 Mcal/kg.
+const MIN_GROWING_DIET_NEM: f64 = 1.1;
+
+/// Lowest diet NEm, in Mcal/kg, considered for cows, which can be wintered on poor forage.
+const MIN_COW_DIET_NEM: f64 = 0.8;
+
+/// Highest diet NEm, in Mcal/kg, considered when finding the energy density needed.
+const MAX_DIET_NEM: f64 = 2.6;
+
+/// Net energy in each kg of beef cow milk, at 4% fat, in Mcal (NRC, 1996).
+const MILK_NET_ENERGY: f64 = 0.3512 + 0.0962 * 4.0;
+
+/// True protein in each kg of beef cow milk, in g.
+const MILK_TRUE_PROTEIN: f64 = 34.0;
+
+/// Calf birth weight assumed by the NRC (1996) beef cow requirement tables, in kg.
+const CALF_BIRTH_WEIGHT: f64 = 40.0;
+
+/// Average days in a month, for requirements tabled by month.
+const DAYS_PER_MONTH: f64 = 30.4;
+
+/// Digestible energy of the diet the NRC (1998) growing pig requirements are for, in Mcal/kg as
+/// fed, and its dry matter as a proportion of the diet as fed.
+const SWINE_DIET_DE: f64 = 3.4;
+const SWINE_DIET_DRY_MATTER: f64 = 0.90;
+
+/// Requirements of growing pigs fed ad libitum, from NRC (1998) Table 10-1: the upper body
+/// weight of each weight class in kg, with crude protein, calcium and total phosphorus as % of
+/// the diet as fed. The first class starts at 3 kg.
+const SWINE_REQUIREMENTS: [(f64, f64, f64, f64); 6] = [
+    (5.0, 26.0, 0.90, 0.70),
+    (10.0, 23.7, 0.80, 0.65),
+    (20.0, 20.9, 0.70, 0.60),
+    (50.0, 18.0, 0.60, 0.50),
+    (80.0, 15.5, 0.50, 0.45),
+    (120.0, 13.2, 0.45, 0.40),
+];
+
+/// Maintenance requirements of goats with minimal activity, from NRC (1981) Table 1: body
+/// weight in kg, with total protein, calcium and phosphorus in g/day.
+const GOAT_MAINTENANCE: [[f64; 4]; 10] = [
+    [10.0, 22.0, 1.0, 0.7],
+    [20.0, 38.0, 1.0, 0.7],
+    [30.0, 51.0, 2.0, 1.4],
+    [40.0, 63.0, 2.0, 1.4],
+    [50.0, 74.0, 3.0, 2.1],
+    [60.0, 85.0, 3.0, 2.1],
+    [70.0, 95.0, 4.0, 2.8],
+    [80.0, 105.0, 4.0, 2.8],
+    [90.0, 114.0, 4.0, 2.8],
+    [100.0, 123.0, 5.0, 3.5],
+];
+
+/// Additional requirements of goats from NRC (1981) Table 1, as ME in Mcal with total protein,
+/// calcium and phosphorus in g/day: for each 50 g/day of gain, in the last two months of
+/// pregnancy, and for each kg of milk at 4% fat.
+const GOAT_GAIN: [f64; 4] = [0.36, 14.0, 1.0, 0.7];
+const GOAT_LATE_PREGNANCY: [f64; 4] = [1.42, 82.0, 2.0, 1.4];
+const GOAT_MILK: [f64; 4] = [1.20, 72.0, 2.0, 1.4];
+
+/// ME, in Mcal/kg of dry matter, of the diet NRC (1981) Table 1 gives goats' dry matter for.
+pub(crate) const GOAT_DIET_ME: f64 = 2.0;
+
+/// Daily requirements of sheep from NRC (1985) Table 1: body weight in kg, with dry matter in
+/// kg, ME in Mcal, and crude protein, calcium and phosphorus in g. Ewes are for maintenance, the
+/// first 15 weeks of gestation, the last 4 weeks of gestation (130 to 150% lambing) and the
+/// first 6 to 8 weeks of lactation suckling singles; lambs are finishing lambs of 4 to 7 months
+/// gaining 295, 275 and 205 g/day.
+const SHEEP_MAINTENANCE: [[f64; 6]; 5] = [
+    [50.0, 1.0, 2.0, 95.0, 2.0, 1.8],
+    [60.0, 1.1, 2.2, 104.0, 2.3, 2.1],
+    [70.0, 1.2, 2.4, 113.0, 2.5, 2.4],
+    [80.0, 1.3, 2.6, 122.0, 2.7, 2.8],
+    [90.0, 1.4, 2.8, 131.0, 2.9, 3.1],
+];
+const SHEEP_EARLY_GESTATION: [[f64; 6]; 5] = [
+    [50.0, 1.2, 2.4, 112.0, 2.9, 2.1],
+    [60.0, 1.3, 2.6, 121.0, 3.2, 2.5],
+    [70.0, 1.4, 2.8, 130.0, 3.5, 2.9],
+    [80.0, 1.5, 3.0, 139.0, 3.8, 3.3],
+    [90.0, 1.6, 3.2, 148.0, 4.1, 3.6],
+];
+const SHEEP_LATE_GESTATION: [[f64; 6]; 5] = [
+    [50.0, 1.7, 3.4, 175.0, 5.9, 4.8],
+    [60.0, 1.8, 3.6, 184.0, 6.0, 5.2],
+    [70.0, 1.9, 3.8, 193.0, 6.2, 5.6],
+    [80.0, 2.0, 4.0, 202.0, 6.3, 6.1],
+    [90.0, 2.1, 4.2, 212.0, 6.4, 6.5],
+];
+const SHEEP_LACTATION: [[f64; 6]; 5] = [
+    [50.0, 2.1, 4.9, 304.0, 8.9, 6.1],
+    [60.0, 2.3, 5.4, 319.0, 9.1, 6.6],
+    [70.0, 2.5, 5.9, 334.0, 9.3, 7.0],
+    [80.0, 2.6, 6.1, 344.0, 9.5, 7.4],
+    [90.0, 2.7, 6.3, 353.0, 9.6, 7.8],
+];
+const SHEEP_FINISHING: [[f64; 6]; 3] = [
+    [30.0, 1.3, 3.4, 191.0, 6.6, 3.2],
+    [40.0, 1.6, 4.4, 185.0, 6.6, 3.3],
+    [50.0, 1.6, 4.4, 160.0, 5.6, 3.0],
+];
+
+/// The day of a 147 day pregnancy from which ewes are in the last 4 weeks of gestation.
+const SHEEP_LATE_GESTATION_DAY: u32 = 119;
+
+/// Daily requirements of gestating sows from NRC (1998): body weight at breeding in
+/// kg, with feed as fed in kg and crude protein as % of the diet, for the gestation weight gains
+/// and litter sizes the table assumes.
+const SOW_GESTATION: [[f64; 3]; 4] = [
+    [125.0, 1.96, 12.9],
+    [150.0, 1.82, 12.8],
+    [175.0, 1.80, 12.4],
+    [200.0, 1.92, 12.0],
+];
+
+/// Daily requirements of lactating 175 kg sows from NRC (1998): litter gain in
+/// kg/day, for 10 pigs, with feed as fed in kg and crude protein as % of the diet.
+const SOW_LACTATION: [[f64; 3]; 3] = [[1.5, 4.31, 17.5], [2.0, 5.35, 18.4], [2.5, 6.40, 19.2]];
+
+/// Milk sows give for each kg of litter gain, in kg.
+const SOW_MILK_PER_LITTER_GAIN: f64 = 4.0;
+
+/// Calcium and total phosphorus of gestating and lactating sow diets, as % as fed (NRC, 1998).
+const SOW_CALCIUM: f64 = 0.75;
+const SOW_PHOSPHORUS: f64 = 0.60;
+
+/// Multiples of maintenance DE needed by mares in the 9th, 10th and 11th months of gestation
+/// (NRC, 1989). Mares need no more than maintenance before then.
+const MARE_GESTATION_DE: [f64; 3] = [1.11, 1.13, 1.20];
+
+/// What is known about an animal, used to calculate its requirements.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RequirementInputs {
+    /// Full (unshrunk) body weight.
+    pub body_weight: Mass,
+    /// Target average daily gain.
+    pub adg: MassRate,
+    /// Days since conception, for pregnant females.
+    pub days_pregnant: Option<u32>,
+    /// Daily milk yield, for lactating females.
+    pub milk_yield: Option<MassRate>,
+    /// Expected birth weight of the calf, for pregnant cattle. Without it, the 40 kg calf of the
+    /// NRC (1996) beef cow tables is assumed.
+    pub calf_birth_weight: Option<Mass>,
+    /// Age, for growing horses, whose gain costs more energy as they mature.
+    pub age: Option<Duration>,
+}
+
+impl RequirementInputs {
+    /// Inputs for an animal that is neither pregnant nor lactating.
+    pub fn new(body_weight: Mass, adg: MassRate) -> Self {
+        RequirementInputs {
+            body_weight,
+            adg,
+            days_pregnant: None,
+            milk_yield: None,
+            calf_birth_weight: None,
+            age: None,
+        }
+    }
+
+    /// The stage of production the inputs describe. Lactation takes precedence over gestation,
+    /// and gestation over growth.
+    pub fn stage(&self) -> ProductionStage {
+        if self.milk_yield.is_some() {
+            ProductionStage::Lactation
+        } else if self.days_pregnant.is_some() {
+            ProductionStage::Gestation
+        } else if self.adg.as_kilograms_per_day() > 0.0 {
+            ProductionStage::Growing
+        } else {
+            ProductionStage::Maintenance
+        }
+    }
+}
+
+/// The daily energy requirement of an animal, in the energy system used for its species.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EnergyRequirement {
+    /// The net energy system, for cattle.
+    Net {
+        /// Net energy for maintenance (NEm), including pregnancy and milk, Mcal/day.
+        maintenance: f64,
+        /// Net energy for gain (NEg), Mcal/day.
+        gain: f64,
+        /// The diet NEm, in Mcal/kg of dry matter, at which the predicted intake meets both
+        /// energy requirements.
+        diet_nem: f64,
+        /// The diet NEg, in Mcal/kg of dry matter, that goes with `diet_nem`.
+        diet_neg: f64,
+    },
+    /// Metabolizable energy (ME), for sheep and goats.
+    Metabolizable {
+        /// Mcal/day.
+        daily: f64,
+        /// The diet ME, in Mcal/kg of dry matter, at which the predicted intake meets it.
+        diet: f64,
+    },
+    /// Digestible energy (DE), for swine and horses.
+    Digestible {
+        /// Mcal/day.
+        daily: f64,
+        /// The diet DE, in Mcal/kg of dry matter, at which the predicted intake meets it.
+        diet: f64,
+    },
+}
+
+/// The daily nutrient requirements of an animal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Requirements {
+    /// Predicted dry matter intake on a diet of the required energy density.
+    pub dmi: MassRate,
+    pub energy: EnergyRequirement,
+    /// Metabolizable protein, for cattle.
+    pub metabolizable_protein: Option<MassRate>,
+    pub crude_protein: MassRate,
+    pub calcium: MassRate,
+    pub phosphorus: MassRate,
+}
+
+/// Calculate the daily nutrient requirements of an animal for maintenance, growth, gestation
+/// and lactation.
+///
+/// Requirements are factorial: the needs of maintenance, gain, the pregnancy and milk are added
+/// together.
+///
+/// - Cattle use the net energy system of NASEM (2016) and NRC (1996). Maintenance needs
+///   `NEm = 0.077 * SBW^0.75` and gain `NEg = 0.0557 * EQSBW^0.75 * SWG^1.097` Mcal/day, where
+///   `SBW` is the shrunk body weight, `EQSBW` the weight scaled to a medium-frame steer and
+///   `SWG` the shrunk gain. Intake depends on the diet's energy density, so the density is found
+///   at which the predicted intake supplies the energy needed.
+/// - Sheep use the metabolizable energy tables of NRC (1985) for 50 to 90 kg ewes, at
+///   maintenance, in early and late gestation and in early lactation, and for finishing lambs of
+///   30 to 50 kg, interpolated between the tabled weights. The tables are for typical milk
+///   yields and gains, so those inputs only select the table.
+/// - Goats use the metabolizable energy tables of NRC (1981): maintenance needs
+///   `ME = 0.10138 * BW^0.75` Mcal/day, with protein and minerals interpolated between the
+///   tabled weights of 10 to 100 kg, plus fixed amounts per 50 g of gain, per kg of milk and for
+///   the last two months of pregnancy.
+/// - Growing pigs of 3 to 120 kg use the digestible energy of NRC (1998): the DE intake
+///   `13.162 * (1 - e^(-0.0176 * BW))` Mcal/day of pigs fed ad libitum, with protein and
+///   minerals from the percentages of the diet for the weight class. The tables assume pigs
+///   grow at the rate ad libitum feeding allows, so the target ADG is not used.
+/// - Sows use the gestation and lactation tables of NRC (1998), by weight at breeding for
+///   125 to 200 kg gestating sows, and by litter gain for lactating sows, taken as a quarter of
+///   the milk yield.
+/// - Horses use digestible energy. Maintenance needs `DE = 0.0333 * BW` Mcal/day, with 1.26 g
+///   crude protein, 0.04 g calcium and 0.028 g phosphorus per kg of body weight (NRC, 2007).
+///   Growing horses of 4 months and older need
+///   `DE = 0.0565 * x^-0.145 * BW + (1.99 + 1.21 * x - 0.021 * x^2) * ADG`, where `x` is the
+///   age in months (NRC, 2007). Pregnant mares need 1.11, 1.13 and 1.20 times maintenance in
+///   the last three months (NRC, 1989), and lactating mares 0.792 Mcal for each kg of milk.
+///
+/// # Arguments
+/// - `livestock_type`: The type of livestock.
+/// - `inputs`: The animal's body weight, target gain, days pregnant, milk yield, expected calf
+///   birth weight and age.
+///
+/// # Returns
+/// The daily requirements, or an error if the species is not covered or the requirements
+/// cannot be met on any diet.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::feed::requirements::{
+///     calculate_requirements, EnergyRequirement, RequirementInputs,
+/// };
+/// use livestock_rs::types::LivestockType;
+/// use livestock_rs::units::{Mass, MassRate};
+///
+/// let inputs = RequirementInputs::new(Mass::kilograms(300.0), MassRate::kilograms_per_day(1.3));
+/// let requirements = calculate_requirements(LivestockType::Cattle, &inputs).unwrap();
+/// let EnergyRequirement::Net { maintenance, gain, .. } = requirements.energy else {
+///     panic!("Cattle use net energy.");
+/// };
+/// assert_eq!(format!("{:.2}", maintenance), "5.38");
+/// assert_eq!(format!("{:.2}", gain), "4.32");
+///
+/// // A 50 kg doe giving 2 kg of milk a day.
+/// let inputs = RequirementInputs {
+///     milk_yield: Some(MassRate::kilograms_per_day(2.0)),
+///     ..RequirementInputs::new(Mass::kilograms(50.0), MassRate::kilograms_per_day(0.0))
+/// };
+/// let requirements = calculate_requirements(LivestockType::Goat, &inputs).unwrap();
+/// let EnergyRequirement::Metabolizable { daily, .. } = requirements.energy else {
+///     panic!("Goats use metabolizable energy.");
+/// };
+/// assert_eq!(format!("{:.2}", daily), "4.31");
+/// ```
+pub fn calculate_requirements(
+    livestock_type: LivestockType,
+    inputs: &RequirementInputs,
+) -> Result<Requirements> {
+    ensure!(
+        inputs.body_weight.as_kilograms() > 0.0,
+        "Body weight must be greater than zero."
+    );
+    ensure!(
+        inputs.adg.as_kilograms_per_day() >= 0.0,
+        "ADG must not be negative."
+    );
+    if let Some(milk_yield) = inputs.milk_yield {
+        ensure!(
+            milk_yield.as_kilograms_per_day() >= 0.0,
+            "Milk yield must not be negative."
+        );
+    }
+    if let Some(calf_birth_weight) = inputs.calf_birth_weight {
+        ensure!(
+            calf_birth_weight.as_kilograms() > 0.0,
+            "Calf birth weight must be greater than zero."
+        );
+    }
+    if let Some(age) = inputs.age {
+        ensure!(age.as_days() >= 0.0, "Age must not be negative.");
+    }
+    if let Some(days_pregnant) = inputs.days_pregnant {
+        let length = gestation_length(livestock_type).ok_or_else(|| {
+            anyhow!(
+                "There are no nutrient requirements for {:?}.",
+                livestock_type
+            )
+        })?;
+        ensure!(
+            days_pregnant <= length,
+            "{:?} are pregnant for about {} days, not {}.",
+            livestock_type,
+            length,
+            days_pregnant
+        );
+    }
+
+    match livestock_type {
+        LivestockType::Cattle => cattle_requirements(inputs),
+        LivestockType::Sheep => sheep_requirements(inputs),
+        LivestockType::Goat => goat_requirements(inputs),
+        LivestockType::Swine => swine_requirements(inputs),
+        LivestockType::Horse => horse_requirements(inputs),
+        _ => Err(anyhow!(
+            "There are no nutrient requirements for {:?}.",
+            livestock_type
+        )),
+    }
+}
+
+/// The typical length of gestation, in days, of the species with nutrient requirements.
+fn gestation_length(livestock_type: LivestockType) -> Option<u32> {
+    match livestock_type {
+        LivestockType::Cattle => Some(283),
+        LivestockType::Sheep => Some(147),
+        LivestockType::Goat => Some(150),
+        LivestockType::Swine => Some(114),
+        LivestockType::Horse => Some(340),
+        _ => None,
+    }
+}
+
+fn cattle_requirements(inputs: &RequirementInputs) -> Result<Requirements> {
+    let shrunk_weight = 0.96 * inputs.body_weight.as_kilograms();
+    let equivalent_weight = shrunk_weight * STANDARD_REFERENCE_WEIGHT / FINISHED_SHRUNK_WEIGHT;
+    let shrunk_gain = 0.96 * inputs.adg.as_kilograms_per_day();
+    let milk_yield = inputs
+        .milk_yield
+        .map_or(0.0, |milk_yield| milk_yield.as_kilograms_per_day());
+
+    let mut nem = 0.077 * shrunk_weight.powf(0.75);
+    let neg = 0.0557 * equivalent_weight.powf(0.75) * shrunk_gain.powf(1.097);
+
+    // Protein retained in gain falls as more of the gain is fat.
+    let net_protein_gain = if shrunk_gain > 0.0 {
+        (shrunk_gain * 268.0 - 29.4 * neg).max(0.0)
+    } else {
+        0.0
+    };
+    let efficiency = if equivalent_weight <= 300.0 {
+        0.834 - 0.00114 * equivalent_weight
+    } else {
+        0.492
+    };
+    let mut metabolizable_protein = 3.8 * shrunk_weight.powf(0.75) + net_protein_gain / efficiency;
+
+    // Absorbed minerals for maintenance and gain.
+    let mut calcium = 0.0154 * shrunk_weight + 0.071 * net_protein_gain;
+    let mut phosphorus = 0.016 * shrunk_weight + 0.039 * net_protein_gain;
+
+    // The gravid uterus, from NRC (1996), for the expected calf birth weight. Energy retained
+    // is used with an efficiency of 0.13, and counted as NEm at the efficiency of maintenance.
+    if let Some(days) = inputs.days_pregnant {
+        let days = days as f64;
+        let calf_weight = inputs
+            .calf_birth_weight
+            .map_or(CALF_BIRTH_WEIGHT, |weight| weight.as_kilograms());
+        let retained_energy = calf_weight
+            * (0.05855 - 0.0000996 * days)
+            * (0.03233 * days - 0.0000275 * days.powi(2)).exp()
+            / 1000.0;
+        nem += retained_energy / 0.13 * 0.576;
+        let retained_protein = calf_weight
+            * 6.25
+            * (0.001669 - 0.00000211 * days)
+            * (0.0278 * days - 0.0000176 * days.powi(2)).exp();
+        metabolizable_protein += retained_protein / 0.65;
+        if days >= 190.0 {
+            calcium += 13.7;
+            phosphorus += 7.6;
+        }
+    }
+
+    // Milk, at 4% fat and 3.4% true protein.
+    nem += milk_yield * MILK_NET_ENERGY;
+    metabolizable_protein += milk_yield * MILK_TRUE_PROTEIN / 0.65;
+    calcium += milk_yield * 1.23;
+    phosphorus += milk_yield * 0.95;
+
+    // Growing cattle and cows have different intake equations. Either way, intake falls or
+    // rises slower than the intake needed as energy density rises, so there is one density at
+    // which they meet.
+    let stage = inputs.stage();
+    let dmi = |diet_nem: f64| -> Result<f64> {
+        let inputs = IntakeInputs {
+            energy_density: Some(diet_nem),
+            milk_yield: inputs.milk_yield,
+            pregnant: inputs.days_pregnant.is_some(),
+            ..IntakeInputs::new(inputs.body_weight, stage)
+        };
+        Ok(predict_dmi(LivestockType::Cattle, &inputs)?
+            .dmi
+            .as_kilograms_per_day())
+    };
+    let surplus = |diet_nem: f64| -> Result<f64> {
+        Ok(dmi(diet_nem)? - nem / diet_nem - neg / diet_neg(diet_nem))
+    };
+
+    let mut low = match stage {
+        ProductionStage::Growing => MIN_GROWING_DIET_NEM,
+        _ => MIN_COW_DIET_NEM,
+    };
+    let mut high = MAX_DIET_NEM;
+    if surplus(high)? < 0.0 {
+        if stage == ProductionStage::Growing {
+            return Err(anyhow!(
+                "An ADG of {:.2} kg/day is more than cattle of this weight can gain on any diet.",
+                inputs.adg.as_kilograms_per_day()
+            ));
+        }
+        return Err(anyhow!(
+            "No diet up to {:.1} Mcal/kg NEm meets the energy requirements at the predicted intake.",
+            MAX_DIET_NEM
+        ));
+    }
+    if surplus(low)? < 0.0 {
+        for _ in 0..60 {
+            let middle = (low + high) / 2.0;
+            if surplus(middle)? < 0.0 {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+    } else {
+        high = low;
+    }
+
+    Ok(Requirements {
+        dmi: MassRate::kilograms_per_day(dmi(high)?),
+        energy: EnergyRequirement::Net {
+            maintenance: nem,
+            gain: neg,
+            diet_nem: high,
+            diet_neg: diet_neg(high),
+        },
+        metabolizable_protein: Some(grams_per_day(metabolizable_protein)),
+        crude_protein: grams_per_day(
+            metabolizable_protein / METABOLIZABLE_PROTEIN_PER_CRUDE_PROTEIN,
+        ),
+        // Over the absorption coefficients of calcium and phosphorus.
+        calcium: grams_per_day(calcium / 0.50),
+        phosphorus: grams_per_day(phosphorus / 0.68),
+    })
+}
+
+fn sheep_requirements(inputs: &RequirementInputs) -> Result<Requirements> {
+    let late_gestation = inputs
+        .days_pregnant
+        .is_some_and(|days| days >= SHEEP_LATE_GESTATION_DAY);
+    let [dmi, energy, crude_protein, calcium, phosphorus] = sheep_daily_requirements(
+        inputs.body_weight.as_kilograms(),
+        inputs.stage(),
+        late_gestation,
+    )?;
+
+    Ok(Requirements {
+        dmi: MassRate::kilograms_per_day(dmi),
+        energy: EnergyRequirement::Metabolizable {
+            daily: energy,
+            diet: energy / dmi,
+        },
+        metabolizable_protein: None,
+        crude_protein: grams_per_day(crude_protein),
+        calcium: grams_per_day(calcium),
+        phosphorus: grams_per_day(phosphorus),
+    })
+}
+
+/// The daily dry matter in kg, ME in Mcal, and crude protein, calcium and phosphorus in g that a
+/// sheep needs (NRC, 1985), from its body weight in kg, its stage of production and, for
+/// gestation, whether it is in the last 4 weeks. Growing sheep are taken as finishing lambs.
+pub(crate) fn sheep_daily_requirements(
+    body_weight: f64,
+    stage: ProductionStage,
+    late_gestation: bool,
+) -> Result<[f64; 5]> {
+    let (table, description) = match stage {
+        ProductionStage::Lactation => (&SHEEP_LACTATION[..], "lactating ewes"),
+        ProductionStage::Gestation if late_gestation => {
+            (&SHEEP_LATE_GESTATION[..], "ewes in late gestation")
+        }
+        ProductionStage::Gestation => (&SHEEP_EARLY_GESTATION[..], "ewes in early gestation"),
+        ProductionStage::Growing => (&SHEEP_FINISHING[..], "finishing lambs"),
+        ProductionStage::Maintenance => (&SHEEP_MAINTENANCE[..], "ewes at maintenance"),
+        ProductionStage::Work => bail!("There are no requirements for working sheep."),
+    };
+    let [_, requirements @ ..] = interpolate(table, body_weight).ok_or_else(|| {
+        anyhow!(
+            "Requirements of {} (NRC, 1985) cover {} to {} kg, not {:.0} kg.",
+            description,
+            table[0][0],
+            table[table.len() - 1][0],
+            body_weight
+        )
+    })?;
+
+    Ok(requirements)
+}
+
+fn goat_requirements(inputs: &RequirementInputs) -> Result<Requirements> {
+    // The last two months of a pregnancy of about 150 days.
+    let late_pregnancy = inputs.days_pregnant.is_some_and(|days| days >= 90);
+    let [energy, crude_protein, calcium, phosphorus] = goat_daily_requirements(
+        inputs.body_weight.as_kilograms(),
+        inputs.adg.as_kilograms_per_day(),
+        late_pregnancy,
+        inputs
+            .milk_yield
+            .map_or(0.0, |milk_yield| milk_yield.as_kilograms_per_day()),
+    )?;
+
+    // The tables give dry matter for a diet of 2.0 Mcal/kg ME.
+    let dmi = energy / GOAT_DIET_ME;
+    Ok(Requirements {
+        dmi: MassRate::kilograms_per_day(dmi),
+        energy: EnergyRequirement::Metabolizable {
+            daily: energy,
+            diet: GOAT_DIET_ME,
+        },
+        metabolizable_protein: None,
+        crude_protein: grams_per_day(crude_protein),
+        calcium: grams_per_day(calcium),
+        phosphorus: grams_per_day(phosphorus),
+    })
+}
+
+/// The daily ME in Mcal, with total protein, calcium and phosphorus in g, that a goat needs
+/// (NRC, 1981), from its body weight in kg, gain and milk yield in kg/day, and whether it is in
+/// the last two months of pregnancy.
+pub(crate) fn goat_daily_requirements(
+    body_weight: f64,
+    adg: f64,
+    late_pregnancy: bool,
+    milk_yield: f64,
+) -> Result<[f64; 4]> {
+    // Between the tabled weights, protein and minerals are interpolated.
+    let [_, crude_protein, calcium, phosphorus] = interpolate(&GOAT_MAINTENANCE, body_weight)
+        .ok_or_else(|| {
+            anyhow!(
+                "Goat requirements (NRC, 1981) cover {} to {} kg, not {:.0} kg.",
+                GOAT_MAINTENANCE[0][0],
+                GOAT_MAINTENANCE[GOAT_MAINTENANCE.len() - 1][0],
+                body_weight
+            )
+        })?;
+    let mut total = [
+        0.10138 * body_weight.powf(0.75),
+        crude_protein,
+        calcium,
+        phosphorus,
+    ];
+
+    let additions = [
+        (GOAT_GAIN, adg / 0.05),
+        (GOAT_LATE_PREGNANCY, if late_pregnancy { 1.0 } else { 0.0 }),
+        (GOAT_MILK, milk_yield),
+    ];
+    for (per_unit, units) in additions {
+        for (total, per_unit) in total.iter_mut().zip(per_unit) {
+            *total += per_unit * units;
+        }
+    }
+
+    Ok(total)
+}
+
+fn swine_requirements(inputs: &RequirementInputs) -> Result<Requirements> {
+    let body_weight = inputs.body_weight.as_kilograms();
+    if let Some(milk_yield) = inputs.milk_yield {
+        let litter_gain = milk_yield.as_kilograms_per_day() / SOW_MILK_PER_LITTER_GAIN;
+        let [_, as_fed, crude_protein] =
+            interpolate(&SOW_LACTATION, litter_gain).ok_or_else(|| {
+                anyhow!(
+                    "Lactating sow requirements (NRC, 1998) cover litters gaining {} to {} kg/day, \
+                     from about {} to {} kg of milk, not {:.1} kg.",
+                    SOW_LACTATION[0][0],
+                    SOW_LACTATION[2][0],
+                    SOW_LACTATION[0][0] * SOW_MILK_PER_LITTER_GAIN,
+                    SOW_LACTATION[2][0] * SOW_MILK_PER_LITTER_GAIN,
+                    milk_yield.as_kilograms_per_day()
+                )
+            })?;
+        return Ok(sow_requirements(as_fed, crude_protein));
+    }
+    if inputs.days_pregnant.is_some() {
+        let [_, as_fed, crude_protein] =
+            interpolate(&SOW_GESTATION, body_weight).ok_or_else(|| {
+                anyhow!(
+                    "Gestating sow requirements (NRC, 1998) cover {} to {} kg at breeding, not {:.0} kg.",
+                    SOW_GESTATION[0][0],
+                    SOW_GESTATION[3][0],
+                    body_weight
+                )
+            })?;
+        return Ok(sow_requirements(as_fed, crude_protein));
+    }
+
+    let (_, crude_protein, calcium, phosphorus) = SWINE_REQUIREMENTS
+        .iter()
+        .find(|row| body_weight <= row.0)
+        .filter(|_| body_weight >= 3.0)
+        .ok_or_else(|| {
+            anyhow!(
+                "Growing pig requirements (NRC, 1998) cover 3 to 120 kg, not {:.0} kg.",
+                body_weight
+            )
+        })?;
+
+    // Intake is the diet that supplies the DE pigs eat to when fed ad libitum.
+    let diet = SWINE_DIET_DE / SWINE_DIET_DRY_MATTER;
+    let intake = IntakeInputs {
+        energy_density: Some(diet),
+        ..IntakeInputs::new(inputs.body_weight, ProductionStage::Growing)
+    };
+    let dmi = predict_dmi(LivestockType::Swine, &intake)?
+        .dmi
+        .as_kilograms_per_day();
+    let as_fed = dmi / SWINE_DIET_DRY_MATTER;
+    let percent_of_diet = |percent: f64| grams_per_day(percent * as_fed * 10.0);
+
+    Ok(Requirements {
+        dmi: MassRate::kilograms_per_day(dmi),
+        energy: EnergyRequirement::Digestible {
+            daily: dmi * diet,
+            diet,
+        },
+        metabolizable_protein: None,
+        crude_protein: percent_of_diet(*crude_protein),
+        calcium: percent_of_diet(*calcium),
+        phosphorus: percent_of_diet(*phosphorus),
+    })
+}
+
+/// The requirements of a sow fed an amount of a diet of 3.4 Mcal/kg DE as fed, with its crude
+/// protein as % of the diet.
+fn sow_requirements(as_fed: f64, crude_protein: f64) -> Requirements {
+    let percent_of_diet = |percent: f64| grams_per_day(percent * as_fed * 10.0);
+    Requirements {
+        dmi: MassRate::kilograms_per_day(as_fed * SWINE_DIET_DRY_MATTER),
+        energy: EnergyRequirement::Digestible {
+            daily: as_fed * SWINE_DIET_DE,
+            diet: SWINE_DIET_DE / SWINE_DIET_DRY_MATTER,
+        },
+        metabolizable_protein: None,
+        crude_protein: percent_of_diet(crude_protein),
+        calcium: percent_of_diet(SOW_CALCIUM),
+        phosphorus: percent_of_diet(SOW_PHOSPHORUS),
+    }
+}
+
+fn horse_requirements(inputs: &RequirementInputs) -> Result<Requirements> {
+    let stage = inputs.stage();
+    let body_weight = inputs.body_weight.as_kilograms();
+    let adg = inputs.adg.as_kilograms_per_day();
+
+    // Maintenance (NRC, 2007).
+    let mut energy = 0.0333 * body_weight;
+    let mut crude_protein = 1.26 * body_weight;
+    let mut calcium = 0.04 * body_weight;
+    let mut phosphorus = 0.028 * body_weight;
+    match stage {
+        ProductionStage::Growing => {
+            let age = inputs
+                .age
+                .ok_or_else(|| anyhow!("Growing horse requirements need the horse's age."))?;
+            let months = age.as_days() / DAYS_PER_MONTH;
+            ensure!(
+                months >= 4.0,
+                "Growing horse requirements (NRC, 2007) are for weanlings of 4 months and older."
+            );
+            // Gain is used less efficiently for protein as the horse matures.
+            let efficiency = match months {
+                months if months < 5.0 => 0.50,
+                months if months < 6.0 => 0.45,
+                months if months < 7.0 => 0.40,
+                months if months < 8.0 => 0.35,
+                _ => 0.30,
+            };
+            energy = 0.0565 * months.powf(-0.145) * body_weight
+                + (1.99 + 1.21 * months - 0.021 * months.powi(2)) * adg;
+            crude_protein = 1.44 * body_weight + adg * 1000.0 * 0.20 / efficiency / 0.79;
+            calcium = 0.072 * body_weight + 32.0 * adg;
+            phosphorus = 0.04 * body_weight + 17.8 * adg;
+        }
+        ProductionStage::Gestation => {
+            let month = inputs.days_pregnant.unwrap_or_default() as f64 / DAYS_PER_MONTH;
+            // The 9th month starts after 8 full months.
+            if month >= 8.0 {
+                let index = ((month - 8.0) as usize).min(2);
+                energy *= MARE_GESTATION_DE[index];
+                // 44 g of crude protein for each Mcal of DE, and the minerals of a 500 kg mare's
+                // 35 g calcium and 26 g phosphorus, or 37 g and 28 g in the 11th month (NRC, 1989).
+                crude_protein = 44.0 * energy;
+                let (calcium_per_kg, phosphorus_per_kg) = match index {
+                    2 => (37.0 / 500.0, 28.0 / 500.0),
+                    _ => (35.0 / 500.0, 26.0 / 500.0),
+                };
+                calcium = calcium_per_kg * body_weight;
+                phosphorus = phosphorus_per_kg * body_weight;
+            }
+        }
+        ProductionStage::Lactation => {
+            let milk_yield = inputs
+                .milk_yield
+                .map_or(0.0, |milk_yield| milk_yield.as_kilograms_per_day());
+            // Milk has 1.2 g calcium and 0.75 g phosphorus per kg, absorbed at 50% and 45%.
+            energy += 0.792 * milk_yield;
+            crude_protein = 1.44 * body_weight + 50.0 * milk_yield;
+            calcium += 1.2 / 0.50 * milk_yield;
+            phosphorus += 0.75 / 0.45 * milk_yield;
+        }
+        _ => {}
+    }
+
+    let dmi = predict_dmi(
+        LivestockType::Horse,
+        &IntakeInputs::new(inputs.body_weight, stage),
+    )?
+    .dmi
+    .as_kilograms_per_day();
+
+    Ok(Requirements {
+        dmi: MassRate::kilograms_per_day(dmi),
+        energy: EnergyRequirement::Digestible {
+            daily: energy,
+            diet: energy / dmi,
+        },
+        metabolizable_protein: None,
+        crude_protein: grams_per_day(crude_protein),
+        calcium: grams_per_day(calcium),
+        phosphorus: grams_per_day(phosphorus),
+    })
+}
+
+/// Interpolates between the rows of a table, keyed by its first column in ascending order.
+///
+/// # Returns
+/// The interpolated row, or `None` if the key is outside the table.
+fn interpolate<const N: usize>(table: &[[f64; N]], key: f64) -> Option<[f64; N]> {
+    let (first, last) = (table.first()?[0], table.last()?[0]);
+    if !(first..=last).contains(&key) {
+        return None;
+    }
+    let upper = table
+        .iter()
+        .position(|row| row[0] >= key)
+        .unwrap_or_default()
+        .max(1)
+        .min(table.len() - 1);
+    if table.len() == 1 {
+        return Some(table[0]);
+    }
+    let (low, high) = (table[upper - 1], table[upper]);
+    let fraction = (key - low[0]) / (high[0] - low[0]);
+    let mut row = low;
+    for (value, high) in row.iter_mut().zip(high) {
+        *value += (high - *value) * fraction;
+    }
+    Some(row)
+}
+
+/// The NEg of a diet from its NEm, both in Mcal/kg, from Zinn and Shen (1998).
+fn diet_neg(diet_nem: f64) -> f64 {
+    0.877 * diet_nem - 0.41
+}
+
+fn grams_per_day(grams: f64) -> MassRate {
+    MassRate::kilograms_per_day(grams / 1000.0)
+}
+
+/// How much of a nutrient a ration supplies against an animal's requirement.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NutrientBalance {
+    pub name: &'static str,
+    /// Unit of `required` and `supplied`, e.g. `Mcal/day`.
+    pub unit: &'static str,
+    pub required: f64,
+    pub supplied: f64,
+}
+
+impl NutrientBalance {
+    /// The surplus supplied, or a deficit if negative.
+    pub fn surplus(&self) -> f64 {
+        self.supplied - self.required
+    }
+
+    /// The amount supplied as a percentage of the requirement.
+    pub fn percent_of_requirement(&self) -> f64 {
+        self.supplied / self.required * 100.0
+    }
+
+    pub fn is_met(&self) -> bool {
+        self.surplus() >= 0.0
+    }
+}
+
+/// Compare a ration against an animal's requirements, nutrient by nutrient.
+///
+/// For cattle, the ration's NEm first covers the maintenance requirement, and the dry matter
+/// left over supplies NEg. For other species the ration's energy is estimated from the TDN of
+/// its feeds.
+///
+/// # Arguments
+/// - `requirements`: The animal's daily requirements.
+/// - `ration`: Each feed in the ration, with the amount fed as fed per head per day.
+///
+/// # Returns
+/// The balance of energy, crude protein, calcium and phosphorus, or an error if the ration is
+/// empty or has a negative amount of a feed.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::feed::library::FeedLibrary;
+/// use livestock_rs::calculators::feed::requirements::{
+///     calculate_requirements, compare_ration, RequirementInputs,
+/// };
+/// use livestock_rs::types::LivestockType;
+/// use livestock_rs::units::{Mass, MassRate};
+///
+/// let inputs = RequirementInputs {
+///     days_pregnant: Some(120),
+///     ..RequirementInputs::new(Mass::kilograms(60.0), MassRate::kilograms_per_day(0.0))
+/// };
+/// let requirements = calculate_requirements(LivestockType::Goat, &inputs).unwrap();
+///
+/// let library = FeedLibrary::builtin();
+/// let ration = [(library.get("grass hay").unwrap().clone(), MassRate::kilograms_per_day(1.5))];
+/// let balances = compare_ration(&requirements, &ration).unwrap();
+/// let energy = &balances[0];
+/// assert_eq!(energy.name, "ME");
+/// assert!(!energy.is_met());
+/// ```
+pub fn compare_ration(
+    requirements: &Requirements,
+    ration: &[(Feed, MassRate)],
+) -> Result<Vec<NutrientBalance>> {
+    ensure!(!ration.is_empty(), "A ration must have at least one feed.");
+    ensure!(
+        ration
+            .iter()
+            .all(|(_, as_fed)| as_fed.as_kilograms_per_day() >= 0.0),
+        "The amount of each feed must not be negative."
+    );
+
+    let dry_matter: Vec<(&Feed, f64)> = ration
+        .iter()
+        .map(|(feed, as_fed)| (feed, feed.to_dry_matter(*as_fed).as_kilograms_per_day()))
+        .collect();
+    let dmi: f64 = dry_matter.iter().map(|(_, amount)| amount).sum();
+    ensure!(dmi > 0.0, "A ration must supply some dry matter.");
+    let supplied = |nutrient: fn(&Feed) -> f64| -> f64 {
+        dry_matter
+            .iter()
+            .map(|(feed, amount)| nutrient(feed) * amount)
+            .sum()
+    };
+    let grams = |percent: f64| percent / 100.0 * 1000.0;
+    let balance = |name, unit, required, supplied| NutrientBalance {
+        name,
+        unit,
+        required,
+        supplied,
+    };
+
+    let mut balances = match requirements.energy {
+        EnergyRequirement::Net {
+            maintenance, gain, ..
+        } => {
+            let nem = supplied(|feed| feed.nem);
+            let neg = supplied(|feed| feed.neg);
+            // Dry matter is used for maintenance first, at the ration's average NEm.
+            let for_maintenance = maintenance / (nem / dmi);
+            let left_for_gain = (1.0 - for_maintenance / dmi).max(0.0);
+            vec![
+                balance("NEm", "Mcal/day", maintenance, nem),
+                balance("NEg", "Mcal/day", gain, neg * left_for_gain),
+            ]
+        }
+        EnergyRequirement::Metabolizable { daily, .. } => vec![balance(
+            "ME",
+            "Mcal/day",
+            daily,
+            supplied(Feed::metabolizable_energy),
+        )],
+        EnergyRequirement::Digestible { daily, .. } => vec![balance(
+            "DE",
+            "Mcal/day",
+            daily,
+            supplied(Feed::digestible_energy),
+        )],
+    };
+    balances.extend([
+        balance(
+            "Crude Protein",
+            "g/day",
+            requirements.crude_protein.as_kilograms_per_day() * 1000.0,
+            grams(supplied(|feed| feed.crude_protein)),
+        ),
+        balance(
+            "Calcium",
+            "g/day",
+            requirements.calcium.as_kilograms_per_day() * 1000.0,
+            grams(supplied(|feed| feed.calcium)),
+        ),
+        balance(
+            "Phosphorus",
+            "g/day",
+            requirements.phosphorus.as_kilograms_per_day() * 1000.0,
+            grams(supplied(|feed| feed.phosphorus)),
+        ),
+    ]);
+
+    Ok(balances)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculators::feed::library::FeedLibrary;
+
+    fn requirements(weight: f64, adg: f64) -> Result<Requirements> {
+        let inputs =
+            RequirementInputs::new(Mass::kilograms(weight), MassRate::kilograms_per_day(adg));
+        calculate_requirements(LivestockType::Cattle, &inputs)
+    }
+
+    fn net_energy(requirements: &Requirements) -> (f64, f64, f64, f64) {
+        match requirements.energy {
+            EnergyRequirement::Net {
+                maintenance,
+                gain,
+                diet_nem,
+                diet_neg,
+            } => (maintenance, gain, diet_nem, diet_neg),
+            other => panic!("expected net energy, got {:?}", other),
+        }
+    }
+
+    fn cow(weight: f64, days_pregnant: Option<u32>, milk_yield: Option<f64>) -> Requirements {
+        let inputs = RequirementInputs {
+            days_pregnant,
+            milk_yield: milk_yield.map(MassRate::kilograms_per_day),
+            ..RequirementInputs::new(Mass::kilograms(weight), MassRate::kilograms_per_day(0.0))
+        };
+        calculate_requirements(LivestockType::Cattle, &inputs).unwrap()
+    }
+
+    #[test]
+    fn test_growing_cattle_requirements() {
+        let steer = requirements(300.0, 1.3).unwrap();
+        let (nem, neg, diet_nem, diet_neg) = net_energy(&steer);
+        assert!((nem - 5.3831).abs() < 1e-3);
+        assert!((neg - 4.3172).abs() < 1e-3);
+        assert!((steer.calcium.as_kilograms_per_day() * 1000.0 - 38.34).abs() < 0.05);
+        assert!((steer.phosphorus.as_kilograms_per_day() * 1000.0 - 18.68).abs() < 0.05);
+
+        // The predicted intake on a diet of the required density meets both energy needs.
+        let dmi = steer.dmi.as_kilograms_per_day();
+        assert!((nem / diet_nem + neg / diet_neg - dmi).abs() < 1e-6);
+        assert!(diet_nem > 1.5 && diet_nem < 2.2);
+        assert!((steer.crude_protein.as_kilograms_per_day() / dmi - 0.13).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_growing_cattle_requirements_increase_with_gain() {
+        let slow = requirements(300.0, 0.5).unwrap();
+        let fast = requirements(300.0, 1.5).unwrap();
+        assert_eq!(net_energy(&slow).0, net_energy(&fast).0);
+        assert!(net_energy(&slow).1 < net_energy(&fast).1);
+        assert!(net_energy(&slow).2 < net_energy(&fast).2);
+        assert!(slow.metabolizable_protein < fast.metabolizable_protein);
+        assert!(slow.calcium < fast.calcium);
+
+        // Without gain, the open cow intake equation only has to cover maintenance.
+        let maintenance = requirements(300.0, 0.0).unwrap();
+        let (nem, neg, diet_nem, _) = net_energy(&maintenance);
+        assert_eq!(neg, 0.0);
+        assert!(diet_nem >= MIN_COW_DIET_NEM && diet_nem < net_energy(&slow).2);
+        assert!((nem / diet_nem - maintenance.dmi.as_kilograms_per_day()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cow_requirements() {
+        let open = cow(550.0, None, None);
+        let early = cow(550.0, Some(100), None);
+        let late = cow(550.0, Some(270), None);
+        let lactating = cow(550.0, None, Some(8.0));
+
+        // The fetus needs little until the last trimester.
+        assert!(net_energy(&early).0 - net_energy(&open).0 < 0.2);
+        assert!((net_energy(&late).0 - net_energy(&open).0 - 4.67).abs() < 0.01);
+        // The gravid uterus scales with the expected calf.
+        let small_calf = RequirementInputs {
+            days_pregnant: Some(270),
+            calf_birth_weight: Some(Mass::kilograms(35.0)),
+            ..RequirementInputs::new(Mass::kilograms(550.0), MassRate::kilograms_per_day(0.0))
+        };
+        let small_calf = calculate_requirements(LivestockType::Cattle, &small_calf).unwrap();
+        assert!((net_energy(&small_calf).0 - net_energy(&open).0 - 4.09).abs() < 0.01);
+        assert!(late.calcium > early.calcium);
+        assert!(late.metabolizable_protein > early.metabolizable_protein);
+
+        assert!((net_energy(&lactating).0 - net_energy(&open).0 - 8.0 * 0.736).abs() < 1e-3);
+        assert!(
+            (lactating.calcium.as_kilograms_per_day()
+                - open.calcium.as_kilograms_per_day()
+                - 8.0 * 1.23 / 500.0)
+                .abs()
+                < 1e-9
+        );
+        assert!(net_energy(&lactating).2 > net_energy(&open).2);
+    }
+
+    #[test]
+    fn test_goat_requirements() {
+        let doe = |weight, adg, days_pregnant, milk_yield: Option<f64>| {
+            let inputs = RequirementInputs {
+                days_pregnant,
+                milk_yield: milk_yield.map(MassRate::kilograms_per_day),
+                ..RequirementInputs::new(Mass::kilograms(weight), MassRate::kilograms_per_day(adg))
+            };
+            calculate_requirements(LivestockType::Goat, &inputs).unwrap()
+        };
+        let daily = |requirements: &Requirements| match requirements.energy {
+            EnergyRequirement::Metabolizable { daily, .. } => daily,
+            other => panic!("expected metabolizable energy, got {:?}", other),
+        };
+        let grams = |amount: MassRate| amount.as_kilograms_per_day() * 1000.0;
+
+        // The tabled 50 kg goat.
+        let open = doe(50.0, 0.0, None, None);
+        assert!((daily(&open) - 1.906).abs() < 1e-3);
+        assert!((open.dmi.as_kilograms_per_day() - daily(&open) / 2.0).abs() < 1e-9);
+        assert!((grams(open.crude_protein) - 74.0).abs() < 1e-9);
+        assert!((grams(open.calcium) - 3.0).abs() < 1e-9);
+
+        // Halfway between the 50 and 60 kg rows.
+        let heavier = doe(55.0, 0.0, None, None);
+        assert!((grams(heavier.crude_protein) - 79.5).abs() < 1e-9);
+        assert!((grams(heavier.phosphorus) - 2.1).abs() < 1e-9);
+
+        // Pregnancy adds nothing until the last two months.
+        assert_eq!(daily(&doe(50.0, 0.0, Some(60), None)), daily(&open));
+        let late = doe(50.0, 0.0, Some(120), None);
+        assert!((daily(&late) - daily(&open) - 1.42).abs() < 1e-9);
+        assert!((grams(late.crude_protein) - 74.0 - 82.0).abs() < 1e-9);
+
+        let growing = doe(20.0, 0.1, None, None);
+        assert!((daily(&growing) - 0.10138 * 20f64.powf(0.75) - 0.72).abs() < 1e-9);
+        assert!((grams(growing.crude_protein) - 38.0 - 28.0).abs() < 1e-9);
+
+        let lactating = doe(50.0, 0.0, None, Some(2.0));
+        assert!((daily(&lactating) - daily(&open) - 2.4).abs() < 1e-9);
+        assert!((grams(lactating.calcium) - 3.0 - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sheep_requirements() {
+        let ewe = |weight, adg, days_pregnant, milk_yield: Option<f64>| {
+            let inputs = RequirementInputs {
+                days_pregnant,
+                milk_yield: milk_yield.map(MassRate::kilograms_per_day),
+                ..RequirementInputs::new(Mass::kilograms(weight), MassRate::kilograms_per_day(adg))
+            };
+            calculate_requirements(LivestockType::Sheep, &inputs)
+        };
+        let energy = |requirements: &Requirements| match requirements.energy {
+            EnergyRequirement::Metabolizable { daily, diet } => (daily, diet),
+            other => panic!("expected metabolizable energy, got {:?}", other),
+        };
+        let grams = |amount: MassRate| amount.as_kilograms_per_day() * 1000.0;
+
+        // The tabled 70 kg ewe.
+        let open = ewe(70.0, 0.0, None, None).unwrap();
+        assert_eq!(energy(&open), (2.4, 2.0));
+        assert!((open.dmi.as_kilograms_per_day() - 1.2).abs() < 1e-9);
+        assert!((grams(open.crude_protein) - 113.0).abs() < 1e-9);
+
+        // Halfway between the 60 and 70 kg rows.
+        let lighter = ewe(65.0, 0.0, None, None).unwrap();
+        assert!((energy(&lighter).0 - 2.3).abs() < 1e-9);
+        assert!((grams(lighter.calcium) - 2.4).abs() < 1e-9);
+
+        let early = ewe(70.0, 0.0, Some(60), None).unwrap();
+        assert!((energy(&early).0 - 2.8).abs() < 1e-9);
+        let late = ewe(70.0, 0.0, Some(130), None).unwrap();
+        assert!((energy(&late).0 - 3.8).abs() < 1e-9);
+        assert!((grams(late.crude_protein) - 193.0).abs() < 1e-9);
+        let lactating = ewe(70.0, 0.0, None, Some(2.0)).unwrap();
+        assert!((energy(&lactating).0 - 5.9).abs() < 1e-9);
+
+        let lamb = ewe(40.0, 0.275, None, None).unwrap();
+        assert!((energy(&lamb).0 - 4.4).abs() < 1e-9);
+        assert!((lamb.dmi.as_kilograms_per_day() - 1.6).abs() < 1e-9);
+
+        assert!(ewe(40.0, 0.0, None, None).is_err());
+        assert!(ewe(60.0, 0.3, None, None).is_err());
+        assert!(ewe(70.0, 0.0, Some(160), None).is_err());
+    }
+
+    #[test]
+    fn test_swine_requirements() {
+        let pig = |weight| {
+            let inputs =
+                RequirementInputs::new(Mass::kilograms(weight), MassRate::kilograms_per_day(0.8));
+            calculate_requirements(LivestockType::Swine, &inputs)
+        };
+
+        let grower = pig(60.0).unwrap();
+        let de_intake = 13.162 * (1.0 - (-0.0176f64 * 60.0).exp());
+        match grower.energy {
+            EnergyRequirement::Digestible { daily, diet } => {
+                assert!((daily - de_intake).abs() < 1e-9);
+                assert!((diet - 3.4 / 0.9).abs() < 1e-9);
+            }
+            other => panic!("expected digestible energy, got {:?}", other),
+        }
+        // 15.5% crude protein and 0.50% calcium of the diet as fed.
+        let as_fed = de_intake / 3.4;
+        assert!((grower.crude_protein.as_kilograms_per_day() - 0.155 * as_fed).abs() < 1e-9);
+        assert!((grower.calcium.as_kilograms_per_day() - 0.005 * as_fed).abs() < 1e-9);
+        assert!((grower.dmi.as_kilograms_per_day() - 0.9 * as_fed).abs() < 1e-9);
+
+        // Lighter pigs need a denser diet.
+        let nursery = pig(8.0).unwrap();
+        assert!(
+            nursery.crude_protein.as_kilograms_per_day() / nursery.dmi.as_kilograms_per_day()
+                > grower.crude_protein.as_kilograms_per_day() / grower.dmi.as_kilograms_per_day()
+        );
+
+        assert!(pig(2.0).is_err());
+        assert!(pig(150.0).is_err());
+
+        let sow = |weight, days_pregnant, milk_yield: Option<f64>| {
+            let inputs = RequirementInputs {
+                days_pregnant,
+                milk_yield: milk_yield.map(MassRate::kilograms_per_day),
+                ..RequirementInputs::new(Mass::kilograms(weight), MassRate::kilograms_per_day(0.0))
+            };
+            calculate_requirements(LivestockType::Swine, &inputs)
+        };
+        // Halfway between the 175 and 200 kg gestation rows.
+        let gestating = sow(187.5, Some(80), None).unwrap();
+        assert!((gestating.dmi.as_kilograms_per_day() - 1.86 * 0.9).abs() < 1e-9);
+        assert!((gestating.crude_protein.as_kilograms_per_day() - 0.122 * 1.86).abs() < 1e-9);
+        assert!((gestating.calcium.as_kilograms_per_day() - 0.0075 * 1.86).abs() < 1e-9);
+        assert!(sow(250.0, Some(80), None).is_err());
+
+        // 8 kg of milk grows the litter 2 kg a day.
+        let lactating = sow(200.0, None, Some(8.0)).unwrap();
+        match lactating.energy {
+            EnergyRequirement::Digestible { daily, .. } => {
+                assert!((daily - 5.35 * 3.4).abs() < 1e-9)
+            }
+            other => panic!("expected digestible energy, got {:?}", other),
+        }
+        assert!((lactating.crude_protein.as_kilograms_per_day() - 0.184 * 5.35).abs() < 1e-9);
+        assert!(sow(200.0, None, Some(14.0)).is_err());
+    }
+
+    #[test]
+    fn test_horse_requirements() {
+        let gelding =
+            RequirementInputs::new(Mass::kilograms(500.0), MassRate::kilograms_per_day(0.0));
+        let horse = calculate_requirements(LivestockType::Horse, &gelding).unwrap();
+        match horse.energy {
+            EnergyRequirement::Digestible { daily, diet } => {
+                assert!((daily - 16.65).abs() < 1e-9);
+                assert!((diet - 16.65 / 10.0).abs() < 1e-9);
+            }
+            other => panic!("expected digestible energy, got {:?}", other),
+        }
+        assert!((horse.crude_protein.as_kilograms_per_day() - 0.63).abs() < 1e-9);
+        assert!((horse.calcium.as_kilograms_per_day() - 0.020).abs() < 1e-9);
+        assert!((horse.phosphorus.as_kilograms_per_day() - 0.014).abs() < 1e-9);
+        assert_eq!(horse.metabolizable_protein, None);
+
+        let daily = |requirements: &Requirements| match requirements.energy {
+            EnergyRequirement::Digestible { daily, .. } => daily,
+            other => panic!("expected digestible energy, got {:?}", other),
+        };
+
+        // Mares need only maintenance until the 9th month, then more each month.
+        let pregnant = |days| {
+            let inputs = RequirementInputs {
+                days_pregnant: Some(days),
+                ..gelding
+            };
+            calculate_requirements(LivestockType::Horse, &inputs).unwrap()
+        };
+        assert!((daily(&pregnant(200)) - 16.65).abs() < 1e-9);
+        assert!((daily(&pregnant(250)) - 16.65 * 1.11).abs() < 1e-9);
+        let eleventh_month = pregnant(320);
+        assert!((daily(&eleventh_month) - 16.65 * 1.20).abs() < 1e-9);
+        assert!(
+            (eleventh_month.crude_protein.as_kilograms_per_day() - 0.044 * 16.65 * 1.20).abs()
+                < 1e-9
+        );
+        assert!((eleventh_month.calcium.as_kilograms_per_day() - 0.037).abs() < 1e-9);
+
+        let mare = RequirementInputs {
+            milk_yield: Some(MassRate::kilograms_per_day(15.0)),
+            ..gelding
+        };
+        let mare = calculate_requirements(LivestockType::Horse, &mare).unwrap();
+        assert!((daily(&mare) - 16.65 - 0.792 * 15.0).abs() < 1e-9);
+        assert!((mare.crude_protein.as_kilograms_per_day() - 0.72 - 0.75).abs() < 1e-9);
+        assert!((mare.calcium.as_kilograms_per_day() - 0.020 - 0.036).abs() < 1e-9);
+
+        // A yearling's gain needs its age.
+        let growing = |months: f64| {
+            let inputs = RequirementInputs {
+                age: Some(Duration::days(months * 30.4)),
+                ..RequirementInputs::new(Mass::kilograms(350.0), MassRate::kilograms_per_day(0.5))
+            };
+            calculate_requirements(LivestockType::Horse, &inputs)
+        };
+        let yearling = growing(12.0).unwrap();
+        let energy = 0.0565 * 12f64.powf(-0.145) * 350.0 + (1.99 + 14.52 - 3.024) * 0.5;
+        assert!((daily(&yearling) - energy).abs() < 1e-9);
+        // 20% protein in 500 g of gain, at 30% efficiency and 79% digestibility.
+        let crude_protein = 0.504 + 0.5 * 0.20 / 0.30 / 0.79;
+        assert!((yearling.crude_protein.as_kilograms_per_day() - crude_protein).abs() < 1e-9);
+        assert!(growing(2.0).is_err());
+        let no_age =
+            RequirementInputs::new(Mass::kilograms(350.0), MassRate::kilograms_per_day(0.5));
+        assert!(calculate_requirements(LivestockType::Horse, &no_age).is_err());
+    }
+
+    #[test]
+    fn test_compare_ration() {
+        let library = FeedLibrary::builtin();
+        let feed = |name: &str, as_fed: f64| {
+            (
+                library.get(name).unwrap().clone(),
+                MassRate::kilograms_per_day(as_fed),
+            )
+        };
+        let steer = requirements(300.0, 1.3).unwrap();
+
+        // Corn silage alone is not dense enough to grow a steer at 1.3 kg/day.
+        let balances = compare_ration(&steer, &[feed("corn silage", 19.4)]).unwrap();
+        let names: Vec<&str> = balances.iter().map(|balance| balance.name).collect();
+        assert_eq!(
+            names,
+            ["NEm", "NEg", "Crude Protein", "Calcium", "Phosphorus"]
+        );
+        assert!(balances[0].is_met());
+        assert!(!balances[1].is_met());
+        assert!(!balances[2].is_met());
+        let silage = library.get("corn silage").unwrap();
+        let dmi = 19.4 * 0.35;
+        assert!((balances[0].supplied - dmi * silage.nem).abs() < 1e-9);
+        assert!((balances[2].supplied - dmi * 80.0).abs() < 1e-9);
+
+        // Adding corn and soybean meal makes up the energy and protein.
+        let balances = compare_ration(
+            &steer,
+            &[
+                feed("corn silage", 8.0),
+                feed("corn grain", 4.0),
+                feed("soybean meal", 1.0),
+                feed("limestone", 0.08),
+            ],
+        )
+        .unwrap();
+        assert!(
+            balances.iter().all(NutrientBalance::is_met),
+            "{:?}",
+            balances
+        );
+        assert!(balances[0].percent_of_requirement() > 100.0);
+
+        assert!(compare_ration(&steer, &[]).is_err());
+        assert!(compare_ration(&steer, &[feed("corn grain", -1.0)]).is_err());
+    }
+
+    #[test]
+    fn test_requirements_invalid() {
+        assert!(requirements(300.0, 3.5).is_err());
+        assert!(requirements(0.0, 1.0).is_err());
+        assert!(requirements(300.0, -1.0).is_err());
+
+        let inputs =
+            RequirementInputs::new(Mass::kilograms(30.0), MassRate::kilograms_per_day(0.2));
+        assert!(calculate_requirements(LivestockType::Chicken, &inputs).is_err());
+
+        let inputs = RequirementInputs {
+            days_pregnant: Some(300),
+            ..RequirementInputs::new(Mass::kilograms(550.0), MassRate::kilograms_per_day(0.0))
+        };
+        assert!(calculate_requirements(LivestockType::Cattle, &inputs).is_err());
+        let inputs = RequirementInputs {
+            days_pregnant: None,
+            milk_yield: Some(MassRate::kilograms_per_day(-1.0)),
+            ..inputs
+        };
+        assert!(calculate_requirements(LivestockType::Goat, &inputs).is_err());
+
+        let inputs = RequirementInputs {
+            calf_birth_weight: Some(Mass::kilograms(0.0)),
+            ..RequirementInputs::new(Mass::kilograms(550.0), MassRate::kilograms_per_day(0.0))
+        };
+        assert!(calculate_requirements(LivestockType::Cattle, &inputs).is_err());
+    }
+}
@@ -0,0 +1,257 @@
+use crate::calculators::stats::multiple_regression;
+use crate::units::{Mass, MassRate};
+use anyhow::{ensure, Context, Result};
+
+/// The intake and performance of one animal over a feed efficiency test.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RfiRecord {
+    /// An identifier for the animal, e.g. its tag.
+    pub id: String,
+    /// Average daily dry matter intake (DMI) over the test.
+    pub dmi: MassRate,
+    /// Body weight at the middle of the test.
+    pub mid_test_weight: Mass,
+    /// Average daily gain over the test.
+    pub adg: MassRate,
+}
+
+/// The residual feed intake of one animal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimalRfi {
+    pub id: String,
+    /// The intake predicted from the animal's size and gain.
+    pub expected_dmi: MassRate,
+    /// Actual minus expected intake. Negative values are more efficient.
+    pub rfi: MassRate,
+    /// Rank within the group, where 1 is the most efficient animal.
+    pub rank: usize,
+}
+
+/// The residual feed intake of a test group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupRfi {
+    /// The expected-intake regression, in kg/day, is:
+    ///
+    /// `expected DMI = intercept + metabolic_weight_coefficient * MMWT + adg_coefficient * ADG`
+    ///
+    /// where `MMWT` is the mid-test metabolic weight in kg^0.75 and `ADG` is in kg/day.
+    pub intercept: f64,
+    pub metabolic_weight_coefficient: f64,
+    pub adg_coefficient: f64,
+    /// Coefficient of determination of the regression, from `0.0` to `1.0`.
+    pub r_squared: f64,
+    /// Each animal's residual feed intake, in the order the records were given.
+    pub animals: Vec<AnimalRfi>,
+}
+
+/// Calculate residual feed intake (RFI) across a contemporary test group.
+///
+/// Unlike FCR, RFI does not penalize large, fast-growing animals. Each animal's dry matter
+/// intake is compared with the intake expected for its size and gain, from a regression fitted
+/// across the group:
+///
+/// `DMI = b0 + b1 * MMWT + b2 * ADG + RFI`
+///
+/// where `MMWT` is the mid-test metabolic body weight, `mid_test_weight^0.75` in kg.
+///
+/// # Arguments
+/// - `records`: The intake and performance of each animal in the group, at least four and
+///   ideally many more.
+///
+/// # Returns
+/// The fitted regression and each animal's RFI and rank.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::feed::rfi::{calculate_rfi, RfiRecord};
+/// use livestock_rs::units::{Mass, MassRate};
+///
+/// let records: Vec<RfiRecord> = [
+///     ("101", 9.8, 420.0, 1.60),
+///     ("102", 10.9, 455.0, 1.75),
+///     ("103", 9.1, 410.0, 1.55),
+///     ("104", 11.6, 470.0, 1.85),
+///     ("105", 10.0, 440.0, 1.50),
+///     ("106", 10.4, 430.0, 1.80),
+/// ]
+/// .iter()
+/// .map(|(id, dmi, weight, adg)| RfiRecord {
+///     id: id.to_string(),
+///     dmi: MassRate::kilograms_per_day(*dmi),
+///     mid_test_weight: Mass::kilograms(*weight),
+///     adg: MassRate::kilograms_per_day(*adg),
+/// })
+/// .collect();
+///
+/// let group = calculate_rfi(&records).unwrap();
+/// let most_efficient = group.animals.iter().find(|animal| animal.rank == 1).unwrap();
+/// assert!(most_efficient.rfi.as_kilograms_per_day() < 0.0);
+///
+/// // Residuals average to zero across the group.
+/// let total: f64 = group.animals.iter().map(|animal| animal.rfi.as_kilograms_per_day()).sum();
+/// assert!(total.abs() < 1e-9);
+/// ```
+pub fn calculate_rfi(records: &[RfiRecord]) -> Result<GroupRfi> {
+    ensure!(
+        records.len() >= 4,
+        "At least 4 animals are needed to calculate RFI."
+    );
+    ensure!(
+        records
+            .iter()
+            .all(|record| record.dmi.as_kilograms_per_day() > 0.0
+                && record.mid_test_weight.as_kilograms() > 0.0),
+        "Intake and weights must be greater than zero."
+    );
+
+    let predictors: Vec<Vec<f64>> = records
+        .iter()
+        .map(|record| {
+            vec![
+                metabolic_weight(record.mid_test_weight),
+                record.adg.as_kilograms_per_day(),
+            ]
+        })
+        .collect();
+    let intakes: Vec<f64> = records
+        .iter()
+        .map(|record| record.dmi.as_kilograms_per_day())
+        .collect();
+    let fit = multiple_regression(&predictors, &intakes)
+        .context("Failed to fit the expected intake regression.")?;
+
+    let residuals: Vec<f64> = predictors
+        .iter()
+        .zip(&intakes)
+        .map(|(row, intake)| intake - fit.predict(row))
+        .collect();
+    let mut order: Vec<usize> = (0..records.len()).collect();
+    order.sort_by(|a, b| residuals[*a].total_cmp(&residuals[*b]));
+    let mut ranks = vec![0; records.len()];
+    for (rank, index) in order.into_iter().enumerate() {
+        ranks[index] = rank + 1;
+    }
+
+    Ok(GroupRfi {
+        intercept: fit.coefficients[0],
+        metabolic_weight_coefficient: fit.coefficients[1],
+        adg_coefficient: fit.coefficients[2],
+        r_squared: fit.r_squared,
+        animals: records
+            .iter()
+            .zip(predictors.iter().zip(residuals))
+            .zip(ranks)
+            .map(|((record, (row, residual)), rank)| AnimalRfi {
+                id: record.id.clone(),
+                expected_dmi: MassRate::kilograms_per_day(fit.predict(row)),
+                rfi: MassRate::kilograms_per_day(residual),
+                rank,
+            })
+            .collect(),
+    })
+}
+
+/// The metabolic body weight, `weight^0.75` in kg.
+fn metabolic_weight(weight: Mass) -> f64 {
+    weight.as_kilograms().powf(0.75)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: &str, dmi: f64, weight: f64, adg: f64) -> RfiRecord {
+        RfiRecord {
+            id: id.to_string(),
+            dmi: MassRate::kilograms_per_day(dmi),
+            mid_test_weight: Mass::kilograms(weight),
+            adg: MassRate::kilograms_per_day(adg),
+        }
+    }
+
+    #[test]
+    fn test_calculate_rfi() {
+        // Intakes lie on 1 + 0.1 * MMWT + 2 * ADG, except two animals eating 0.5 kg more and
+        // less than expected.
+        let animals = [
+            ("a", 400.0, 1.5, 0.0),
+            ("b", 450.0, 1.7, 0.5),
+            ("c", 380.0, 1.4, 0.0),
+            ("d", 500.0, 1.9, -0.5),
+            ("e", 420.0, 1.2, 0.0),
+            ("f", 470.0, 1.6, 0.0),
+        ];
+        let records: Vec<RfiRecord> = animals
+            .iter()
+            .map(|(id, weight, adg, residual)| {
+                let mmwt = f64::powf(*weight, 0.75);
+                record(id, 1.0 + 0.1 * mmwt + 2.0 * adg + residual, *weight, *adg)
+            })
+            .collect();
+
+        let group = calculate_rfi(&records).unwrap();
+        let rfi: Vec<f64> = group
+            .animals
+            .iter()
+            .map(|animal| animal.rfi.as_kilograms_per_day())
+            .collect();
+        assert!(rfi.iter().sum::<f64>().abs() < 1e-9);
+        assert!(group.r_squared > 0.0 && group.r_squared < 1.0);
+
+        // The inefficient animal ranks last and the efficient one first.
+        assert_eq!(group.animals[1].rank, 6);
+        assert_eq!(group.animals[3].rank, 1);
+        assert!(rfi[1] > 0.0 && rfi[3] < 0.0);
+
+        let mut ranks: Vec<usize> = group.animals.iter().map(|animal| animal.rank).collect();
+        ranks.sort();
+        assert_eq!(ranks, vec![1, 2, 3, 4, 5, 6]);
+        for (animal, record) in group.animals.iter().zip(&records) {
+            assert_eq!(animal.id, record.id);
+            assert!(
+                ((animal.expected_dmi.as_kilograms_per_day() + animal.rfi.as_kilograms_per_day())
+                    - record.dmi.as_kilograms_per_day())
+                .abs()
+                    < 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn test_calculate_rfi_exact_fit() {
+        let records: Vec<RfiRecord> = [(400.0, 1.5), (450.0, 1.7), (380.0, 1.4), (500.0, 1.2)]
+            .iter()
+            .enumerate()
+            .map(|(i, (weight, adg))| {
+                let dmi = 1.0 + 0.1 * f64::powf(*weight, 0.75) + 2.0 * adg;
+                record(&i.to_string(), dmi, *weight, *adg)
+            })
+            .collect();
+
+        let group = calculate_rfi(&records).unwrap();
+        assert!((group.intercept - 1.0).abs() < 1e-6);
+        assert!((group.metabolic_weight_coefficient - 0.1).abs() < 1e-6);
+        assert!((group.adg_coefficient - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_calculate_rfi_invalid() {
+        let records = vec![
+            record("a", 10.0, 400.0, 1.5),
+            record("b", 11.0, 450.0, 1.7),
+            record("c", 9.0, 380.0, 1.4),
+        ];
+        assert!(calculate_rfi(&records).is_err());
+
+        let mut records = records;
+        records.push(record("d", 0.0, 500.0, 1.9));
+        assert!(calculate_rfi(&records).is_err());
+
+        // Every animal gained the same, so ADG cannot be separated from the intercept.
+        let records: Vec<RfiRecord> = [400.0, 450.0, 380.0, 500.0]
+            .iter()
+            .map(|weight| record("x", weight / 40.0, *weight, 1.5))
+            .collect();
+        assert!(calculate_rfi(&records).is_err());
+    }
+}
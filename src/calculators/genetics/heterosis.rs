@@ -0,0 +1,311 @@
+use crate::breeds::{Breed, BreedComposition};
+use crate::types::LivestockType;
+use anyhow::{ensure, Result};
+
+/// A trait with published heterosis estimates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HeterosisTrait {
+    /// Proportion of exposed females giving birth, e.g. calving or lambing rate.
+    BirthRate,
+    /// Survival of offspring from birth to weaning.
+    Survival,
+    BirthWeight,
+    WeaningWeight,
+    YearlingWeight,
+    /// Length of the productive life of the dam.
+    Longevity,
+}
+
+/// The expected heterosis advantage for a single trait.
+#[derive(Debug, PartialEq)]
+pub struct TraitHeterosis {
+    pub heterosis_trait: HeterosisTrait,
+    /// Improvement over the parental average from the offspring's own heterosis, in percent.
+    pub individual: f64,
+    /// Improvement over the parental average from the dam's heterosis, in percent.
+    pub maternal: f64,
+    /// Combined improvement over the parental average, in percent.
+    pub total: f64,
+}
+
+/// The expected heterosis of a mating.
+#[derive(Debug, PartialEq)]
+pub struct Heterosis {
+    /// Individual heterosis of the offspring, as a percentage of the F1 level.
+    pub individual: f64,
+    /// Maternal heterosis expressed by the dam, as a percentage of the F1 level.
+    pub maternal: f64,
+    /// Expected trait advantages, empty for species without published estimates.
+    pub traits: Vec<TraitHeterosis>,
+}
+
+/// Calculate expected individual and maternal heterosis for a mating.
+///
+/// Individual heterosis is the chance that the two alleles an offspring inherits at a locus
+/// come from different breeds. Maternal heterosis is the dam's own individual heterosis, which
+/// depends on her parents rather than her composition: an F1 dam has 100%, but a dam of the
+/// same composition from an interbred composite only 50%. Calculate it from the dam's parents
+/// with [`individual_heterosis`], or for composite dams with [`composite_retained_heterosis`].
+///
+/// # Arguments
+/// - `sire`: Breed composition of the sire.
+/// - `dam`: Breed composition of the dam.
+/// - `dam_heterosis`: The dam's own individual heterosis in percent.
+///
+/// # Returns
+/// The expected heterosis of the offspring and dam, with the advantage expected for each trait
+/// that has published estimates for the species.
+///
+/// # Example
+/// ```
+/// use livestock_rs::breeds::{BreedComposition, CattleBreed};
+/// use livestock_rs::calculators::genetics::heterosis::{calculate_heterosis, individual_heterosis};
+/// use std::str::FromStr;
+///
+/// // An Angus bull on F1 Hereford x Angus cows.
+/// let sire = BreedComposition::purebred(CattleBreed::Angus);
+/// let dam = BreedComposition::from_str("1/2 AN 1/2 HH").unwrap();
+/// let dam_heterosis = individual_heterosis(
+///     &BreedComposition::purebred(CattleBreed::Hereford),
+///     &BreedComposition::purebred(CattleBreed::Angus),
+/// );
+///
+/// let heterosis = calculate_heterosis(&sire, &dam, dam_heterosis).unwrap();
+/// assert_eq!(heterosis.individual, 50.0);
+/// assert_eq!(heterosis.maternal, 100.0);
+/// ```
+///
+/// # Notes
+/// - Trait estimates are from Cundiff and Gregory (1999) for cattle and Nitter (1978) for sheep.
+pub fn calculate_heterosis<B: Breed + Copy + Ord>(
+    sire: &BreedComposition<B>,
+    dam: &BreedComposition<B>,
+    dam_heterosis: f64,
+) -> Result<Heterosis> {
+    let species = sire.breeds()[0].0.species();
+    ensure!(
+        sire.breeds()
+            .iter()
+            .chain(dam.breeds().iter())
+            .all(|(breed, _)| breed.species() == species),
+        "Sire and dam must be the same species."
+    );
+
+    ensure!(
+        (0.0..=100.0).contains(&dam_heterosis),
+        "Dam heterosis must be between 0 and 100 percent."
+    );
+    let maternal = dam_heterosis;
+    let individual = individual_heterosis(sire, dam);
+
+    let traits = HETEROSIS_ESTIMATES
+        .iter()
+        .filter(|(estimate_species, ..)| *estimate_species == species)
+        .map(
+            |(_, heterosis_trait, individual_estimate, maternal_estimate)| {
+                let individual = individual_estimate * individual / 100.0;
+                let maternal = maternal_estimate * maternal / 100.0;
+                TraitHeterosis {
+                    heterosis_trait: *heterosis_trait,
+                    individual,
+                    maternal,
+                    total: individual + maternal,
+                }
+            },
+        )
+        .collect();
+
+    Ok(Heterosis {
+        individual,
+        maternal,
+        traits,
+    })
+}
+
+/// Calculate the individual heterosis of the offspring of a sire and dam, in percent.
+///
+/// # Example
+/// ```
+/// use livestock_rs::breeds::{BreedComposition, CattleBreed};
+/// use livestock_rs::calculators::genetics::heterosis::individual_heterosis;
+///
+/// let sire = BreedComposition::purebred(CattleBreed::Angus);
+/// let dam = BreedComposition::purebred(CattleBreed::Hereford);
+/// assert_eq!(individual_heterosis(&sire, &dam), 100.0);
+/// ```
+pub fn individual_heterosis<B: Breed + Copy + Ord>(
+    sire: &BreedComposition<B>,
+    dam: &BreedComposition<B>,
+) -> f64 {
+    let shared: f64 = sire
+        .breeds()
+        .iter()
+        .map(|(breed, fraction)| fraction * dam.fraction(*breed))
+        .sum();
+
+    (1.0 - shared) * 100.0
+}
+
+/// Calculate the heterosis retained by an interbred composite, in percent.
+///
+/// Once animals of a composite are mated to each other, heterosis settles at `1 - Σp²`, where
+/// `p` is the fraction of each breed in the composite.
+///
+/// # Example
+/// ```
+/// use livestock_rs::breeds::{BreedComposition, CattleBreed};
+/// use livestock_rs::calculators::genetics::heterosis::composite_retained_heterosis;
+/// use std::str::FromStr;
+///
+/// let brangus = BreedComposition::<CattleBreed>::from_str("5/8 AN 3/8 BR").unwrap();
+/// assert_eq!(composite_retained_heterosis(&brangus), 46.875);
+/// ```
+pub fn composite_retained_heterosis<B: Breed + Copy + Ord>(
+    composition: &BreedComposition<B>,
+) -> f64 {
+    individual_heterosis(composition, composition)
+}
+
+/// Calculate the heterosis retained by a rotational crossbreeding system, in percent.
+///
+/// Once a rotation using a different breed of sire each generation has stabilized, calves
+/// retain `(2ⁿ - 2) / (2ⁿ - 1)` of F1 heterosis, where `n` is the number of breeds.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::genetics::heterosis::rotational_retained_heterosis;
+///
+/// let two_breed = rotational_retained_heterosis(2).unwrap();
+/// assert_eq!(format!("{:.1}", two_breed), "66.7");
+/// ```
+pub fn rotational_retained_heterosis(breeds: u32) -> Result<f64> {
+    ensure!(breeds >= 2, "A rotation needs at least 2 breeds.");
+    ensure!(breeds <= 16, "A rotation can use at most 16 breeds.");
+
+    let generations = 2f64.powi(breeds as i32);
+    Ok((generations - 2.0) / (generations - 1.0) * 100.0)
+}
+
+/// Published heterosis estimates as the percent improvement of an F1 over the parental average,
+/// from individual and maternal heterosis respectively.
+///
+/// Sources:
+/// - Cattle: Cundiff, L.V. and Gregory, K.E. (1999), as summarized in the BIF Guidelines.
+/// - Sheep: Nitter, G. (1978), Breed utilization for meat production in sheep.
+const HETEROSIS_ESTIMATES: &[(LivestockType, HeterosisTrait, f64, f64)] = &[
+    (LivestockType::Cattle, HeterosisTrait::BirthRate, 3.2, 3.5),
+    (LivestockType::Cattle, HeterosisTrait::Survival, 1.4, 0.8),
+    (LivestockType::Cattle, HeterosisTrait::BirthWeight, 3.9, 1.8),
+    (
+        LivestockType::Cattle,
+        HeterosisTrait::WeaningWeight,
+        5.0,
+        3.9,
+    ),
+    (
+        LivestockType::Cattle,
+        HeterosisTrait::YearlingWeight,
+        3.8,
+        0.0,
+    ),
+    (LivestockType::Cattle, HeterosisTrait::Longevity, 0.0, 16.2),
+    (LivestockType::Sheep, HeterosisTrait::BirthRate, 2.6, 8.7),
+    (LivestockType::Sheep, HeterosisTrait::Survival, 9.8, 2.7),
+    (LivestockType::Sheep, HeterosisTrait::BirthWeight, 3.2, 5.1),
+    (
+        LivestockType::Sheep,
+        HeterosisTrait::WeaningWeight,
+        5.0,
+        6.3,
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::breeds::{AnyBreed, CattleBreed, GoatBreed, SheepBreed};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_individual_heterosis() {
+        let cases = [
+            ("1 AN", "1 AN", 0.0),
+            ("1 AN", "1 HH", 100.0),
+            ("1 AN", "1/2 AN 1/2 HH", 50.0),
+            ("1 BR", "1/2 AN 1/2 HH", 100.0),
+            ("1/2 AN 1/2 HH", "1/2 AN 1/2 HH", 50.0),
+        ];
+
+        for (sire, dam, expected) in cases.iter() {
+            let sire = BreedComposition::<CattleBreed>::from_str(sire).unwrap();
+            let dam = BreedComposition::<CattleBreed>::from_str(dam).unwrap();
+            assert_eq!(individual_heterosis(&sire, &dam), *expected);
+        }
+    }
+
+    #[test]
+    fn test_calculate_heterosis() {
+        let sire = BreedComposition::purebred(CattleBreed::Angus);
+        let dam = BreedComposition::from_str("1/2 AN 1/2 HH").unwrap();
+
+        // An F1 dam, and a dam of the same composition from an interbred composite.
+        let f1 = calculate_heterosis(&sire, &dam, 100.0).unwrap();
+        assert_eq!(f1.individual, 50.0);
+        assert_eq!(f1.maternal, 100.0);
+        let composite =
+            calculate_heterosis(&sire, &dam, composite_retained_heterosis(&dam)).unwrap();
+        assert_eq!(composite.maternal, 50.0);
+
+        let weaning_weight = composite
+            .traits
+            .iter()
+            .find(|t| t.heterosis_trait == HeterosisTrait::WeaningWeight)
+            .unwrap();
+        assert_eq!(weaning_weight.individual, 2.5);
+        assert_eq!(weaning_weight.maternal, 1.95);
+        assert_eq!(weaning_weight.total, 4.45);
+    }
+
+    #[test]
+    fn test_calculate_heterosis_species_tables() {
+        let ram = BreedComposition::purebred(SheepBreed::Dorper);
+        let ewe = BreedComposition::purebred(SheepBreed::Katahdin);
+        let heterosis = calculate_heterosis(&ram, &ewe, 0.0).unwrap();
+        assert_eq!(heterosis.traits.len(), 4);
+        assert!(heterosis
+            .traits
+            .iter()
+            .all(|t| t.heterosis_trait != HeterosisTrait::Longevity));
+
+        let buck = BreedComposition::purebred(GoatBreed::Boer);
+        let doe = BreedComposition::purebred(GoatBreed::Kiko);
+        let heterosis = calculate_heterosis(&buck, &doe, 0.0).unwrap();
+        assert_eq!(heterosis.individual, 100.0);
+        assert!(heterosis.traits.is_empty());
+    }
+
+    #[test]
+    fn test_calculate_heterosis_invalid() {
+        let sire = BreedComposition::purebred(CattleBreed::Angus);
+        let dam = BreedComposition::purebred(CattleBreed::Hereford);
+        assert!(calculate_heterosis(&sire, &dam, 120.0).is_err());
+        assert!(calculate_heterosis(&sire, &dam, -1.0).is_err());
+
+        let sire = BreedComposition::purebred(AnyBreed::Cattle(CattleBreed::Angus));
+        let dam = BreedComposition::purebred(AnyBreed::Goat(GoatBreed::Boer));
+        assert!(calculate_heterosis(&sire, &dam, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_rotational_retained_heterosis() {
+        let cases = [(2, 2.0 / 3.0), (3, 6.0 / 7.0), (4, 14.0 / 15.0)];
+
+        for (breeds, expected) in cases.iter() {
+            let retained = rotational_retained_heterosis(*breeds).unwrap();
+            assert!((retained - expected * 100.0).abs() < 1e-9);
+        }
+
+        assert!(rotational_retained_heterosis(1).is_err());
+        assert!(rotational_retained_heterosis(17).is_err());
+    }
+}
@@ -1,76 +1,557 @@
+use crate::calculators::stats::{linear_regression, t_quantile};
+use crate::types::Date;
+use crate::units::{Duration, Mass, MassRate};
 use anyhow::{ensure, Result};
 
+/// How many residual standard deviations a weigh-in must be from the fitted line to be flagged
+/// as an outlier.
+pub const OUTLIER_THRESHOLD: f64 = 2.0;
+
+/// A weight recorded on a date, such as a row from a scale export.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeighIn {
+    pub date: Date,
+    pub weight: Mass,
+}
+
+impl WeighIn {
+    pub fn new(date: Date, weight: Mass) -> Self {
+        WeighIn { date, weight }
+    }
+}
+
 /// Calculate Average Daily Gain (ADG) for livestock.
 ///
 /// # Arguments
-/// - `initial_weight`: Starting weight of the animal (kg or lbs).
-/// - `final_weight`: Ending weight of the animal (kg or lbs).
-/// - `days`: The number of days in the measurement period.
+/// - `initial_weight`: Starting weight of the animal.
+/// - `final_weight`: Ending weight of the animal.
+/// - `period`: The length of the measurement period.
 ///
 /// # Returns
-/// The average daily gain (ADG) in the same unit as the weight.
+/// The average daily gain (ADG), which can be read in any mass unit per day. Weight loss is an
+/// error, see [`calculate_weight_change`] to record it.
 ///
 /// # Example
 /// ```
 /// use livestock_rs::calculators::growth::adg::calculate_adg;
-/// let adg = calculate_adg(100.0, 150.0, 50).unwrap();
-/// assert_eq!(adg, 1.0); // 1 kg or lb per day
+/// use livestock_rs::units::{Duration, Mass};
+///
+/// let adg = calculate_adg(Mass::kilograms(100.0), Mass::kilograms(150.0), Duration::days(50.0)).unwrap();
+/// assert_eq!(adg.as_kilograms_per_day(), 1.0); // 1 kg per day
 /// ```
-pub fn calculate_adg(initial_weight: f64, final_weight: f64, days: usize) -> Result<f64> {
-    ensure!(days > 0, "Number of days cannot be zero.");
+pub fn calculate_adg(
+    initial_weight: Mass,
+    final_weight: Mass,
+    period: Duration,
+) -> Result<MassRate> {
+    ensure!(
+        period.as_days() > 0.0,
+        "Number of days must be greater than zero."
+    );
     ensure!(
         final_weight > initial_weight,
         "Final weight must be greater than initial weight."
     );
     ensure!(
-        final_weight > 0.0 && initial_weight > 0.0,
+        final_weight.as_kilograms() > 0.0 && initial_weight.as_kilograms() > 0.0,
         "Weights must be greater than zero."
     );
 
-    Ok((final_weight - initial_weight) / days as f64)
+    Ok((final_weight - initial_weight) / period)
+}
+
+/// Calculate Average Daily Gain (ADG) from dated weigh-in records.
+///
+/// The number of days is counted between the earliest and latest weigh-ins, so records can be
+/// given in any order and periods can span month and leap-year boundaries.
+///
+/// # Arguments
+/// - `weigh_ins`: Two or more weigh-ins of the same animal, on different dates.
+///
+/// # Returns
+/// The average daily gain (ADG) between the earliest and latest weigh-in.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::growth::adg::{calculate_adg_from_weigh_ins, WeighIn};
+/// use livestock_rs::types::Date;
+/// use livestock_rs::units::Mass;
+/// use std::str::FromStr;
+///
+/// let weigh_ins = [
+///     WeighIn::new(Date::from_str("2024-02-01").unwrap(), Mass::kilograms(250.0)),
+///     WeighIn::new(Date::from_str("2024-03-02").unwrap(), Mass::kilograms(280.0)),
+/// ];
+///
+/// // 2024 is a leap year, so the animal was weighed 30 days apart.
+/// let adg = calculate_adg_from_weigh_ins(&weigh_ins).unwrap();
+/// assert_eq!(adg.as_kilograms_per_day(), 1.0);
+/// ```
+pub fn calculate_adg_from_weigh_ins(weigh_ins: &[WeighIn]) -> Result<MassRate> {
+    ensure!(
+        weigh_ins.len() >= 2,
+        "At least two weigh-ins are needed to calculate ADG."
+    );
+
+    let first = weigh_ins
+        .iter()
+        .min_by_key(|weigh_in| weigh_in.date)
+        .unwrap();
+    let last = weigh_ins
+        .iter()
+        .max_by_key(|weigh_in| weigh_in.date)
+        .unwrap();
+    ensure!(
+        first.date != last.date,
+        "Weigh-ins must be on at least two different dates."
+    );
+
+    calculate_adg(first.weight, last.weight, last.date - first.date)
+}
+
+/// Whether an animal gained, held or lost weight over a period.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WeightTrend {
+    Gain,
+    /// The daily change is within the maintenance tolerance of zero.
+    Maintenance,
+    Loss,
+}
+
+/// The signed daily change in weight of an animal over a period.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeightChange {
+    /// Change in weight per day, negative when the animal lost weight.
+    pub daily_change: MassRate,
+    pub trend: WeightTrend,
+}
+
+/// Calculate the signed daily weight change of an animal, allowing for weight loss.
+///
+/// Unlike [`calculate_adg`], the final weight may be less than or equal to the initial weight,
+/// e.g. to track animals through drought, illness or late gestation.
+///
+/// # Arguments
+/// - `initial_weight`: Starting weight of the animal.
+/// - `final_weight`: Ending weight of the animal.
+/// - `period`: The length of the measurement period.
+/// - `maintenance_tolerance`: The largest daily change, in either direction, still treated as
+///   maintaining weight.
+///
+/// # Returns
+/// The daily weight change and whether it is a gain, maintenance or loss.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::growth::adg::{calculate_weight_change, WeightTrend};
+/// use livestock_rs::units::{Duration, Mass, MassRate};
+///
+/// let change = calculate_weight_change(
+///     Mass::kilograms(450.0),
+///     Mass::kilograms(420.0),
+///     Duration::days(60.0),
+///     MassRate::kilograms_per_day(0.05),
+/// )
+/// .unwrap();
+/// assert_eq!(change.daily_change.as_kilograms_per_day(), -0.5);
+/// assert_eq!(change.trend, WeightTrend::Loss);
+/// ```
+pub fn calculate_weight_change(
+    initial_weight: Mass,
+    final_weight: Mass,
+    period: Duration,
+    maintenance_tolerance: MassRate,
+) -> Result<WeightChange> {
+    ensure!(
+        period.as_days() > 0.0,
+        "Number of days must be greater than zero."
+    );
+    ensure!(
+        final_weight.as_kilograms() > 0.0 && initial_weight.as_kilograms() > 0.0,
+        "Weights must be greater than zero."
+    );
+    ensure!(
+        maintenance_tolerance.as_kilograms_per_day() >= 0.0,
+        "Maintenance tolerance cannot be negative."
+    );
+
+    let daily_change = (final_weight - initial_weight) / period;
+    let trend = if daily_change.as_kilograms_per_day().abs()
+        <= maintenance_tolerance.as_kilograms_per_day()
+    {
+        WeightTrend::Maintenance
+    } else if daily_change.as_kilograms_per_day() > 0.0 {
+        WeightTrend::Gain
+    } else {
+        WeightTrend::Loss
+    };
+
+    Ok(WeightChange {
+        daily_change,
+        trend,
+    })
+}
+
+/// How far a weigh-in is from the line fitted through a series.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeighInResidual {
+    pub weigh_in: WeighIn,
+    /// Recorded weight minus the fitted weight on the same date.
+    pub residual: Mass,
+    /// The residual divided by the residual standard deviation of the fit.
+    pub standardized: f64,
+    /// Whether the weigh-in is more than `OUTLIER_THRESHOLD` standard deviations from the line,
+    /// e.g. because of unusual gut fill or a recording error.
+    pub outlier: bool,
+}
+
+/// Average Daily Gain (ADG) fitted by linear regression over a series of weigh-ins.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeriesAdg {
+    /// The slope of the fitted line.
+    pub adg: MassRate,
+    /// The fitted weight on `start_date`.
+    pub intercept: Mass,
+    /// The date of the earliest weigh-in.
+    pub start_date: Date,
+    /// Coefficient of determination of the fit, from `0.0` to `1.0`.
+    pub r_squared: f64,
+    /// Standard error of the ADG.
+    pub standard_error: MassRate,
+    /// The confidence level of `confidence_interval`, e.g. `0.95`.
+    pub confidence: f64,
+    /// Lower and upper bounds of the confidence interval for the ADG.
+    pub confidence_interval: (MassRate, MassRate),
+    /// Residuals of each weigh-in, in date order.
+    pub residuals: Vec<WeighInResidual>,
+}
+
+impl SeriesAdg {
+    /// The weigh-ins flagged as outliers.
+    pub fn outliers(&self) -> impl Iterator<Item = &WeighIn> {
+        self.residuals
+            .iter()
+            .filter(|residual| residual.outlier)
+            .map(|residual| &residual.weigh_in)
+    }
+}
+
+/// Calculate Average Daily Gain (ADG) by fitting a least-squares line through every weigh-in.
+///
+/// Weights vary from day to day with gut fill, so the slope through a full series of weigh-ins
+/// is a more reliable measure of gain than the difference between two of them, and is what
+/// performance tests report.
+///
+/// # Arguments
+/// - `weigh_ins`: Three or more weigh-ins of the same animal, on at least two different dates.
+/// - `confidence`: The confidence level of the interval around the ADG, e.g. `0.95`.
+///
+/// # Returns
+/// The fitted ADG, with its goodness of fit, standard error, confidence interval and the residual
+/// of each weigh-in.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::growth::adg::{calculate_series_adg, WeighIn};
+/// use livestock_rs::types::Date;
+/// use livestock_rs::units::Mass;
+/// use std::str::FromStr;
+///
+/// let weigh_ins: Vec<WeighIn> = [
+///     ("2026-01-01", 300.0),
+///     ("2026-01-15", 322.0),
+///     ("2026-01-29", 340.0),
+///     ("2026-02-12", 364.0),
+/// ]
+/// .iter()
+/// .map(|(date, kg)| WeighIn::new(Date::from_str(date).unwrap(), Mass::kilograms(*kg)))
+/// .collect();
+///
+/// let series = calculate_series_adg(&weigh_ins, 0.95).unwrap();
+/// assert_eq!(format!("{:.2}", series.adg.as_kilograms_per_day()), "1.50");
+/// assert!(series.r_squared > 0.99);
+/// assert_eq!(series.outliers().count(), 0);
+/// ```
+pub fn calculate_series_adg(weigh_ins: &[WeighIn], confidence: f64) -> Result<SeriesAdg> {
+    ensure!(
+        confidence > 0.0 && confidence < 1.0,
+        "Confidence must be between 0 and 1."
+    );
+    ensure!(
+        weigh_ins.len() >= 3,
+        "At least three weigh-ins are needed to fit a series."
+    );
+
+    let mut weigh_ins = weigh_ins.to_vec();
+    weigh_ins.sort_by_key(|weigh_in| weigh_in.date);
+    let start_date = weigh_ins[0].date;
+
+    let points: Vec<(f64, f64)> = weigh_ins
+        .iter()
+        .map(|weigh_in| {
+            (
+                (weigh_in.date - start_date).as_days(),
+                weigh_in.weight.as_kilograms(),
+            )
+        })
+        .collect();
+    let fit = linear_regression(&points)?;
+
+    let margin = t_quantile(1.0 - (1.0 - confidence) / 2.0, fit.degrees_of_freedom)
+        * fit.slope_standard_error;
+    let residuals = weigh_ins
+        .iter()
+        .zip(points.iter())
+        .map(|(weigh_in, (days, kilograms))| {
+            let residual = kilograms - fit.predict(*days);
+            let standardized = if fit.residual_standard_deviation > 0.0 {
+                residual / fit.residual_standard_deviation
+            } else {
+                0.0
+            };
+
+            WeighInResidual {
+                weigh_in: *weigh_in,
+                residual: Mass::kilograms(residual),
+                standardized,
+                outlier: standardized.abs() > OUTLIER_THRESHOLD,
+            }
+        })
+        .collect();
+
+    Ok(SeriesAdg {
+        adg: MassRate::kilograms_per_day(fit.slope),
+        intercept: Mass::kilograms(fit.intercept),
+        start_date,
+        r_squared: fit.r_squared,
+        standard_error: MassRate::kilograms_per_day(fit.slope_standard_error),
+        confidence,
+        confidence_interval: (
+            MassRate::kilograms_per_day(fit.slope - margin),
+            MassRate::kilograms_per_day(fit.slope + margin),
+        ),
+        residuals,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
 
     #[test]
     fn test_calculate_adg() {
         let adg_test_cases = [
-            (100.0, 150.0, 50, 1.0),
-            (100.0, 200.0, 100, 1.0),
-            (100.0, 200.0, 50, 2.0),
-            (100.0, 150.0, 100, 0.5),
+            (100.0, 150.0, 50.0, 1.0),
+            (100.0, 200.0, 100.0, 1.0),
+            (100.0, 200.0, 50.0, 2.0),
+            (100.0, 150.0, 100.0, 0.5),
         ];
 
         for (initial_weight, final_weight, days, expected) in adg_test_cases.iter() {
-            let result = calculate_adg(*initial_weight, *final_weight, *days);
+            let result = calculate_adg(
+                Mass::kilograms(*initial_weight),
+                Mass::kilograms(*final_weight),
+                Duration::days(*days),
+            );
             assert!(result.is_ok());
-            assert_eq!(result.unwrap(), *expected);
+            assert_eq!(result.unwrap().as_kilograms_per_day(), *expected);
         }
     }
 
+    #[test]
+    fn test_calculate_adg_mixed_units() {
+        let adg = calculate_adg(
+            Mass::kilograms(100.0),
+            Mass::pounds(330.0),
+            Duration::days(50.0),
+        )
+        .unwrap();
+        assert!((adg.as_kilograms_per_day() - 0.993_709_642).abs() < 1e-9);
+    }
+
     #[test]
     fn test_calculate_adg_zero_days() {
-        let result = calculate_adg(100.0, 150.0, 0);
+        let result = calculate_adg(
+            Mass::kilograms(100.0),
+            Mass::kilograms(150.0),
+            Duration::days(0.0),
+        );
         assert!(result.is_err());
     }
 
     #[test]
     fn test_calculate_adg_negative_weight() {
-        let result = calculate_adg(-100.0, 150.0, 50);
+        let result = calculate_adg(
+            Mass::kilograms(-100.0),
+            Mass::kilograms(150.0),
+            Duration::days(50.0),
+        );
         assert!(result.is_err());
     }
 
     #[test]
     fn test_calculate_adg_negative_final_weight() {
-        let result = calculate_adg(100.0, -150.0, 50);
+        let result = calculate_adg(
+            Mass::kilograms(100.0),
+            Mass::kilograms(-150.0),
+            Duration::days(50.0),
+        );
         assert!(result.is_err());
     }
 
     #[test]
     fn test_calculate_adg_negative_initial_weight() {
-        let result = calculate_adg(-100.0, 150.0, 50);
+        let result = calculate_adg(
+            Mass::kilograms(-100.0),
+            Mass::kilograms(150.0),
+            Duration::days(50.0),
+        );
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+
+    fn weigh_in(date: &str, kilograms: f64) -> WeighIn {
+        WeighIn::new(Date::from_str(date).unwrap(), Mass::kilograms(kilograms))
+    }
+
+    #[test]
+    fn test_calculate_adg_from_weigh_ins() {
+        let cases = [
+            (
+                vec![weigh_in("2026-03-01", 300.0), weigh_in("2026-06-15", 406.0)],
+                1.0,
+            ),
+            (
+                vec![weigh_in("2025-12-20", 200.0), weigh_in("2026-01-09", 230.0)],
+                1.5,
+            ),
+            (
+                vec![weigh_in("2023-02-01", 100.0), weigh_in("2023-03-01", 128.0)],
+                1.0,
+            ),
+            (
+                vec![
+                    weigh_in("2026-02-01", 250.0),
+                    weigh_in("2026-01-01", 220.0),
+                    weigh_in("2026-01-15", 240.0),
+                ],
+                30.0 / 31.0,
+            ),
+        ];
+
+        for (weigh_ins, expected) in cases.iter() {
+            let adg = calculate_adg_from_weigh_ins(weigh_ins).unwrap();
+            assert!((adg.as_kilograms_per_day() - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_calculate_adg_from_weigh_ins_invalid() {
+        let invalid_cases = [
+            vec![],
+            vec![weigh_in("2026-03-01", 300.0)],
+            vec![weigh_in("2026-03-01", 300.0), weigh_in("2026-03-01", 310.0)],
+            vec![weigh_in("2026-03-01", 300.0), weigh_in("2026-04-01", 290.0)],
+        ];
+
+        for weigh_ins in invalid_cases.iter() {
+            assert!(calculate_adg_from_weigh_ins(weigh_ins).is_err());
+        }
+    }
+
+    #[test]
+    fn test_calculate_series_adg() {
+        let weigh_ins = [
+            weigh_in("2026-01-01", 300.0),
+            weigh_in("2026-01-11", 312.0),
+            weigh_in("2026-01-21", 318.0),
+            weigh_in("2026-01-31", 330.0),
+            weigh_in("2026-02-10", 340.0),
+        ];
+
+        let series = calculate_series_adg(&weigh_ins, 0.95).unwrap();
+        assert!((series.adg.as_kilograms_per_day() - 0.98).abs() < 1e-9);
+        assert!((series.intercept.as_kilograms() - 300.4).abs() < 1e-9);
+        assert_eq!(series.start_date, Date::from_str("2026-01-01").unwrap());
+        assert!((series.r_squared - 0.992_148_760).abs() < 1e-9);
+        assert!((series.standard_error.as_kilograms_per_day() - 0.050_332_230).abs() < 1e-9);
+
+        let (low, high) = series.confidence_interval;
+        assert!((low.as_kilograms_per_day() - 0.819_820_382).abs() < 1e-6);
+        assert!((high.as_kilograms_per_day() - 1.140_179_618).abs() < 1e-6);
+        assert_eq!(series.residuals.len(), 5);
+    }
+
+    #[test]
+    fn test_calculate_series_adg_outliers() {
+        let mut weigh_ins: Vec<WeighIn> = (0..10)
+            .map(|week| WeighIn {
+                date: Date::new(2026, 1 + week / 4, 1 + (week % 4) * 7).unwrap(),
+                weight: Mass::kilograms(300.0 + week as f64 * 7.0),
+            })
+            .collect();
+        weigh_ins[5].weight = weigh_ins[5].weight + Mass::kilograms(25.0);
+
+        let series = calculate_series_adg(&weigh_ins, 0.95).unwrap();
+        let outliers: Vec<&WeighIn> = series.outliers().collect();
+        assert_eq!(outliers, vec![&weigh_ins[5]]);
+    }
+
+    #[test]
+    fn test_calculate_series_adg_invalid() {
+        let weigh_ins = [
+            weigh_in("2026-01-01", 300.0),
+            weigh_in("2026-01-11", 312.0),
+            weigh_in("2026-01-21", 318.0),
+        ];
+        assert!(calculate_series_adg(&weigh_ins[..2], 0.95).is_err());
+        assert!(calculate_series_adg(&weigh_ins, 1.0).is_err());
+        assert!(calculate_series_adg(&weigh_ins, 0.0).is_err());
+
+        let same_day = [
+            weigh_in("2026-01-01", 300.0),
+            weigh_in("2026-01-01", 312.0),
+            weigh_in("2026-01-01", 318.0),
+        ];
+        assert!(calculate_series_adg(&same_day, 0.95).is_err());
+    }
+
+    #[test]
+    fn test_calculate_weight_change() {
+        let tolerance = MassRate::kilograms_per_day(0.05);
+        let cases = [
+            (400.0, 430.0, 30.0, 1.0, WeightTrend::Gain),
+            (450.0, 420.0, 60.0, -0.5, WeightTrend::Loss),
+            (400.0, 400.0, 30.0, 0.0, WeightTrend::Maintenance),
+            (400.0, 401.0, 30.0, 1.0 / 30.0, WeightTrend::Maintenance),
+            (400.0, 399.0, 30.0, -1.0 / 30.0, WeightTrend::Maintenance),
+        ];
+
+        for (initial_weight, final_weight, days, expected, trend) in cases.iter() {
+            let change = calculate_weight_change(
+                Mass::kilograms(*initial_weight),
+                Mass::kilograms(*final_weight),
+                Duration::days(*days),
+                tolerance,
+            )
+            .unwrap();
+            assert!((change.daily_change.as_kilograms_per_day() - expected).abs() < 1e-12);
+            assert_eq!(change.trend, *trend);
+        }
+    }
+
+    #[test]
+    fn test_calculate_weight_change_invalid() {
+        let tolerance = MassRate::kilograms_per_day(0.05);
+        let weight = Mass::kilograms(400.0);
+        let days = Duration::days(30.0);
+
+        assert!(calculate_weight_change(weight, weight, Duration::days(0.0), tolerance).is_err());
+        assert!(calculate_weight_change(Mass::kilograms(0.0), weight, days, tolerance).is_err());
+        assert!(calculate_weight_change(weight, Mass::kilograms(-1.0), days, tolerance).is_err());
+        assert!(
+            calculate_weight_change(weight, weight, days, MassRate::kilograms_per_day(-0.1))
+                .is_err()
+        );
+    }
+}
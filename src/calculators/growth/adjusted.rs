@@ -0,0 +1,327 @@
+use crate::units::{Duration, Mass};
+use anyhow::{ensure, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+/// Ages, in days, at which weaning weights can be adjusted to 205 days.
+pub const WEANING_AGE_RANGE: RangeInclusive<f64> = 160.0..=250.0;
+
+/// Ages, in days, at which yearling weights can be adjusted to 365 days.
+pub const YEARLING_AGE_RANGE: RangeInclusive<f64> = 320.0..=410.0;
+
+/// The birth weight assumed when a calf was not weighed at birth, in pounds.
+///
+/// Breed associations publish their own standards, which should be passed instead when known.
+pub const STANDARD_BIRTH_WEIGHT_POUNDS: f64 = 70.0;
+
+/// The sex of a calf, which determines its age-of-dam adjustment.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ValueEnum,
+)]
+pub enum CalfSex {
+    Bull,
+    Steer,
+    Heifer,
+}
+
+/// The weight added to a calf's 205-day weight for the age of its dam, from the Beef Improvement
+/// Federation (BIF) guidelines.
+///
+/// Young and old dams milk less than mature cows, so their calves are adjusted upwards. Bulls and
+/// steers share the same factors.
+///
+/// # Arguments
+/// - `dam_age`: Age of the dam in years when the calf was born.
+/// - `sex`: Sex of the calf.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::growth::adjusted::{age_of_dam_adjustment, CalfSex};
+///
+/// let adjustment = age_of_dam_adjustment(2, CalfSex::Heifer).unwrap();
+/// assert_eq!(format!("{:.0}", adjustment.as_pounds()), "54");
+/// ```
+pub fn age_of_dam_adjustment(dam_age: u32, sex: CalfSex) -> Result<Mass> {
+    ensure!(dam_age >= 2, "Dam must be at least 2 years old.");
+
+    let (male, female) = match dam_age {
+        2 => (60.0, 54.0),
+        3 => (40.0, 36.0),
+        4 => (20.0, 18.0),
+        5..=10 => (0.0, 0.0),
+        _ => (20.0, 18.0),
+    };
+
+    Ok(Mass::pounds(match sex {
+        CalfSex::Bull | CalfSex::Steer => male,
+        CalfSex::Heifer => female,
+    }))
+}
+
+/// Calculate a calf's adjusted 205-day weaning weight.
+///
+/// The formula, from the Beef Improvement Federation (BIF) guidelines, is:
+///
+/// `adjusted 205-day weight = (weaning weight - birth weight) / weaning age * 205 + birth weight + age-of-dam adjustment`
+///
+/// # Arguments
+/// - `weaning_weight`: Actual weight of the calf at weaning.
+/// - `weaning_age`: Age of the calf at weaning, from 160 to 250 days.
+/// - `birth_weight`: Actual birth weight, or `None` to use [`STANDARD_BIRTH_WEIGHT_POUNDS`].
+/// - `dam_age`: Age of the dam in years when the calf was born.
+/// - `sex`: Sex of the calf.
+///
+/// # Returns
+/// The weight the calf would have weighed at 205 days of age out of a mature cow.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::growth::adjusted::{calculate_adjusted_205_day_weight, CalfSex};
+/// use livestock_rs::units::{Duration, Mass};
+///
+/// let adjusted = calculate_adjusted_205_day_weight(
+///     Mass::pounds(550.0),
+///     Duration::days(190.0),
+///     Some(Mass::pounds(80.0)),
+///     3,
+///     CalfSex::Bull,
+/// )
+/// .unwrap();
+/// assert_eq!(format!("{:.1}", adjusted.as_pounds()), "627.1");
+/// ```
+pub fn calculate_adjusted_205_day_weight(
+    weaning_weight: Mass,
+    weaning_age: Duration,
+    birth_weight: Option<Mass>,
+    dam_age: u32,
+    sex: CalfSex,
+) -> Result<Mass> {
+    ensure!(
+        WEANING_AGE_RANGE.contains(&weaning_age.as_days()),
+        "Weaning age must be between 160 and 250 days."
+    );
+
+    let birth_weight = birth_weight.unwrap_or(Mass::pounds(STANDARD_BIRTH_WEIGHT_POUNDS));
+    ensure!(
+        birth_weight.as_kilograms() > 0.0,
+        "Birth weight must be greater than zero."
+    );
+    ensure!(
+        weaning_weight > birth_weight,
+        "Weaning weight must be greater than birth weight."
+    );
+
+    let preweaning_gain = (weaning_weight - birth_weight) / weaning_age;
+    Ok(preweaning_gain * Duration::days(205.0)
+        + birth_weight
+        + age_of_dam_adjustment(dam_age, sex)?)
+}
+
+/// Calculate a calf's adjusted 365-day yearling weight.
+///
+/// The formula, from the Beef Improvement Federation (BIF) guidelines, projects the gain since
+/// weaning over the 160 days from 205 to 365 days of age:
+///
+/// `adjusted 365-day weight = (yearling weight - weaning weight) / days between weighings * 160 + adjusted 205-day weight`
+///
+/// # Arguments
+/// - `yearling_weight`: Actual weight of the animal as a yearling.
+/// - `yearling_age`: Age of the animal when weighed as a yearling, from 320 to 410 days.
+/// - `weaning_weight`: Actual weight of the calf at weaning.
+/// - `weaning_age`: Age of the calf at weaning.
+/// - `adjusted_205_day_weight`: The calf's adjusted weaning weight, see
+///   [`calculate_adjusted_205_day_weight`].
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::growth::adjusted::calculate_adjusted_365_day_weight;
+/// use livestock_rs::units::{Duration, Mass};
+///
+/// let adjusted = calculate_adjusted_365_day_weight(
+///     Mass::pounds(1000.0),
+///     Duration::days(370.0),
+///     Mass::pounds(550.0),
+///     Duration::days(190.0),
+///     Mass::pounds(627.1),
+/// )
+/// .unwrap();
+/// assert_eq!(format!("{:.1}", adjusted.as_pounds()), "1027.1");
+/// ```
+pub fn calculate_adjusted_365_day_weight(
+    yearling_weight: Mass,
+    yearling_age: Duration,
+    weaning_weight: Mass,
+    weaning_age: Duration,
+    adjusted_205_day_weight: Mass,
+) -> Result<Mass> {
+    ensure!(
+        YEARLING_AGE_RANGE.contains(&yearling_age.as_days()),
+        "Yearling age must be between 320 and 410 days."
+    );
+    ensure!(
+        yearling_age > weaning_age,
+        "Yearling age must be greater than weaning age."
+    );
+    ensure!(
+        weaning_weight.as_kilograms() > 0.0 && yearling_weight.as_kilograms() > 0.0,
+        "Weights must be greater than zero."
+    );
+
+    let days_between = Duration::days(yearling_age.as_days() - weaning_age.as_days());
+    let postweaning_gain = (yearling_weight - weaning_weight) / days_between;
+    Ok(postweaning_gain * Duration::days(160.0) + adjusted_205_day_weight)
+}
+
+/// Calculate each animal's ratio within its contemporary group.
+///
+/// A ratio is an animal's adjusted weight as a percentage of the group average, so 100 is
+/// average, and ratios can be compared between groups raised in different conditions. Groups
+/// should be calves of the same sex raised together.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::growth::adjusted::calculate_contemporary_ratios;
+/// use livestock_rs::units::Mass;
+///
+/// let weights = [Mass::pounds(550.0), Mass::pounds(600.0), Mass::pounds(650.0)];
+/// let ratios = calculate_contemporary_ratios(&weights).unwrap();
+/// assert_eq!(format!("{:.0} {:.0} {:.0}", ratios[0], ratios[1], ratios[2]), "92 100 108");
+/// ```
+pub fn calculate_contemporary_ratios(adjusted_weights: &[Mass]) -> Result<Vec<f64>> {
+    ensure!(
+        !adjusted_weights.is_empty(),
+        "Contemporary group must not be empty."
+    );
+    ensure!(
+        adjusted_weights
+            .iter()
+            .all(|weight| weight.as_kilograms() > 0.0),
+        "Weights must be greater than zero."
+    );
+
+    let total = adjusted_weights
+        .iter()
+        .fold(Mass::default(), |total, weight| total + *weight);
+    let average = total / adjusted_weights.len() as f64;
+
+    Ok(adjusted_weights
+        .iter()
+        .map(|weight| *weight / average * 100.0)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_age_of_dam_adjustment() {
+        let cases = [
+            (2, CalfSex::Bull, 60.0),
+            (3, CalfSex::Steer, 40.0),
+            (4, CalfSex::Bull, 20.0),
+            (5, CalfSex::Bull, 0.0),
+            (10, CalfSex::Heifer, 0.0),
+            (11, CalfSex::Bull, 20.0),
+            (2, CalfSex::Heifer, 54.0),
+            (3, CalfSex::Heifer, 36.0),
+            (4, CalfSex::Heifer, 18.0),
+            (14, CalfSex::Heifer, 18.0),
+        ];
+
+        for (dam_age, sex, expected) in cases.iter() {
+            let adjustment = age_of_dam_adjustment(*dam_age, *sex).unwrap();
+            assert!((adjustment.as_pounds() - expected).abs() < 1e-9);
+        }
+        assert!(age_of_dam_adjustment(1, CalfSex::Bull).is_err());
+    }
+
+    #[test]
+    fn test_calculate_adjusted_205_day_weight() {
+        let adjusted = calculate_adjusted_205_day_weight(
+            Mass::pounds(500.0),
+            Duration::days(205.0),
+            None,
+            6,
+            CalfSex::Steer,
+        )
+        .unwrap();
+        assert!((adjusted.as_pounds() - 500.0).abs() < 1e-9);
+
+        // (480 - 75) / 180 * 205 + 75 + 54
+        let adjusted = calculate_adjusted_205_day_weight(
+            Mass::pounds(480.0),
+            Duration::days(180.0),
+            Some(Mass::pounds(75.0)),
+            2,
+            CalfSex::Heifer,
+        )
+        .unwrap();
+        assert!((adjusted.as_pounds() - 590.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_calculate_adjusted_205_day_weight_invalid() {
+        let weight = Mass::pounds(500.0);
+        let age = Duration::days(200.0);
+
+        for weaning_age in [159.0, 251.0] {
+            assert!(calculate_adjusted_205_day_weight(
+                weight,
+                Duration::days(weaning_age),
+                None,
+                5,
+                CalfSex::Bull
+            )
+            .is_err());
+        }
+        assert!(
+            calculate_adjusted_205_day_weight(Mass::pounds(60.0), age, None, 5, CalfSex::Bull)
+                .is_err()
+        );
+        assert!(calculate_adjusted_205_day_weight(
+            weight,
+            age,
+            Some(Mass::pounds(0.0)),
+            5,
+            CalfSex::Bull
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_calculate_adjusted_365_day_weight() {
+        // (1000 - 550) / 180 * 160 + 600
+        let adjusted = calculate_adjusted_365_day_weight(
+            Mass::pounds(1000.0),
+            Duration::days(370.0),
+            Mass::pounds(550.0),
+            Duration::days(190.0),
+            Mass::pounds(600.0),
+        )
+        .unwrap();
+        assert!((adjusted.as_pounds() - 1000.0).abs() < 1e-9);
+
+        assert!(calculate_adjusted_365_day_weight(
+            Mass::pounds(1000.0),
+            Duration::days(300.0),
+            Mass::pounds(550.0),
+            Duration::days(190.0),
+            Mass::pounds(600.0),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_calculate_contemporary_ratios() {
+        let ratios =
+            calculate_contemporary_ratios(&[Mass::kilograms(200.0), Mass::kilograms(300.0)])
+                .unwrap();
+        assert!((ratios[0] - 80.0).abs() < 1e-9);
+        assert!((ratios[1] - 120.0).abs() < 1e-9);
+
+        assert!(calculate_contemporary_ratios(&[]).is_err());
+        assert!(calculate_contemporary_ratios(&[Mass::kilograms(0.0)]).is_err());
+    }
+}
@@ -0,0 +1,409 @@
+use crate::calculators::stats::nonlinear_least_squares;
+use crate::types::LivestockType;
+use crate::units::{Duration, Mass};
+use anyhow::{ensure, Context, Result};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// A growth curve model relating weight to age.
+///
+/// Each model has a mature weight `A`, an integration constant `B` fixing the weight at birth,
+/// and a maturing rate `k` per day. In the formulas, `t` is the age in days.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize, ValueEnum)]
+pub enum GrowthModel {
+    /// `W = A * (1 - B * exp(-k * t))`, which has no inflection point.
+    Brody,
+    /// `W = A * (1 - B * exp(-k * t))^3`
+    VonBertalanffy,
+    /// `W = A * exp(-B * exp(-k * t))`
+    Gompertz,
+    /// `W = A / (1 + B * exp(-k * t))`
+    Logistic,
+    /// `W = A * (1 - B * exp(-k * t))^M`, where the shape `M` is also fitted.
+    Richards,
+}
+
+impl GrowthModel {
+    /// Returns every growth model.
+    pub fn all() -> &'static [GrowthModel] {
+        &[
+            GrowthModel::Brody,
+            GrowthModel::VonBertalanffy,
+            GrowthModel::Gompertz,
+            GrowthModel::Logistic,
+            GrowthModel::Richards,
+        ]
+    }
+
+    /// The number of parameters fitted by the model.
+    pub fn parameter_count(&self) -> usize {
+        match self {
+            GrowthModel::Richards => 4,
+            _ => 3,
+        }
+    }
+
+    /// The weight in kilograms predicted by the model at an age in days.
+    fn evaluate(&self, age: f64, parameters: &[f64]) -> f64 {
+        let (mature_weight, scale, rate) = (parameters[0], parameters[1], parameters[2]);
+        let decay = (-rate * age).exp();
+
+        match self {
+            GrowthModel::Brody => mature_weight * (1.0 - scale * decay),
+            GrowthModel::VonBertalanffy => mature_weight * (1.0 - scale * decay).powi(3),
+            GrowthModel::Gompertz => mature_weight * (-scale * decay).exp(),
+            GrowthModel::Logistic => mature_weight / (1.0 + scale * decay),
+            GrowthModel::Richards => mature_weight * (1.0 - scale * decay).powf(parameters[3]),
+        }
+    }
+
+    /// The parameters at which the model passes through the birth weight at age zero and
+    /// approaches the mature weight.
+    fn starting_parameters(&self, defaults: &GrowthDefaults, mature_weight: Mass) -> Vec<f64> {
+        let mature_weight = mature_weight.as_kilograms();
+        let proportion = defaults.birth_weight.as_kilograms() / mature_weight;
+        let rate = defaults.maturing_rate;
+
+        match self {
+            GrowthModel::Brody => vec![mature_weight, 1.0 - proportion, rate],
+            GrowthModel::VonBertalanffy => {
+                vec![mature_weight, 1.0 - proportion.cbrt(), rate]
+            }
+            GrowthModel::Gompertz => vec![mature_weight, -proportion.ln(), rate],
+            GrowthModel::Logistic => vec![mature_weight, 1.0 / proportion - 1.0, rate],
+            GrowthModel::Richards => vec![mature_weight, 1.0 - proportion.cbrt(), rate, 3.0],
+        }
+    }
+}
+
+/// Typical birth weight, mature weight and maturing rate of a type of livestock, used as the
+/// starting point when fitting a growth curve.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GrowthDefaults {
+    pub birth_weight: Mass,
+    pub mature_weight: Mass,
+    /// Approximate maturing rate `k`, per day.
+    pub maturing_rate: f64,
+}
+
+impl From<LivestockType> for GrowthDefaults {
+    fn from(livestock_type: LivestockType) -> Self {
+        let (birth_weight, mature_weight, maturing_rate) = match livestock_type {
+            LivestockType::Cattle => (35.0, 600.0, 0.004),
+            LivestockType::Swine => (1.4, 250.0, 0.01),
+            LivestockType::Chicken => (0.04, 3.5, 0.03),
+            LivestockType::Rabbit => (0.06, 4.5, 0.025),
+            LivestockType::Sheep => (4.5, 70.0, 0.01),
+            LivestockType::Goat => (3.5, 55.0, 0.008),
+            LivestockType::Horse => (45.0, 500.0, 0.004),
+            LivestockType::Donkey => (25.0, 250.0, 0.004),
+            LivestockType::Camel => (35.0, 550.0, 0.0025),
+            LivestockType::Reindeer => (6.0, 110.0, 0.006),
+        };
+
+        GrowthDefaults {
+            birth_weight: Mass::kilograms(birth_weight),
+            mature_weight: Mass::kilograms(mature_weight),
+            maturing_rate,
+        }
+    }
+}
+
+/// The weight of an animal at a known age.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AgeWeight {
+    pub age: Duration,
+    pub weight: Mass,
+}
+
+impl AgeWeight {
+    pub fn new(age: Duration, weight: Mass) -> Self {
+        AgeWeight { age, weight }
+    }
+}
+
+/// A growth curve fitted to age and weight records.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrowthCurve {
+    pub model: GrowthModel,
+    /// The asymptotic mature weight `A`.
+    pub mature_weight: Mass,
+    /// The integration constant `B`.
+    pub scale: f64,
+    /// The maturing rate `k`, per day.
+    pub maturing_rate: f64,
+    /// The shape `M` of a Richards curve, `None` for other models.
+    pub shape: Option<f64>,
+    /// Coefficient of determination, from `0.0` to `1.0`.
+    pub r_squared: f64,
+    /// Standard deviation of the weights about the curve.
+    pub residual_standard_deviation: Mass,
+    /// Akaike information criterion, for comparing models fitted to the same records. Lower is
+    /// better.
+    pub aic: f64,
+}
+
+impl GrowthCurve {
+    fn parameters(&self) -> Vec<f64> {
+        let mut parameters = vec![
+            self.mature_weight.as_kilograms(),
+            self.scale,
+            self.maturing_rate,
+        ];
+        parameters.extend(self.shape);
+        parameters
+    }
+
+    /// The weight predicted by the curve at an age, e.g. to project a future weight.
+    pub fn weight_at(&self, age: Duration) -> Mass {
+        Mass::kilograms(self.model.evaluate(age.as_days(), &self.parameters()))
+    }
+
+    /// The age and weight at which growth is fastest, or `None` for Brody curves and curves
+    /// whose inflection point is before birth.
+    pub fn inflection(&self) -> Option<(Duration, Mass)> {
+        let (scale, rate) = (self.scale, self.maturing_rate);
+        let (age, proportion) = match (self.model, self.shape) {
+            (GrowthModel::Brody, _) => return None,
+            (GrowthModel::VonBertalanffy, _) => ((3.0 * scale).ln() / rate, 8.0 / 27.0),
+            (GrowthModel::Gompertz, _) => (scale.ln() / rate, (-1.0f64).exp()),
+            (GrowthModel::Logistic, _) => (scale.ln() / rate, 0.5),
+            (GrowthModel::Richards, Some(shape)) if shape > 1.0 => {
+                ((shape * scale).ln() / rate, (1.0 - 1.0 / shape).powf(shape))
+            }
+            (GrowthModel::Richards, _) => return None,
+        };
+
+        (age.is_finite() && age >= 0.0)
+            .then(|| (Duration::days(age), self.mature_weight * proportion))
+    }
+}
+
+/// Fit a growth curve to records of an animal's weight at known ages.
+///
+/// The curve is fitted by nonlinear least squares, starting from the typical birth weight,
+/// mature weight and maturing rate of the type of livestock. The starting mature weight is
+/// raised above the heaviest record if needed.
+///
+/// # Arguments
+/// - `model`: The growth curve model to fit.
+/// - `records`: Weights at known ages, at least one more than the model's parameters.
+/// - `livestock_type`: The type of livestock, used for the starting parameters.
+///
+/// # Returns
+/// The fitted curve, which can project weights at other ages and find the inflection point.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::growth::curves::{fit_growth_curve, AgeWeight, GrowthModel};
+/// use livestock_rs::types::LivestockType;
+/// use livestock_rs::units::{Duration, Mass};
+///
+/// let records: Vec<AgeWeight> = [(0.0, 38.0), (205.0, 250.0), (365.0, 380.0), (550.0, 470.0), (730.0, 530.0), (1095.0, 590.0)]
+///     .iter()
+///     .map(|(age, weight)| AgeWeight::new(Duration::days(*age), Mass::kilograms(*weight)))
+///     .collect();
+///
+/// let curve = fit_growth_curve(GrowthModel::Gompertz, &records, LivestockType::Cattle).unwrap();
+/// assert!(curve.r_squared > 0.99);
+/// assert_eq!(format!("{:.0}", curve.mature_weight.as_kilograms()), "585");
+///
+/// let (age, _) = curve.inflection().unwrap();
+/// assert_eq!(format!("{:.0}", age.as_days()), "186");
+///
+/// let projected = curve.weight_at(Duration::days(1460.0));
+/// assert!(projected < curve.mature_weight);
+/// ```
+pub fn fit_growth_curve(
+    model: GrowthModel,
+    records: &[AgeWeight],
+    livestock_type: LivestockType,
+) -> Result<GrowthCurve> {
+    let parameter_count = model.parameter_count();
+    ensure!(
+        records.len() > parameter_count,
+        "At least {} weigh-ins are needed to fit a {:?} curve.",
+        parameter_count + 1,
+        model
+    );
+    ensure!(
+        records
+            .iter()
+            .all(|record| record.age.as_days() >= 0.0 && record.weight.as_kilograms() > 0.0),
+        "Ages cannot be negative and weights must be greater than zero."
+    );
+
+    let points: Vec<(f64, f64)> = records
+        .iter()
+        .map(|record| (record.age.as_days(), record.weight.as_kilograms()))
+        .collect();
+    let heaviest =
+        records
+            .iter()
+            .map(|record| record.weight)
+            .fold(Mass::default(), |heaviest, weight| {
+                if weight > heaviest {
+                    weight
+                } else {
+                    heaviest
+                }
+            });
+
+    let defaults = GrowthDefaults::from(livestock_type);
+    let mature_weight = if defaults.mature_weight > heaviest * 1.05 {
+        defaults.mature_weight
+    } else {
+        heaviest * 1.2
+    };
+    let initial = model.starting_parameters(&defaults, mature_weight);
+
+    let fit = nonlinear_least_squares(
+        |age, parameters| model.evaluate(age, parameters),
+        &points,
+        &initial,
+    )
+    .with_context(|| {
+        format!(
+            "Failed to fit a {:?} curve. The model may not suit these weights, try another.",
+            model
+        )
+    })?;
+    ensure!(
+        fit.parameters[0] > 0.0 && fit.parameters[2] > 0.0,
+        "The {:?} curve did not fit a positive mature weight and maturing rate.",
+        model
+    );
+
+    let count = points.len() as f64;
+    let mean = points.iter().map(|(_, weight)| weight).sum::<f64>() / count;
+    let total: f64 = points
+        .iter()
+        .map(|(_, weight)| (weight - mean).powi(2))
+        .sum();
+    let degrees_of_freedom = (points.len() - parameter_count) as f64;
+
+    Ok(GrowthCurve {
+        model,
+        mature_weight: Mass::kilograms(fit.parameters[0]),
+        scale: fit.parameters[1],
+        maturing_rate: fit.parameters[2],
+        shape: fit.parameters.get(3).copied(),
+        r_squared: if total > 0.0 {
+            1.0 - fit.sum_of_squares / total
+        } else {
+            1.0
+        },
+        residual_standard_deviation: Mass::kilograms(
+            (fit.sum_of_squares / degrees_of_freedom).sqrt(),
+        ),
+        aic: count * (fit.sum_of_squares / count).max(f64::MIN_POSITIVE).ln()
+            + 2.0 * parameter_count as f64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AGES: [f64; 8] = [0.0, 60.0, 120.0, 205.0, 300.0, 365.0, 550.0, 730.0];
+
+    fn records(model: GrowthModel, parameters: &[f64]) -> Vec<AgeWeight> {
+        AGES.iter()
+            .map(|age| {
+                AgeWeight::new(
+                    Duration::days(*age),
+                    Mass::kilograms(model.evaluate(*age, parameters)),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fit_growth_curve_recovers_parameters() {
+        let cases = [
+            (GrowthModel::Brody, vec![620.0, 0.94, 0.0025]),
+            (GrowthModel::VonBertalanffy, vec![580.0, 0.6, 0.004]),
+            (GrowthModel::Gompertz, vec![600.0, 2.8, 0.005]),
+            (GrowthModel::Logistic, vec![560.0, 12.0, 0.009]),
+            (GrowthModel::Richards, vec![600.0, 0.55, 0.0045, 2.5]),
+        ];
+
+        for (model, parameters) in cases.iter() {
+            let curve =
+                fit_growth_curve(*model, &records(*model, parameters), LivestockType::Cattle)
+                    .unwrap();
+            let fitted = curve.parameters();
+            for (fitted, expected) in fitted.iter().zip(parameters) {
+                assert!(
+                    ((fitted - expected) / expected).abs() < 1e-4,
+                    "{:?}: expected {:?}, got {:?}",
+                    model,
+                    parameters,
+                    fitted
+                );
+            }
+            assert!(curve.r_squared > 0.999_999);
+        }
+    }
+
+    #[test]
+    fn test_inflection() {
+        let curve = |model, scale, shape| GrowthCurve {
+            model,
+            mature_weight: Mass::kilograms(540.0),
+            scale,
+            maturing_rate: 0.005,
+            shape,
+            r_squared: 1.0,
+            residual_standard_deviation: Mass::default(),
+            aic: 0.0,
+        };
+
+        assert_eq!(curve(GrowthModel::Brody, 0.9, None).inflection(), None);
+
+        let (age, weight) = curve(GrowthModel::Gompertz, 2.8, None)
+            .inflection()
+            .unwrap();
+        assert!((age.as_days() - 2.8f64.ln() / 0.005).abs() < 1e-9);
+        assert!((weight.as_kilograms() - 540.0 / std::f64::consts::E).abs() < 1e-9);
+
+        let (_, weight) = curve(GrowthModel::Logistic, 12.0, None)
+            .inflection()
+            .unwrap();
+        assert!((weight.as_kilograms() - 270.0).abs() < 1e-9);
+
+        let (_, weight) = curve(GrowthModel::VonBertalanffy, 0.6, None)
+            .inflection()
+            .unwrap();
+        assert!((weight.as_kilograms() - 160.0).abs() < 1e-9);
+
+        // With a shape of 3, a Richards curve is a von Bertalanffy curve.
+        let (age, weight) = curve(GrowthModel::Richards, 0.6, Some(3.0))
+            .inflection()
+            .unwrap();
+        assert!((age.as_days() - 1.8f64.ln() / 0.005).abs() < 1e-9);
+        assert!((weight.as_kilograms() - 160.0).abs() < 1e-9);
+
+        // Inflection before birth.
+        assert_eq!(
+            curve(GrowthModel::VonBertalanffy, 0.2, None).inflection(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fit_growth_curve_invalid() {
+        let parameters = [600.0, 2.8, 0.005];
+        let mut records = records(GrowthModel::Gompertz, &parameters);
+
+        assert!(
+            fit_growth_curve(GrowthModel::Gompertz, &records[..3], LivestockType::Cattle).is_err()
+        );
+        assert!(
+            fit_growth_curve(GrowthModel::Richards, &records[..4], LivestockType::Cattle).is_err()
+        );
+
+        records[2].weight = Mass::kilograms(0.0);
+        assert!(fit_growth_curve(GrowthModel::Gompertz, &records, LivestockType::Cattle).is_err());
+    }
+}
@@ -1 +1,6 @@
 pub mod adg;
+pub mod adjusted;
+pub mod curves;
+pub mod estimate;
+pub mod projection;
+pub mod wda;
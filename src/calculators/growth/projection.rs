@@ -0,0 +1,275 @@
+use crate::types::Date;
+use crate::units::{Duration, Mass, MassRate};
+use anyhow::{ensure, Result};
+
+/// When an animal is expected to reach its target weight at a given rate of gain.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Projection {
+    /// The average daily gain the projection assumes.
+    pub adg: MassRate,
+    /// Whole days on feed until the target weight is reached.
+    pub days_on_feed: Duration,
+    /// The date the animal is expected to reach the target weight.
+    pub ship_date: Date,
+    /// Total feed needed to reach the target weight, if a feed conversion ratio was given.
+    pub feed_required: Option<Mass>,
+}
+
+/// Projections for a low, expected and high rate of gain.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProjectionRange {
+    /// The projection at the lowest ADG, with the latest ship date, or `None` if the lowest ADG
+    /// is not above zero, so the animal might never reach the target weight.
+    pub low: Option<Projection>,
+    pub expected: Projection,
+    /// The projection at the highest ADG, with the earliest ship date.
+    pub high: Projection,
+}
+
+/// Project when an animal will reach a target weight, e.g. its market or finish weight.
+///
+/// The days on feed are the weight still to gain divided by the average daily gain, rounded up
+/// to whole days.
+///
+/// # Arguments
+/// - `current_weight`: The weight of the animal on `start_date`.
+/// - `target_weight`: The weight the animal is to be shipped at.
+/// - `start_date`: The day the animal weighed `current_weight`.
+/// - `adg`: The expected average daily gain, e.g. from [`calculate_adg`](super::adg::calculate_adg).
+/// - `fcr`: The feed conversion ratio, e.g. from
+///   [`calculate_fcr`](crate::calculators::feed::fcr::calculate_fcr), to estimate the feed
+///   needed.
+///
+/// # Returns
+/// The days on feed, ship date and, with an FCR, the total feed required.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::growth::projection::project_market_date;
+/// use livestock_rs::types::Date;
+/// use livestock_rs::units::{Mass, MassRate};
+/// use std::str::FromStr;
+///
+/// let projection = project_market_date(
+///     Mass::pounds(850.0),
+///     Mass::pounds(1350.0),
+///     Date::from_str("2026-10-01").unwrap(),
+///     MassRate::pounds_per_day(3.5),
+///     Some(6.0),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(projection.days_on_feed.as_days(), 143.0);
+/// assert_eq!(projection.ship_date.to_string(), "2027-02-21");
+/// assert_eq!(format!("{:.0}", projection.feed_required.unwrap().as_pounds()), "3000");
+/// ```
+pub fn project_market_date(
+    current_weight: Mass,
+    target_weight: Mass,
+    start_date: Date,
+    adg: MassRate,
+    fcr: Option<f64>,
+) -> Result<Projection> {
+    ensure!(
+        current_weight.as_kilograms() > 0.0,
+        "Current weight must be greater than zero."
+    );
+    ensure!(
+        target_weight > current_weight,
+        "Target weight must be greater than current weight."
+    );
+    ensure!(
+        adg.as_kilograms_per_day() > 0.0,
+        "ADG must be greater than zero."
+    );
+    ensure!(
+        fcr.is_none_or(|fcr| fcr > 0.0),
+        "FCR must be greater than zero."
+    );
+
+    let gain = target_weight - current_weight;
+    let days = gain.as_kilograms() / adg.as_kilograms_per_day();
+    // Only round up partial days, not the error from converting units.
+    let days_on_feed = Duration::days(if (days - days.round()).abs() < 1e-9 {
+        days.round()
+    } else {
+        days.ceil()
+    });
+
+    Ok(Projection {
+        adg,
+        days_on_feed,
+        ship_date: start_date + days_on_feed,
+        feed_required: fcr.map(|fcr| gain * fcr),
+    })
+}
+
+/// Project when an animal will reach a target weight at a low, expected and high ADG.
+///
+/// See [`project_market_date`] for the arguments. The low ADG may be zero or negative, as the
+/// lower bound of a confidence interval can be for a short or noisy weigh-in series; there is no
+/// low projection then.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::growth::projection::project_market_date_range;
+/// use livestock_rs::types::Date;
+/// use livestock_rs::units::{Mass, MassRate};
+/// use std::str::FromStr;
+///
+/// let range = project_market_date_range(
+///     Mass::pounds(850.0),
+///     Mass::pounds(1350.0),
+///     Date::from_str("2026-10-01").unwrap(),
+///     (MassRate::pounds_per_day(3.0), MassRate::pounds_per_day(3.5), MassRate::pounds_per_day(4.0)),
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(range.high.ship_date.to_string(), "2027-02-03");
+/// assert_eq!(range.expected.ship_date.to_string(), "2027-02-21");
+/// assert_eq!(range.low.unwrap().ship_date.to_string(), "2027-03-17");
+/// ```
+pub fn project_market_date_range(
+    current_weight: Mass,
+    target_weight: Mass,
+    start_date: Date,
+    (low, expected, high): (MassRate, MassRate, MassRate),
+    fcr: Option<f64>,
+) -> Result<ProjectionRange> {
+    ensure!(
+        low <= expected && expected <= high,
+        "ADGs must be ordered from low to high."
+    );
+
+    let project = |adg| project_market_date(current_weight, target_weight, start_date, adg, fcr);
+    let expected = project(expected)?;
+    Ok(ProjectionRange {
+        low: match low.as_kilograms_per_day() > 0.0 {
+            true => Some(project(low)?),
+            false => None,
+        },
+        expected,
+        high: project(high)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn date(date: &str) -> Date {
+        Date::from_str(date).unwrap()
+    }
+
+    #[test]
+    fn test_project_market_date() {
+        let cases = [
+            (300.0, 600.0, 1.5, "2026-01-01", 200.0, "2026-07-20"),
+            // Partial days round up to the next whole day.
+            (300.0, 600.0, 1.6, "2026-01-01", 188.0, "2026-07-08"),
+            (250.0, 251.0, 2.0, "2024-02-28", 1.0, "2024-02-29"),
+        ];
+
+        for (current, target, adg, start, days, ship) in cases.iter() {
+            let projection = project_market_date(
+                Mass::kilograms(*current),
+                Mass::kilograms(*target),
+                date(start),
+                MassRate::kilograms_per_day(*adg),
+                None,
+            )
+            .unwrap();
+            assert_eq!(projection.days_on_feed.as_days(), *days);
+            assert_eq!(projection.ship_date, date(ship));
+            assert_eq!(projection.feed_required, None);
+        }
+    }
+
+    #[test]
+    fn test_project_market_date_feed_required() {
+        let projection = project_market_date(
+            Mass::kilograms(300.0),
+            Mass::kilograms(600.0),
+            date("2026-01-01"),
+            MassRate::kilograms_per_day(1.5),
+            Some(6.5),
+        )
+        .unwrap();
+        assert!((projection.feed_required.unwrap().as_kilograms() - 1950.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_project_market_date_invalid() {
+        let start = date("2026-01-01");
+        let adg = MassRate::kilograms_per_day(1.5);
+        let current = Mass::kilograms(300.0);
+        let target = Mass::kilograms(600.0);
+
+        assert!(project_market_date(target, current, start, adg, None).is_err());
+        assert!(project_market_date(Mass::kilograms(0.0), target, start, adg, None).is_err());
+        assert!(project_market_date(
+            current,
+            target,
+            start,
+            MassRate::kilograms_per_day(0.0),
+            None
+        )
+        .is_err());
+        assert!(project_market_date(current, target, start, adg, Some(0.0)).is_err());
+    }
+
+    #[test]
+    fn test_project_market_date_range() {
+        let rate = MassRate::kilograms_per_day;
+        let range = project_market_date_range(
+            Mass::kilograms(300.0),
+            Mass::kilograms(600.0),
+            date("2026-01-01"),
+            (rate(1.2), rate(1.5), rate(2.0)),
+            None,
+        )
+        .unwrap();
+        assert_eq!(range.low.unwrap().days_on_feed.as_days(), 250.0);
+        assert_eq!(range.expected.days_on_feed.as_days(), 200.0);
+        assert_eq!(range.high.days_on_feed.as_days(), 150.0);
+
+        assert!(project_market_date_range(
+            Mass::kilograms(300.0),
+            Mass::kilograms(600.0),
+            date("2026-01-01"),
+            (rate(1.5), rate(1.2), rate(2.0)),
+            None,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_project_market_date_range_without_low_gain() {
+        let rate = MassRate::kilograms_per_day;
+        for low in [0.0, -0.3] {
+            let range = project_market_date_range(
+                Mass::kilograms(300.0),
+                Mass::kilograms(600.0),
+                date("2026-01-01"),
+                (rate(low), rate(1.5), rate(2.0)),
+                None,
+            )
+            .unwrap();
+            assert_eq!(range.low, None);
+            assert_eq!(range.expected.days_on_feed.as_days(), 200.0);
+            assert_eq!(range.high.days_on_feed.as_days(), 150.0);
+        }
+
+        // The expected ADG must still reach the target.
+        assert!(project_market_date_range(
+            Mass::kilograms(300.0),
+            Mass::kilograms(600.0),
+            date("2026-01-01"),
+            (rate(-0.3), rate(0.0), rate(2.0)),
+            None,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use crate::types::Date;
+use crate::units::{Mass, MassRate};
+use anyhow::{ensure, Result};
+
+/// Calculate weight per day of age (WDA) for an animal of any type of livestock.
+///
+/// WDA is the animal's weight divided by its age in days on the day it was weighed. When the
+/// birth weight is known, it is subtracted first, giving the average gain per day since birth.
+///
+/// # Arguments
+/// - `birth_date`: The day the animal was born.
+/// - `weigh_date`: The day the animal was weighed.
+/// - `weight`: The weight of the animal on `weigh_date`.
+/// - `birth_weight`: The weight of the animal at birth, if known.
+///
+/// # Returns
+/// The weight per day of age, which can be read in any mass unit per day.
+///
+/// # Example
+/// ```
+/// use livestock_rs::calculators::growth::wda::calculate_wda;
+/// use livestock_rs::types::Date;
+/// use livestock_rs::units::Mass;
+/// use std::str::FromStr;
+///
+/// let birth_date = Date::from_str("2025-03-01").unwrap();
+/// let weigh_date = Date::from_str("2026-02-14").unwrap();
+///
+/// let wda = calculate_wda(birth_date, weigh_date, Mass::pounds(1050.0), None).unwrap();
+/// assert_eq!(format!("{:.2}", wda.as_pounds_per_day()), "3.00");
+///
+/// let wda = calculate_wda(birth_date, weigh_date, Mass::pounds(1050.0), Some(Mass::pounds(80.0))).unwrap();
+/// assert_eq!(format!("{:.2}", wda.as_pounds_per_day()), "2.77");
+/// ```
+pub fn calculate_wda(
+    birth_date: Date,
+    weigh_date: Date,
+    weight: Mass,
+    birth_weight: Option<Mass>,
+) -> Result<MassRate> {
+    ensure!(
+        weigh_date > birth_date,
+        "Weigh date must be after birth date."
+    );
+    ensure!(
+        weight.as_kilograms() > 0.0,
+        "Weight must be greater than zero."
+    );
+
+    let birth_weight = birth_weight.unwrap_or_default();
+    ensure!(
+        birth_weight.as_kilograms() >= 0.0,
+        "Birth weight cannot be negative."
+    );
+    ensure!(
+        weight > birth_weight,
+        "Weight must be greater than birth weight."
+    );
+
+    Ok((weight - birth_weight) / (weigh_date - birth_date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn date(date: &str) -> Date {
+        Date::from_str(date).unwrap()
+    }
+
+    #[test]
+    fn test_calculate_wda() {
+        let cases = [
+            ("2026-01-01", "2026-04-11", 100.0, None, 1.0),
+            ("2026-01-01", "2026-04-11", 100.0, Some(40.0), 0.6),
+            // A leap day falls between the dates.
+            ("2024-02-01", "2024-03-02", 3.0, Some(1.5), 0.05),
+        ];
+
+        for (birth_date, weigh_date, weight, birth_weight, expected) in cases.iter() {
+            let wda = calculate_wda(
+                date(birth_date),
+                date(weigh_date),
+                Mass::kilograms(*weight),
+                birth_weight.map(Mass::kilograms),
+            )
+            .unwrap();
+            assert!((wda.as_kilograms_per_day() - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_calculate_wda_invalid() {
+        let birth_date = date("2026-01-01");
+        let weigh_date = date("2026-04-11");
+        let weight = Mass::kilograms(100.0);
+
+        assert!(calculate_wda(weigh_date, birth_date, weight, None).is_err());
+        assert!(calculate_wda(birth_date, birth_date, weight, None).is_err());
+        assert!(calculate_wda(birth_date, weigh_date, Mass::kilograms(0.0), None).is_err());
+        assert!(
+            calculate_wda(birth_date, weigh_date, weight, Some(Mass::kilograms(100.0))).is_err()
+        );
+        assert!(
+            calculate_wda(birth_date, weigh_date, weight, Some(Mass::kilograms(-1.0))).is_err()
+        );
+    }
+}
@@ -1,2 +1,5 @@
 pub mod feed;
+pub mod genetics;
 pub mod growth;
+pub(crate) mod simplex;
+pub(crate) mod stats;
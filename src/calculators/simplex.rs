@@ -0,0 +1,319 @@
+/// Values closer to zero than this are treated as zero.
+const EPSILON: f64 = 1e-9;
+
+/// How the left-hand side of a constraint relates to its bound.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Relation {
+    AtMost,
+    AtLeast,
+    Equal,
+}
+
+/// A linear constraint, `coefficients · x (relation) bound`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Constraint {
+    pub coefficients: Vec<f64>,
+    pub relation: Relation,
+    pub bound: f64,
+}
+
+impl Constraint {
+    pub fn new(coefficients: Vec<f64>, relation: Relation, bound: f64) -> Self {
+        Constraint {
+            coefficients,
+            relation,
+            bound,
+        }
+    }
+}
+
+/// The outcome of solving a linear program.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum LinearSolution {
+    Optimal {
+        values: Vec<f64>,
+        objective: f64,
+    },
+    /// No point satisfies every constraint.
+    Infeasible,
+    Unbounded,
+}
+
+/// Minimizes `costs · x` subject to the constraints and `x >= 0`, with the two-phase simplex
+/// method and Bland's rule to prevent cycling.
+///
+/// Phase one minimizes the total (scaled) violation of the constraints. If it cannot reach zero,
+/// the constraints are infeasible.
+pub(crate) fn minimize(costs: &[f64], constraints: &[Constraint]) -> LinearSolution {
+    let variables = costs.len();
+    let rows = constraints.len();
+
+    // Scale each row so its largest coefficient is 1, and flip it so its bound is not negative.
+    let mut scales = Vec::with_capacity(rows);
+    let mut relations = Vec::with_capacity(rows);
+    for constraint in constraints {
+        let largest = constraint
+            .coefficients
+            .iter()
+            .fold(0.0_f64, |largest, value| largest.max(value.abs()));
+        let mut scale = if largest > 0.0 { 1.0 / largest } else { 1.0 };
+        let mut relation = constraint.relation;
+        if constraint.bound < 0.0 {
+            scale = -scale;
+            relation = match relation {
+                Relation::AtMost => Relation::AtLeast,
+                Relation::AtLeast => Relation::AtMost,
+                Relation::Equal => Relation::Equal,
+            };
+        }
+        scales.push(scale);
+        relations.push(relation);
+    }
+
+    // Columns are the variables, then a slack or surplus for each inequality, then an artificial
+    // for each row without a slack to start the basis.
+    let slack_count = relations
+        .iter()
+        .filter(|relation| **relation != Relation::Equal)
+        .count();
+    let artificial_count = relations
+        .iter()
+        .filter(|relation| **relation != Relation::AtMost)
+        .count();
+    let first_artificial = variables + slack_count;
+    let columns = first_artificial + artificial_count;
+
+    let mut tableau = Tableau {
+        rows: vec![vec![0.0; columns + 1]; rows],
+        basis: vec![0; rows],
+    };
+    let mut artificial_rows = Vec::new();
+    let (mut slack, mut artificial) = (variables, first_artificial);
+    for (row, constraint) in constraints.iter().enumerate() {
+        let values = &mut tableau.rows[row];
+        for (value, coefficient) in values.iter_mut().zip(&constraint.coefficients) {
+            *value = coefficient * scales[row];
+        }
+        values[columns] = constraint.bound * scales[row];
+
+        match relations[row] {
+            Relation::AtMost => {
+                values[slack] = 1.0;
+                tableau.basis[row] = slack;
+                slack += 1;
+            }
+            Relation::AtLeast => {
+                values[slack] = -1.0;
+                values[artificial] = 1.0;
+                tableau.basis[row] = artificial;
+                slack += 1;
+                artificial += 1;
+                artificial_rows.push(row);
+            }
+            Relation::Equal => {
+                values[artificial] = 1.0;
+                tableau.basis[row] = artificial;
+                artificial += 1;
+                artificial_rows.push(row);
+            }
+        }
+    }
+
+    // Phase one: drive the artificials to zero.
+    let phase_one_costs: Vec<f64> = (0..columns)
+        .map(|column| if column >= first_artificial { 1.0 } else { 0.0 })
+        .collect();
+    if !tableau.optimize(&phase_one_costs, columns) {
+        // The phase one objective is bounded below by zero, so this cannot happen.
+        return LinearSolution::Unbounded;
+    }
+    if tableau.objective(&phase_one_costs) > EPSILON {
+        return LinearSolution::Infeasible;
+    }
+
+    // Pivot any artificials left in the basis at zero out of it, dropping redundant rows.
+    let mut row = 0;
+    while row < tableau.rows.len() {
+        if tableau.basis[row] >= first_artificial {
+            match (0..first_artificial).find(|column| tableau.rows[row][*column].abs() > EPSILON) {
+                Some(column) => tableau.pivot(row, column),
+                None => {
+                    tableau.rows.remove(row);
+                    tableau.basis.remove(row);
+                    continue;
+                }
+            }
+        }
+        row += 1;
+    }
+
+    // Phase two: minimize the real costs, never letting an artificial back in.
+    let mut phase_two_costs = vec![0.0; columns];
+    phase_two_costs[..variables].copy_from_slice(costs);
+    if !tableau.optimize(&phase_two_costs, first_artificial) {
+        return LinearSolution::Unbounded;
+    }
+
+    let mut values = vec![0.0; variables];
+    for (row, column) in tableau.basis.iter().enumerate() {
+        if *column < variables {
+            values[*column] = tableau.rows[row][columns];
+        }
+    }
+    let objective = values.iter().zip(costs).map(|(x, cost)| x * cost).sum();
+    LinearSolution::Optimal { values, objective }
+}
+
+/// A simplex tableau of constraint rows, each ending with its right-hand side.
+struct Tableau {
+    rows: Vec<Vec<f64>>,
+    basis: Vec<usize>,
+}
+
+impl Tableau {
+    fn objective(&self, costs: &[f64]) -> f64 {
+        self.basis
+            .iter()
+            .zip(&self.rows)
+            .map(|(column, row)| costs[*column] * row[row.len() - 1])
+            .sum()
+    }
+
+    /// Pivots until no column below `allowed_columns` improves the objective.
+    ///
+    /// # Returns
+    /// `false` if the objective is unbounded below.
+    fn optimize(&mut self, costs: &[f64], allowed_columns: usize) -> bool {
+        loop {
+            // Bland's rule: the first column with a negative reduced cost enters...
+            let entering = (0..allowed_columns).find(|column| {
+                let reduced_cost = costs[*column]
+                    - self
+                        .basis
+                        .iter()
+                        .zip(&self.rows)
+                        .map(|(basic, row)| costs[*basic] * row[*column])
+                        .sum::<f64>();
+                reduced_cost < -EPSILON
+            });
+            let Some(entering) = entering else {
+                return true;
+            };
+
+            // ...and the row with the smallest ratio, then the smallest basic column, leaves.
+            let rhs = self.rows.first().map_or(0, |row| row.len() - 1);
+            let leaving = (0..self.rows.len())
+                .filter(|row| self.rows[*row][entering] > EPSILON)
+                .min_by(|a, b| {
+                    let ratio = |row: usize| self.rows[row][rhs] / self.rows[row][entering];
+                    ratio(*a)
+                        .total_cmp(&ratio(*b))
+                        .then(self.basis[*a].cmp(&self.basis[*b]))
+                });
+            let Some(leaving) = leaving else {
+                return false;
+            };
+
+            self.pivot(leaving, entering);
+        }
+    }
+
+    fn pivot(&mut self, pivot_row: usize, column: usize) {
+        let pivot = self.rows[pivot_row][column];
+        for value in self.rows[pivot_row].iter_mut() {
+            *value /= pivot;
+        }
+
+        let (before, rest) = self.rows.split_at_mut(pivot_row);
+        let (pivot_values, after) = rest.split_first_mut().expect("pivot row exists");
+        for row in before.iter_mut().chain(after.iter_mut()) {
+            let factor = row[column];
+            if factor != 0.0 {
+                for (value, pivot_value) in row.iter_mut().zip(pivot_values.iter()) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+        self.basis[pivot_row] = column;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn optimal(solution: LinearSolution) -> (Vec<f64>, f64) {
+        match solution {
+            LinearSolution::Optimal { values, objective } => (values, objective),
+            other => panic!("expected an optimal solution, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_minimize() {
+        // The diet problem: two feeds costing 2 and 3, needing at least 8 units of one
+        // nutrient (3 and 1 per unit of feed) and 6 of another (1 and 2).
+        let constraints = [
+            Constraint::new(vec![3.0, 1.0], Relation::AtLeast, 8.0),
+            Constraint::new(vec![1.0, 2.0], Relation::AtLeast, 6.0),
+        ];
+        let (values, objective) = optimal(minimize(&[2.0, 3.0], &constraints));
+        assert!((values[0] - 2.0).abs() < 1e-9);
+        assert!((values[1] - 2.0).abs() < 1e-9);
+        assert!((objective - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_minimize_mixed_constraints() {
+        // Maximize x + y, as minimizing -x - y, with x + 2y <= 4, x - y = 1 and y >= 0.5.
+        let constraints = [
+            Constraint::new(vec![1.0, 2.0], Relation::AtMost, 4.0),
+            Constraint::new(vec![1.0, -1.0], Relation::Equal, 1.0),
+            Constraint::new(vec![0.0, 1.0], Relation::AtLeast, 0.5),
+        ];
+        let (values, objective) = optimal(minimize(&[-1.0, -1.0], &constraints));
+        assert!((values[0] - 2.0).abs() < 1e-9);
+        assert!((values[1] - 1.0).abs() < 1e-9);
+        assert!((objective + 3.0).abs() < 1e-9);
+
+        // A negative bound is flipped: -x <= -1 is x >= 1.
+        let constraints = [Constraint::new(vec![-1.0], Relation::AtMost, -1.0)];
+        let (values, _) = optimal(minimize(&[1.0], &constraints));
+        assert!((values[0] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_minimize_redundant_equalities() {
+        let constraints = [
+            Constraint::new(vec![1.0, 1.0], Relation::Equal, 1.0),
+            Constraint::new(vec![2.0, 2.0], Relation::Equal, 2.0),
+        ];
+        let (values, objective) = optimal(minimize(&[1.0, 2.0], &constraints));
+        assert_eq!(values.len(), 2);
+        assert!((values[0] - 1.0).abs() < 1e-9);
+        assert!((objective - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_minimize_infeasible() {
+        // x + y = 1 with x <= 0.3 can never reach x + 2y >= 4.
+        let constraints = [
+            Constraint::new(vec![1.0, 1.0], Relation::Equal, 1.0),
+            Constraint::new(vec![1.0, 0.0], Relation::AtMost, 0.3),
+            Constraint::new(vec![1.0, 2.0], Relation::AtLeast, 4.0),
+        ];
+        assert_eq!(
+            minimize(&[1.0, 1.0], &constraints),
+            LinearSolution::Infeasible
+        );
+    }
+
+    #[test]
+    fn test_minimize_unbounded() {
+        let constraints = [Constraint::new(vec![1.0, -1.0], Relation::AtMost, 1.0)];
+        assert_eq!(
+            minimize(&[-1.0, 0.0], &constraints),
+            LinearSolution::Unbounded
+        );
+    }
+}
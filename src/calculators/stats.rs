@@ -0,0 +1,586 @@
+use anyhow::{anyhow, ensure, Result};
+
+/// An ordinary least squares fit of `y = intercept + slope * x`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct LinearFit {
+    pub slope: f64,
+    pub intercept: f64,
+    /// Coefficient of determination, from `0.0` to `1.0`.
+    pub r_squared: f64,
+    /// Standard error of the slope.
+    pub slope_standard_error: f64,
+    /// Standard deviation of the residuals.
+    pub residual_standard_deviation: f64,
+    /// Residual degrees of freedom, `n - 2`.
+    pub degrees_of_freedom: usize,
+}
+
+impl LinearFit {
+    pub fn predict(&self, x: f64) -> f64 {
+        self.intercept + self.slope * x
+    }
+}
+
+/// Fits a straight line through the points by ordinary least squares.
+pub(crate) fn linear_regression(points: &[(f64, f64)]) -> Result<LinearFit> {
+    ensure!(
+        points.len() >= 3,
+        "At least three points are needed to fit a regression."
+    );
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let sxx: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    let sxy: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let syy: f64 = points.iter().map(|(_, y)| (y - mean_y).powi(2)).sum();
+    ensure!(
+        sxx > 0.0,
+        "Points must have at least two different x values."
+    );
+
+    let slope = sxy / sxx;
+    let intercept = mean_y - slope * mean_x;
+    let sse: f64 = points
+        .iter()
+        .map(|(x, y)| (y - (intercept + slope * x)).powi(2))
+        .sum();
+    let degrees_of_freedom = points.len() - 2;
+    let residual_variance = sse / degrees_of_freedom as f64;
+
+    Ok(LinearFit {
+        slope,
+        intercept,
+        r_squared: if syy > 0.0 { 1.0 - sse / syy } else { 1.0 },
+        slope_standard_error: (residual_variance / sxx).sqrt(),
+        residual_standard_deviation: residual_variance.sqrt(),
+        degrees_of_freedom,
+    })
+}
+
+/// An ordinary least squares fit of `y = b0 + b1 * x1 + ... + bk * xk`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct MultipleFit {
+    /// The intercept `b0`, followed by a coefficient for each predictor.
+    pub coefficients: Vec<f64>,
+    /// Coefficient of determination, from `0.0` to `1.0`.
+    pub r_squared: f64,
+}
+
+impl MultipleFit {
+    pub fn predict(&self, predictors: &[f64]) -> f64 {
+        self.coefficients[0]
+            + self.coefficients[1..]
+                .iter()
+                .zip(predictors)
+                .map(|(coefficient, x)| coefficient * x)
+                .sum::<f64>()
+    }
+}
+
+/// Fits a linear model with several predictors by ordinary least squares, solving the normal
+/// equations.
+pub(crate) fn multiple_regression(
+    predictors: &[Vec<f64>],
+    responses: &[f64],
+) -> Result<MultipleFit> {
+    ensure!(
+        predictors.len() == responses.len(),
+        "Each response must have a row of predictors."
+    );
+    let size = predictors.first().map_or(0, Vec::len) + 1;
+    ensure!(
+        predictors.iter().all(|row| row.len() + 1 == size),
+        "Each row must have the same number of predictors."
+    );
+    ensure!(
+        responses.len() > size,
+        "At least {} observations are needed to fit {} coefficients.",
+        size + 1,
+        size
+    );
+
+    let mut normal = vec![vec![0.0; size]; size];
+    let mut cross = vec![0.0; size];
+    for (row, response) in predictors.iter().zip(responses) {
+        let row: Vec<f64> = std::iter::once(1.0).chain(row.iter().copied()).collect();
+        for i in 0..size {
+            cross[i] += row[i] * response;
+            for j in 0..size {
+                normal[i][j] += row[i] * row[j];
+            }
+        }
+    }
+    let coefficients = solve(normal, cross)
+        .ok_or_else(|| anyhow!("Predictors must not be constant or collinear."))?;
+
+    let fit = MultipleFit {
+        coefficients,
+        r_squared: 0.0,
+    };
+    let mean = responses.iter().sum::<f64>() / responses.len() as f64;
+    let total: f64 = responses.iter().map(|y| (y - mean).powi(2)).sum();
+    let residual: f64 = predictors
+        .iter()
+        .zip(responses)
+        .map(|(row, y)| (y - fit.predict(row)).powi(2))
+        .sum();
+
+    Ok(MultipleFit {
+        r_squared: if total > 0.0 {
+            1.0 - residual / total
+        } else {
+            1.0
+        },
+        ..fit
+    })
+}
+
+/// A nonlinear least squares fit of a model's parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct NonlinearFit {
+    pub parameters: Vec<f64>,
+    /// Sum of squared residuals at the fitted parameters.
+    pub sum_of_squares: f64,
+    pub iterations: usize,
+}
+
+/// Fits the parameters of `model(x, parameters)` to the points by nonlinear least squares, using
+/// the Levenberg-Marquardt algorithm from the `initial` parameters.
+///
+/// The Jacobian is estimated by central differences. Parameters where the model is not finite are
+/// rejected, so models can return `NaN` outside their domain.
+pub(crate) fn nonlinear_least_squares<F>(
+    model: F,
+    points: &[(f64, f64)],
+    initial: &[f64],
+) -> Result<NonlinearFit>
+where
+    F: Fn(f64, &[f64]) -> f64,
+{
+    const MAX_ITERATIONS: usize = 1000;
+    const MAX_DAMPING: f64 = 1e16;
+    const TOLERANCE: f64 = 1e-12;
+
+    ensure!(
+        points.len() > initial.len(),
+        "At least {} points are needed to fit {} parameters.",
+        initial.len() + 1,
+        initial.len()
+    );
+
+    let sum_of_squares = |parameters: &[f64]| -> f64 {
+        points
+            .iter()
+            .map(|(x, y)| (y - model(*x, parameters)).powi(2))
+            .sum()
+    };
+
+    let mut parameters = initial.to_vec();
+    let mut current = sum_of_squares(&parameters);
+    ensure!(
+        current.is_finite(),
+        "The model is not defined at the starting parameters."
+    );
+
+    let mut damping = 1e-3;
+    for iteration in 1..=MAX_ITERATIONS {
+        let jacobian = jacobian(&model, points, &parameters);
+        let residuals: Vec<f64> = points
+            .iter()
+            .map(|(x, y)| y - model(*x, &parameters))
+            .collect();
+
+        let size = parameters.len();
+        let mut normal = vec![vec![0.0; size]; size];
+        let mut gradient = vec![0.0; size];
+        for (row, residual) in jacobian.iter().zip(&residuals) {
+            for i in 0..size {
+                gradient[i] += row[i] * residual;
+                for j in 0..size {
+                    normal[i][j] += row[i] * row[j];
+                }
+            }
+        }
+
+        // Raise the damping until a step reduces the sum of squares, moving from Gauss-Newton
+        // towards gradient descent.
+        loop {
+            let mut damped = normal.clone();
+            for (i, row) in damped.iter_mut().enumerate() {
+                row[i] += damping * normal[i][i].max(f64::EPSILON);
+            }
+
+            let candidate = solve(damped, gradient.clone()).map(|step| {
+                parameters
+                    .iter()
+                    .zip(&step)
+                    .map(|(parameter, step)| parameter + step)
+                    .collect::<Vec<f64>>()
+            });
+            if let Some(candidate) = candidate {
+                let candidate_sum = sum_of_squares(&candidate);
+                if candidate_sum.is_finite() && candidate_sum <= current {
+                    let converged = current - candidate_sum <= TOLERANCE * current
+                        || candidate.iter().zip(&parameters).all(|(new, old)| {
+                            (new - old).abs() <= TOLERANCE * old.abs().max(TOLERANCE)
+                        });
+
+                    parameters = candidate;
+                    current = candidate_sum;
+                    damping = (damping / 10.0).max(1e-12);
+
+                    if converged {
+                        return Ok(NonlinearFit {
+                            parameters,
+                            sum_of_squares: current,
+                            iterations: iteration,
+                        });
+                    }
+                    break;
+                }
+            }
+
+            damping *= 10.0;
+            if damping > MAX_DAMPING {
+                // No step can reduce the sum of squares further, so this is a minimum.
+                return Ok(NonlinearFit {
+                    parameters,
+                    sum_of_squares: current,
+                    iterations: iteration,
+                });
+            }
+        }
+    }
+
+    Err(anyhow!(
+        "The fit did not converge after {} iterations.",
+        MAX_ITERATIONS
+    ))
+}
+
+/// Estimates the partial derivatives of the model at each point by central differences.
+fn jacobian<F>(model: &F, points: &[(f64, f64)], parameters: &[f64]) -> Vec<Vec<f64>>
+where
+    F: Fn(f64, &[f64]) -> f64,
+{
+    let mut shifted = parameters.to_vec();
+    let steps: Vec<f64> = parameters
+        .iter()
+        .map(|parameter| 1e-6 * parameter.abs().max(1e-6))
+        .collect();
+
+    points
+        .iter()
+        .map(|(x, _)| {
+            (0..parameters.len())
+                .map(|i| {
+                    shifted[i] = parameters[i] + steps[i];
+                    let above = model(*x, &shifted);
+                    shifted[i] = parameters[i] - steps[i];
+                    let below = model(*x, &shifted);
+                    shifted[i] = parameters[i];
+
+                    let derivative = (above - below) / (2.0 * steps[i]);
+                    if derivative.is_finite() {
+                        derivative
+                    } else {
+                        0.0
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Solves the linear system `matrix * x = vector` by Gaussian elimination with partial pivoting,
+/// or returns `None` if the matrix is singular.
+fn solve(mut matrix: Vec<Vec<f64>>, mut vector: Vec<f64>) -> Option<Vec<f64>> {
+    let size = vector.len();
+    // Pivots are compared with the original diagonal, so columns of very different scales are
+    // only treated as singular when they are dependent on the others.
+    let diagonal: Vec<f64> = (0..size).map(|i| matrix[i][i].abs()).collect();
+
+    for column in 0..size {
+        let pivot = (column..size).max_by(|a, b| {
+            matrix[*a][column]
+                .abs()
+                .total_cmp(&matrix[*b][column].abs())
+        })?;
+        if matrix[pivot][column].abs() <= 1e-12 * diagonal[column] || matrix[pivot][column] == 0.0 {
+            return None;
+        }
+        matrix.swap(column, pivot);
+        vector.swap(column, pivot);
+
+        let (above, below) = matrix.split_at_mut(column + 1);
+        let pivot_row = &above[column];
+        for (offset, row) in below.iter_mut().enumerate() {
+            let factor = row[column] / pivot_row[column];
+            for (value, pivot_value) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *value -= factor * pivot_value;
+            }
+            vector[column + 1 + offset] -= factor * vector[column];
+        }
+    }
+
+    let mut solution = vec![0.0; size];
+    for row in (0..size).rev() {
+        let sum: f64 = (row + 1..size).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (vector[row] - sum) / matrix[row][row];
+    }
+
+    solution
+        .iter()
+        .all(|value| value.is_finite())
+        .then_some(solution)
+}
+
+/// The quantile of Student's t distribution, e.g. `t_quantile(0.975, 10)` for the critical value
+/// of a two-sided 95% confidence interval with 10 degrees of freedom.
+pub(crate) fn t_quantile(p: f64, degrees_of_freedom: usize) -> f64 {
+    if p < 0.5 {
+        return -t_quantile(1.0 - p, degrees_of_freedom);
+    }
+
+    // The CDF is monotonic, so bisect for the value whose CDF matches `p`.
+    let (mut low, mut high) = (0.0, 1.0);
+    while t_cdf(high, degrees_of_freedom) < p {
+        high *= 2.0;
+    }
+    for _ in 0..100 {
+        let mid = (low + high) / 2.0;
+        if t_cdf(mid, degrees_of_freedom) < p {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    (low + high) / 2.0
+}
+
+/// The cumulative distribution function of Student's t distribution.
+fn t_cdf(t: f64, degrees_of_freedom: usize) -> f64 {
+    let v = degrees_of_freedom as f64;
+    let tail = 0.5 * incomplete_beta(v / (v + t * t), v / 2.0, 0.5);
+
+    if t >= 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+}
+
+/// The regularized incomplete beta function `I_x(a, b)`, evaluated by continued fraction.
+fn incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+
+    // The continued fraction converges quickly only below this point, so use the symmetry
+    // `I_x(a, b) = 1 - I_(1-x)(b, a)` above it.
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(x, a, b) / a
+    } else {
+        1.0 - front * beta_continued_fraction(1.0 - x, b, a) / b
+    }
+}
+
+/// Evaluates the continued fraction for the incomplete beta function with Lentz's method.
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const EPSILON: f64 = 1e-15;
+    const TINY: f64 = 1e-300;
+
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut fraction = d;
+
+    for m in 1..=300 {
+        let m = m as f64;
+        let numerators = [
+            m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m)),
+            -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0)),
+        ];
+
+        for numerator in numerators {
+            d = 1.0 + numerator * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + numerator / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            fraction *= d * c;
+        }
+
+        if (d * c - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+
+    fraction
+}
+
+/// The natural log of the gamma function, by the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.180_091_729_471_46,
+        -86.505_320_329_416_77,
+        24.014_098_240_830_91,
+        -1.231_739_572_450_155,
+        0.001_208_650_973_866_179,
+        -0.000_005_395_239_384_953,
+    ];
+
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let series = COEFFICIENTS
+        .iter()
+        .enumerate()
+        .fold(1.000_000_000_190_015, |sum, (i, coefficient)| {
+            sum + coefficient / (x + 1.0 + i as f64)
+        });
+
+    -tmp + (2.506_628_274_631_000_5 * series / x).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_regression() {
+        let fit = linear_regression(&[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0), (3.0, 7.0)]).unwrap();
+        assert!((fit.slope - 2.0).abs() < 1e-12);
+        assert!((fit.intercept - 1.0).abs() < 1e-12);
+        assert!((fit.r_squared - 1.0).abs() < 1e-12);
+        assert!(fit.slope_standard_error.abs() < 1e-12);
+        assert_eq!(fit.predict(10.0), 21.0);
+
+        let fit = linear_regression(&[(1.0, 1.0), (2.0, 3.0), (3.0, 2.0), (4.0, 5.0)]).unwrap();
+        assert!((fit.slope - 1.1).abs() < 1e-12);
+        assert!((fit.intercept - 0.0).abs() < 1e-12);
+        assert!((fit.r_squared - 0.691_428_571_428_571_4).abs() < 1e-12);
+        assert!((fit.slope_standard_error - 0.519_615_242_270_663_2).abs() < 1e-12);
+        assert_eq!(fit.degrees_of_freedom, 2);
+    }
+
+    #[test]
+    fn test_linear_regression_invalid() {
+        assert!(linear_regression(&[(0.0, 1.0), (1.0, 2.0)]).is_err());
+        assert!(linear_regression(&[(1.0, 1.0), (1.0, 2.0), (1.0, 3.0)]).is_err());
+    }
+
+    #[test]
+    fn test_multiple_regression() {
+        // y = 1 + 2 * x1 - 0.5 * x2, with one point off the plane.
+        let predictors = vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![0.0, 2.0],
+            vec![1.0, 2.0],
+            vec![2.0, 1.0],
+        ];
+        let responses: Vec<f64> = predictors
+            .iter()
+            .map(|row| 1.0 + 2.0 * row[0] - 0.5 * row[1])
+            .collect();
+        let fit = multiple_regression(&predictors, &responses).unwrap();
+        for (coefficient, expected) in fit.coefficients.iter().zip([1.0, 2.0, -0.5]) {
+            assert!((coefficient - expected).abs() < 1e-12);
+        }
+        assert!((fit.r_squared - 1.0).abs() < 1e-12);
+        assert!((fit.predict(&[3.0, 4.0]) - 5.0).abs() < 1e-12);
+
+        // With a single predictor this is a simple linear regression.
+        let points = [(1.0, 1.0), (2.0, 3.0), (3.0, 2.0), (4.0, 5.0)];
+        let fit = multiple_regression(
+            &points.iter().map(|(x, _)| vec![*x]).collect::<Vec<_>>(),
+            &points.iter().map(|(_, y)| *y).collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let simple = linear_regression(&points).unwrap();
+        assert!((fit.coefficients[0] - simple.intercept).abs() < 1e-12);
+        assert!((fit.coefficients[1] - simple.slope).abs() < 1e-12);
+        assert!((fit.r_squared - simple.r_squared).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_multiple_regression_invalid() {
+        let responses = [1.0, 2.0, 3.0, 4.0];
+        // The second predictor is twice the first.
+        let collinear: Vec<Vec<f64>> = (0..4).map(|x| vec![x as f64, 2.0 * x as f64]).collect();
+        assert!(multiple_regression(&collinear, &responses).is_err());
+        assert!(multiple_regression(&collinear[..3], &responses[..3]).is_err());
+        assert!(multiple_regression(&collinear, &responses[..3]).is_err());
+    }
+
+    #[test]
+    fn test_nonlinear_least_squares() {
+        // y = 3 * exp(0.5 * x), sampled exactly.
+        let points: Vec<(f64, f64)> = (0..6)
+            .map(|x| (x as f64, 3.0 * (0.5 * x as f64).exp()))
+            .collect();
+        let fit = nonlinear_least_squares(
+            |x, parameters| parameters[0] * (parameters[1] * x).exp(),
+            &points,
+            &[1.0, 0.1],
+        )
+        .unwrap();
+        assert!((fit.parameters[0] - 3.0).abs() < 1e-6);
+        assert!((fit.parameters[1] - 0.5).abs() < 1e-6);
+        assert!(fit.sum_of_squares < 1e-12);
+
+        assert!(nonlinear_least_squares(|x, p| p[0] * x, &[(1.0, 1.0)], &[1.0]).is_err());
+        assert!(nonlinear_least_squares(|x, p| (p[0] * x).ln(), &points, &[-1.0]).is_err());
+    }
+
+    #[test]
+    fn test_solve() {
+        let solution = solve(vec![vec![2.0, 1.0], vec![1.0, 3.0]], vec![3.0, 5.0]).unwrap();
+        assert!((solution[0] - 0.8).abs() < 1e-12);
+        assert!((solution[1] - 1.4).abs() < 1e-12);
+
+        assert!(solve(vec![vec![1.0, 2.0], vec![2.0, 4.0]], vec![1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn test_t_quantile() {
+        let cases = [
+            (0.975, 1, 12.706_204_736),
+            (0.975, 2, 4.302_652_730),
+            (0.975, 10, 2.228_138_852),
+            (0.95, 5, 2.015_048_373),
+            (0.995, 30, 2.749_995_654),
+            (0.975, 1000, 1.962_339_081),
+            (0.025, 10, -2.228_138_852),
+        ];
+
+        for (p, degrees_of_freedom, expected) in cases.iter() {
+            let t = t_quantile(*p, *degrees_of_freedom);
+            assert!(
+                (t - expected).abs() < 1e-6,
+                "t({}, {}) = {}",
+                p,
+                degrees_of_freedom,
+                t
+            );
+        }
+    }
+}
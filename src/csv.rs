@@ -0,0 +1,90 @@
+//! Reading the simple comma-separated files used for herd records, rations and feed tests.
+
+/// A data row of a CSV file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvRow<'a> {
+    /// The row's line number in the file, counting from 1, for error messages.
+    pub line: usize,
+    /// The row's fields, trimmed of surrounding whitespace.
+    pub fields: Vec<&'a str>,
+}
+
+/// Read the data rows of a CSV file.
+///
+/// Blank lines and lines starting with `#` are skipped. The first row left may be a header: it is
+/// skipped as well if `is_header` returns true for its fields. Fields are split on every comma,
+/// so they cannot contain quoted commas.
+///
+/// # Arguments
+/// - `contents`: The contents of the file.
+/// - `is_header`: Whether the first row's fields are a header rather than data, e.g. because a
+///   field that should be a number is not.
+///
+/// # Returns
+/// The data rows, in file order.
+pub fn read_rows<'a>(contents: &'a str, is_header: impl Fn(&[&str]) -> bool) -> Vec<CsvRow<'a>> {
+    let mut rows: Vec<CsvRow> = contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| CsvRow {
+            line: index + 1,
+            fields: line.split(',').map(str::trim).collect(),
+        })
+        .collect();
+
+    if rows.first().is_some_and(|row| is_header(&row.fields)) {
+        rows.remove(0);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_header(fields: &[&str]) -> bool {
+        fields
+            .get(1)
+            .is_none_or(|field| field.parse::<f64>().is_err())
+    }
+
+    #[test]
+    fn test_read_rows() {
+        let rows = read_rows("a,1\n  b , 2 \n", is_header);
+        assert_eq!(
+            rows,
+            vec![
+                CsvRow {
+                    line: 1,
+                    fields: vec!["a", "1"]
+                },
+                CsvRow {
+                    line: 2,
+                    fields: vec!["b", "2"]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_rows_header_after_comments() {
+        let rows = read_rows("# herd\n\nname,amount\na,1\n", is_header);
+        assert_eq!(
+            rows,
+            vec![CsvRow {
+                line: 4,
+                fields: vec!["a", "1"]
+            }]
+        );
+
+        // Only the first row can be a header.
+        let rows = read_rows("a,1\nname,amount\n", is_header);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].line, 2);
+
+        assert!(read_rows("# empty\nname,amount\n", is_header).is_empty());
+        assert!(read_rows("", is_header).is_empty());
+    }
+}
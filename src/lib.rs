@@ -1,3 +1,5 @@
 pub mod breeds;
 pub mod calculators;
+pub(crate) mod csv;
 pub mod types;
+pub mod units;
@@ -1,16 +1,466 @@
+use crate::units::{Duration, Mass};
+use anyhow::{anyhow, ensure, Result};
 use clap::ValueEnum;
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
 
-/// The type of livestock 
-/// 
+/// The type of livestock
+///
 /// This enum represents the different types of livestock that can be used in the livestock management system.
 /// This list will grow, and more types will be added in the future, as needed.
-#[derive(Clone, Serialize, Debug, Eq, PartialEq, ValueEnum)]
+#[derive(
+    Clone, Copy, Deserialize, Serialize, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, ValueEnum,
+)]
 pub enum LivestockType {
     Cattle,
     Swine,
     Chicken,
     Rabbit,
     Sheep,
-    Goat
-}
\ No newline at end of file
+    Goat,
+    Horse,
+    Donkey,
+    Camel,
+    Reindeer,
+}
+
+impl LivestockType {
+    /// Returns every type of livestock, in declaration order.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::types::LivestockType;
+    ///
+    /// assert!(LivestockType::all().contains(&LivestockType::Goat));
+    /// assert_eq!(LivestockType::all().len(), LivestockType::count());
+    /// ```
+    pub fn all() -> &'static [LivestockType] {
+        // Generated by `ValueEnum` from the declaration, so no variant can be left out.
+        LivestockType::value_variants()
+    }
+
+    /// Returns an iterator over every type of livestock, in declaration order.
+    pub fn iter() -> impl Iterator<Item = LivestockType> {
+        LivestockType::all().iter().copied()
+    }
+
+    /// Returns the number of types of livestock.
+    pub fn count() -> usize {
+        LivestockType::all().len()
+    }
+}
+
+/// The stage of production of an animal, which affects how much it eats and needs.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ValueEnum,
+)]
+pub enum ProductionStage {
+    /// Mature animals that are not growing, pregnant, lactating or working.
+    Maintenance,
+    /// Young animals growing or being finished.
+    Growing,
+    /// Pregnant females, in late gestation.
+    Gestation,
+    /// Females nursing their young or being milked.
+    Lactation,
+    /// Animals used for draft, riding or other work.
+    Work,
+}
+
+/// The feeding phase of an animal raised for meat, named as in feeding programs for its species:
+/// starter, grower and finisher rations for pigs, poultry, lambs, kids and rabbits, or
+/// backgrounding and finishing for cattle.
+///
+/// Feeding phases divide the [`ProductionStage::Growing`] stage by the ration fed.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ValueEnum,
+)]
+pub enum FeedingPhase {
+    /// Young animals just weaned or hatched, such as nursery pigs, chicks and receiving calves.
+    Starter,
+    Grower,
+    /// Weaned cattle grown on forage before they enter the feedlot.
+    Backgrounding,
+    /// Animals fed to market weight, including cattle in the feedlot.
+    Finisher,
+}
+
+impl FeedingPhase {
+    /// The feeding phase of an animal of a given weight, for the species with published weight
+    /// classes.
+    ///
+    /// Swine phases follow the weight classes of the NRC (1998) growing pig tables: starter
+    /// below 10 kg, grower from 10 to 50 kg and finisher from 50 kg. Where phases change with
+    /// weight differs between farms for other species, so their phase must be given.
+    ///
+    /// # Returns
+    /// The phase, or `None` if there are no published weight classes for the type of livestock.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::types::{FeedingPhase, LivestockType};
+    /// use livestock_rs::units::Mass;
+    ///
+    /// let phase = FeedingPhase::for_weight(LivestockType::Swine, Mass::kilograms(30.0));
+    /// assert_eq!(phase, Some(FeedingPhase::Grower));
+    /// ```
+    pub fn for_weight(livestock_type: LivestockType, weight: Mass) -> Option<FeedingPhase> {
+        if livestock_type != LivestockType::Swine {
+            return None;
+        }
+
+        let weight = weight.as_kilograms();
+        Some(if weight < 10.0 {
+            FeedingPhase::Starter
+        } else if weight < 50.0 {
+            FeedingPhase::Grower
+        } else {
+            FeedingPhase::Finisher
+        })
+    }
+
+    /// The feeding phase of an animal of a given age, for the species fed by age.
+    ///
+    /// Chicken phases follow the Ross broiler feeding program (Aviagen, 2022): starter to 10
+    /// days, grower from 11 to 24 days and finisher from 25 days.
+    ///
+    /// # Returns
+    /// The phase, or `None` if the type of livestock is not fed by age.
+    ///
+    /// # Examples
+    /// ``` rust
+    /// use livestock_rs::types::{FeedingPhase, LivestockType};
+    /// use livestock_rs::units::Duration;
+    ///
+    /// let phase = FeedingPhase::for_age(LivestockType::Chicken, Duration::days(18.0));
+    /// assert_eq!(phase, Some(FeedingPhase::Grower));
+    /// ```
+    pub fn for_age(livestock_type: LivestockType, age: Duration) -> Option<FeedingPhase> {
+        if livestock_type != LivestockType::Chicken {
+            return None;
+        }
+
+        let age = age.as_days();
+        Some(if age < 11.0 {
+            FeedingPhase::Starter
+        } else if age < 25.0 {
+            FeedingPhase::Grower
+        } else {
+            FeedingPhase::Finisher
+        })
+    }
+}
+
+/// A calendar date in the Gregorian calendar, such as the day an animal was weighed.
+///
+/// Dates are written in ISO 8601 format, e.g. `2026-03-01`. Subtracting two dates gives the
+/// `Duration` between them, accounting for month lengths and leap years.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::types::Date;
+/// use std::str::FromStr;
+///
+/// let start = Date::from_str("2026-03-01").unwrap();
+/// let end = Date::new(2026, 6, 15).unwrap();
+/// assert_eq!((end - start).as_days(), 106.0);
+/// assert_eq!(end.to_string(), "2026-06-15");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a date, checking that the day exists in the given month and year.
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Self> {
+        ensure!((1..=12).contains(&month), "Month must be between 1 and 12.");
+        ensure!(
+            day >= 1 && day <= days_in_month(year, month),
+            "Day {} does not exist in {}-{:02}.",
+            day,
+            year,
+            month
+        );
+
+        Ok(Date { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// The number of days since 1970-01-01, negative for earlier dates.
+    fn days_since_epoch(&self) -> i64 {
+        // Shift the year to start in March so the leap day falls at the end of it.
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The date a number of days after 1970-01-01, the inverse of `days_since_epoch`.
+    fn from_days_since_epoch(days: i64) -> Date {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Date {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Subtracting two dates gives the duration between them, negative if `other` is later.
+impl Sub for Date {
+    type Output = Duration;
+    fn sub(self, other: Date) -> Duration {
+        Duration::days((self.days_since_epoch() - other.days_since_epoch()) as f64)
+    }
+}
+
+/// Adding a duration to a date gives the date that many days later, rounded to the nearest day.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::types::Date;
+/// use livestock_rs::units::Duration;
+///
+/// let date = Date::new(2026, 12, 20).unwrap() + Duration::days(75.0);
+/// assert_eq!(date.to_string(), "2027-03-05");
+/// ```
+impl Add<Duration> for Date {
+    type Output = Date;
+    fn add(self, duration: Duration) -> Date {
+        Date::from_days_since_epoch(self.days_since_epoch() + duration.as_days().round() as i64)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Converts an ISO 8601 date, e.g. `2026-03-01`, to a Date.
+impl FromStr for Date {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            anyhow!(
+                "Invalid date: \"{}\". Dates must be written as YYYY-MM-DD.",
+                s
+            )
+        };
+
+        let mut parts = s.trim().splitn(3, '-');
+        let mut next = || -> Result<&str> { parts.next().ok_or_else(invalid) };
+        let year = next()?.parse().map_err(|_| invalid())?;
+        let month = next()?.parse().map_err(|_| invalid())?;
+        let day = next()?.parse().map_err(|_| invalid())?;
+
+        Date::new(year, month, day)
+    }
+}
+
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Date::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_from_str() {
+        let cases = [
+            ("2026-03-01", (2026, 3, 1)),
+            ("2024-02-29", (2024, 2, 29)),
+            ("1999-12-31", (1999, 12, 31)),
+            (" 2026-6-5 ", (2026, 6, 5)),
+        ];
+
+        for (input, (year, month, day)) in cases.iter() {
+            let date = Date::from_str(input).unwrap();
+            assert_eq!(
+                (date.year(), date.month(), date.day()),
+                (*year, *month, *day)
+            );
+        }
+    }
+
+    #[test]
+    fn test_date_from_str_invalid() {
+        let invalid_cases = [
+            "",
+            "2026-03",
+            "2026/03/01",
+            "2026-13-01",
+            "2026-00-10",
+            "2026-04-31",
+            "2025-02-29",
+            "1900-02-29",
+            "2026-03-01-02",
+        ];
+
+        for input in invalid_cases.iter() {
+            assert!(
+                Date::from_str(input).is_err(),
+                "{} should be invalid",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_date_sub() {
+        let cases = [
+            ("2026-03-01", "2026-06-15", 106.0),
+            ("2024-02-28", "2024-03-01", 2.0),
+            ("2025-02-28", "2025-03-01", 1.0),
+            ("2025-12-31", "2026-01-01", 1.0),
+            ("2000-01-01", "2001-01-01", 366.0),
+            ("1970-01-01", "1970-01-01", 0.0),
+            ("2026-06-15", "2026-03-01", -106.0),
+        ];
+
+        for (start, end, expected) in cases.iter() {
+            let start = Date::from_str(start).unwrap();
+            let end = Date::from_str(end).unwrap();
+            assert_eq!((end - start).as_days(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_date_add() {
+        let cases = [
+            ("2026-03-01", 106.0, "2026-06-15"),
+            ("2024-02-28", 1.0, "2024-02-29"),
+            ("2023-02-28", 1.0, "2023-03-01"),
+            ("2026-12-31", 1.0, "2027-01-01"),
+            ("2026-01-10", -10.0, "2025-12-31"),
+            ("2026-01-01", 0.4, "2026-01-01"),
+            ("1969-12-31", 366.0, "1971-01-01"),
+        ];
+
+        for (date, days, expected) in cases.iter() {
+            let date = Date::from_str(date).unwrap() + Duration::days(*days);
+            assert_eq!(date.to_string(), *expected);
+        }
+
+        // Round trip every day across several leap cycles.
+        let start = Date::new(1896, 1, 1).unwrap();
+        for days in 0..(365 * 210) {
+            let date = start + Duration::days(days as f64);
+            assert_eq!((date - start).as_days(), days as f64);
+            assert!(Date::new(date.year(), date.month(), date.day()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_date_days_since_epoch() {
+        assert_eq!(Date::new(1970, 1, 1).unwrap().days_since_epoch(), 0);
+        assert_eq!(Date::new(1969, 12, 31).unwrap().days_since_epoch(), -1);
+        assert_eq!(Date::new(2000, 3, 1).unwrap().days_since_epoch(), 11_017);
+    }
+
+    #[test]
+    fn test_date_ordering_and_serde() {
+        let earlier = Date::new(2026, 3, 1).unwrap();
+        let later = Date::new(2026, 12, 1).unwrap();
+        assert!(earlier < later);
+
+        let json = serde_json::to_string(&earlier).unwrap();
+        assert_eq!(json, "\"2026-03-01\"");
+        assert_eq!(serde_json::from_str::<Date>(&json).unwrap(), earlier);
+    }
+
+    #[test]
+    fn test_feeding_phase_for_weight() {
+        let cases = [
+            (LivestockType::Swine, 8.0, Some(FeedingPhase::Starter)),
+            (LivestockType::Swine, 10.0, Some(FeedingPhase::Grower)),
+            (LivestockType::Swine, 30.0, Some(FeedingPhase::Grower)),
+            (LivestockType::Swine, 100.0, Some(FeedingPhase::Finisher)),
+            (LivestockType::Cattle, 300.0, None),
+            (LivestockType::Horse, 300.0, None),
+        ];
+
+        for (livestock_type, weight, expected) in cases {
+            assert_eq!(
+                FeedingPhase::for_weight(livestock_type, Mass::kilograms(weight)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_feeding_phase_for_age() {
+        let cases = [
+            (LivestockType::Chicken, 7.0, Some(FeedingPhase::Starter)),
+            (LivestockType::Chicken, 11.0, Some(FeedingPhase::Grower)),
+            (LivestockType::Chicken, 24.0, Some(FeedingPhase::Grower)),
+            (LivestockType::Chicken, 35.0, Some(FeedingPhase::Finisher)),
+            (LivestockType::Swine, 60.0, None),
+        ];
+
+        for (livestock_type, age, expected) in cases {
+            assert_eq!(
+                FeedingPhase::for_age(livestock_type, Duration::days(age)),
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,464 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Kilograms in a pound, by international agreement.
+const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+
+/// Pounds in a (US short) hundredweight.
+const POUNDS_PER_HUNDREDWEIGHT: f64 = 100.0;
+
+/// Centimeters in an inch, by international agreement.
+const CENTIMETERS_PER_INCH: f64 = 2.54;
+
+/// A unit of mass.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum MassUnit {
+    Kilogram,
+    Pound,
+    /// The US short hundredweight of 100 lb, commonly used to price cattle.
+    Hundredweight,
+}
+
+impl MassUnit {
+    /// The abbreviation of the unit, e.g. `kg`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            MassUnit::Kilogram => "kg",
+            MassUnit::Pound => "lb",
+            MassUnit::Hundredweight => "cwt",
+        }
+    }
+
+    fn kilograms(&self) -> f64 {
+        match self {
+            MassUnit::Kilogram => 1.0,
+            MassUnit::Pound => KILOGRAMS_PER_POUND,
+            MassUnit::Hundredweight => KILOGRAMS_PER_POUND * POUNDS_PER_HUNDREDWEIGHT,
+        }
+    }
+}
+
+/// A unit of length.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum LengthUnit {
+    Centimeter,
+    Inch,
+}
+
+impl LengthUnit {
+    /// The abbreviation of the unit, e.g. `cm`.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            LengthUnit::Centimeter => "cm",
+            LengthUnit::Inch => "in",
+        }
+    }
+
+    fn centimeters(&self) -> f64 {
+        match self {
+            LengthUnit::Centimeter => 1.0,
+            LengthUnit::Inch => CENTIMETERS_PER_INCH,
+        }
+    }
+}
+
+/// The system of units used to read input and label output.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize, ValueEnum)]
+pub enum UnitSystem {
+    /// Kilograms and centimeters.
+    #[default]
+    Metric,
+    /// Pounds and inches.
+    Imperial,
+}
+
+impl UnitSystem {
+    /// The unit animal and feed weights are given in.
+    pub fn mass_unit(&self) -> MassUnit {
+        match self {
+            UnitSystem::Metric => MassUnit::Kilogram,
+            UnitSystem::Imperial => MassUnit::Pound,
+        }
+    }
+
+    /// Creates a mass from a value in this system's mass unit.
+    pub fn mass(&self, value: f64) -> Mass {
+        Mass::new(value, self.mass_unit())
+    }
+
+    /// The unit body measurements are given in.
+    pub fn length_unit(&self) -> LengthUnit {
+        match self {
+            UnitSystem::Metric => LengthUnit::Centimeter,
+            UnitSystem::Imperial => LengthUnit::Inch,
+        }
+    }
+
+    /// Creates a length from a value in this system's length unit.
+    pub fn length(&self, value: f64) -> Length {
+        Length::new(value, self.length_unit())
+    }
+}
+
+/// A mass, such as the weight of an animal or an amount of feed.
+///
+/// Masses are stored in kilograms, and can be created from and converted to any `MassUnit`, so
+/// values given in different units can be combined safely.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::units::{Mass, MassUnit};
+///
+/// let weight = Mass::pounds(550.0) + Mass::kilograms(10.0);
+/// assert_eq!(format!("{:.1}", weight.as_kilograms()), "259.5");
+/// assert_eq!(format!("{:.2}", Mass::pounds(550.0).as_hundredweight()), "5.50");
+/// assert_eq!(Mass::new(2.0, MassUnit::Hundredweight), Mass::pounds(200.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
+pub struct Mass {
+    kilograms: f64,
+}
+
+impl Mass {
+    /// Creates a mass from a value in the given unit.
+    pub fn new(value: f64, unit: MassUnit) -> Self {
+        Mass {
+            kilograms: value * unit.kilograms(),
+        }
+    }
+
+    pub fn kilograms(kilograms: f64) -> Self {
+        Mass::new(kilograms, MassUnit::Kilogram)
+    }
+
+    pub fn pounds(pounds: f64) -> Self {
+        Mass::new(pounds, MassUnit::Pound)
+    }
+
+    pub fn hundredweight(hundredweight: f64) -> Self {
+        Mass::new(hundredweight, MassUnit::Hundredweight)
+    }
+
+    /// The value of the mass in the given unit.
+    pub fn in_unit(&self, unit: MassUnit) -> f64 {
+        self.kilograms / unit.kilograms()
+    }
+
+    pub fn as_kilograms(&self) -> f64 {
+        self.kilograms
+    }
+
+    pub fn as_pounds(&self) -> f64 {
+        self.in_unit(MassUnit::Pound)
+    }
+
+    pub fn as_hundredweight(&self) -> f64 {
+        self.in_unit(MassUnit::Hundredweight)
+    }
+}
+
+impl fmt::Display for Mass {
+    /// Writes the mass in kilograms, honoring the requested precision.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} kg", precision, self.kilograms),
+            None => write!(f, "{} kg", self.kilograms),
+        }
+    }
+}
+
+impl Add for Mass {
+    type Output = Mass;
+    fn add(self, other: Mass) -> Mass {
+        Mass::kilograms(self.kilograms + other.kilograms)
+    }
+}
+
+impl Sub for Mass {
+    type Output = Mass;
+    fn sub(self, other: Mass) -> Mass {
+        Mass::kilograms(self.kilograms - other.kilograms)
+    }
+}
+
+impl Mul<f64> for Mass {
+    type Output = Mass;
+    fn mul(self, factor: f64) -> Mass {
+        Mass::kilograms(self.kilograms * factor)
+    }
+}
+
+impl Div<f64> for Mass {
+    type Output = Mass;
+    fn div(self, divisor: f64) -> Mass {
+        Mass::kilograms(self.kilograms / divisor)
+    }
+}
+
+/// Dividing two masses gives their unitless ratio.
+impl Div for Mass {
+    type Output = f64;
+    fn div(self, other: Mass) -> f64 {
+        self.kilograms / other.kilograms
+    }
+}
+
+/// Dividing a mass by a duration gives a rate, such as average daily gain.
+impl Div<Duration> for Mass {
+    type Output = MassRate;
+    fn div(self, duration: Duration) -> MassRate {
+        MassRate::kilograms_per_day(self.kilograms / duration.days)
+    }
+}
+
+/// A length of time, such as a feeding or weighing period.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::units::Duration;
+///
+/// assert_eq!(Duration::weeks(2.0).as_days(), 14.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
+pub struct Duration {
+    days: f64,
+}
+
+impl Duration {
+    pub fn days(days: f64) -> Self {
+        Duration { days }
+    }
+
+    pub fn weeks(weeks: f64) -> Self {
+        Duration::days(weeks * 7.0)
+    }
+
+    pub fn as_days(&self) -> f64 {
+        self.days
+    }
+
+    pub fn as_weeks(&self) -> f64 {
+        self.days / 7.0
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} days", precision, self.days),
+            None => write!(f, "{} days", self.days),
+        }
+    }
+}
+
+/// A length, such as the heart girth or body length of an animal.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::units::{Length, LengthUnit};
+///
+/// assert_eq!(Length::inches(10.0).as_centimeters(), 25.4);
+/// assert_eq!(Length::new(127.0, LengthUnit::Centimeter).in_unit(LengthUnit::Inch), 50.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
+pub struct Length {
+    centimeters: f64,
+}
+
+impl Length {
+    /// Creates a length from a value in the given unit.
+    pub fn new(value: f64, unit: LengthUnit) -> Self {
+        Length {
+            centimeters: value * unit.centimeters(),
+        }
+    }
+
+    pub fn centimeters(centimeters: f64) -> Self {
+        Length::new(centimeters, LengthUnit::Centimeter)
+    }
+
+    pub fn inches(inches: f64) -> Self {
+        Length::new(inches, LengthUnit::Inch)
+    }
+
+    /// The value of the length in the given unit.
+    pub fn in_unit(&self, unit: LengthUnit) -> f64 {
+        self.centimeters / unit.centimeters()
+    }
+
+    pub fn as_centimeters(&self) -> f64 {
+        self.centimeters
+    }
+
+    pub fn as_inches(&self) -> f64 {
+        self.in_unit(LengthUnit::Inch)
+    }
+}
+
+impl fmt::Display for Length {
+    /// Writes the length in centimeters, honoring the requested precision.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} cm", precision, self.centimeters),
+            None => write!(f, "{} cm", self.centimeters),
+        }
+    }
+}
+
+/// A change in mass per day, such as average daily gain or daily feed intake.
+///
+/// # Examples
+/// ``` rust
+/// use livestock_rs::units::{Duration, Mass, MassUnit};
+///
+/// let adg = (Mass::pounds(650.0) - Mass::pounds(500.0)) / Duration::days(100.0);
+/// assert_eq!(format!("{:.2}", adg.in_unit(MassUnit::Pound)), "1.50");
+/// assert_eq!(format!("{:.0}", (adg * Duration::days(30.0)).as_pounds()), "45");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
+pub struct MassRate {
+    kilograms_per_day: f64,
+}
+
+impl MassRate {
+    /// Creates a rate from a value in the given unit per day.
+    pub fn per_day(value: f64, unit: MassUnit) -> Self {
+        MassRate {
+            kilograms_per_day: Mass::new(value, unit).as_kilograms(),
+        }
+    }
+
+    pub fn kilograms_per_day(kilograms_per_day: f64) -> Self {
+        MassRate::per_day(kilograms_per_day, MassUnit::Kilogram)
+    }
+
+    pub fn pounds_per_day(pounds_per_day: f64) -> Self {
+        MassRate::per_day(pounds_per_day, MassUnit::Pound)
+    }
+
+    /// The value of the rate in the given unit per day.
+    pub fn in_unit(&self, unit: MassUnit) -> f64 {
+        Mass::kilograms(self.kilograms_per_day).in_unit(unit)
+    }
+
+    pub fn as_kilograms_per_day(&self) -> f64 {
+        self.kilograms_per_day
+    }
+
+    pub fn as_pounds_per_day(&self) -> f64 {
+        self.in_unit(MassUnit::Pound)
+    }
+}
+
+impl fmt::Display for MassRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} kg/day", precision, self.kilograms_per_day),
+            None => write!(f, "{} kg/day", self.kilograms_per_day),
+        }
+    }
+}
+
+/// Multiplying a rate by a duration gives the total change in mass.
+impl Mul<Duration> for MassRate {
+    type Output = Mass;
+    fn mul(self, duration: Duration) -> Mass {
+        Mass::kilograms(self.kilograms_per_day * duration.days)
+    }
+}
+
+impl Mul<f64> for MassRate {
+    type Output = MassRate;
+    fn mul(self, factor: f64) -> MassRate {
+        MassRate::kilograms_per_day(self.kilograms_per_day * factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_mass_conversions() {
+        let cases = [
+            (
+                Mass::kilograms(100.0),
+                100.0,
+                220.462_262_184_877_6,
+                2.204_622_621_848_776,
+            ),
+            (Mass::pounds(100.0), 45.359_237, 100.0, 1.0),
+            (Mass::hundredweight(5.5), 249.475_803_5, 550.0, 5.5),
+        ];
+
+        for (mass, kilograms, pounds, hundredweight) in cases.iter() {
+            assert_close(mass.as_kilograms(), *kilograms);
+            assert_close(mass.as_pounds(), *pounds);
+            assert_close(mass.as_hundredweight(), *hundredweight);
+        }
+    }
+
+    #[test]
+    fn test_mass_arithmetic() {
+        let total = Mass::pounds(100.0) + Mass::kilograms(10.0);
+        assert_close(total.as_kilograms(), 55.359_237);
+        assert_close((total - Mass::kilograms(10.0)).as_pounds(), 100.0);
+        assert_close((Mass::kilograms(10.0) * 3.0).as_kilograms(), 30.0);
+        assert_close((Mass::kilograms(10.0) / 4.0).as_kilograms(), 2.5);
+        assert_close(Mass::pounds(300.0) / Mass::pounds(100.0), 3.0);
+        assert!(Mass::pounds(3.0) > Mass::kilograms(1.0));
+    }
+
+    #[test]
+    fn test_mass_rate() {
+        let adg = Mass::kilograms(50.0) / Duration::days(50.0);
+        assert_close(adg.as_kilograms_per_day(), 1.0);
+        assert_close(adg.as_pounds_per_day(), 2.204_622_621_848_776);
+        assert_close(MassRate::pounds_per_day(2.0).in_unit(MassUnit::Pound), 2.0);
+        assert_close((adg * Duration::weeks(1.0)).as_kilograms(), 7.0);
+    }
+
+    #[test]
+    fn test_length_conversions() {
+        assert_close(Length::inches(72.0).as_centimeters(), 182.88);
+        assert_close(
+            Length::centimeters(100.0).as_inches(),
+            39.370_078_740_157_48,
+        );
+        assert_close(
+            Length::new(3.0, LengthUnit::Inch).in_unit(LengthUnit::Centimeter),
+            7.62,
+        );
+    }
+
+    #[test]
+    fn test_unit_system() {
+        assert_eq!(UnitSystem::Metric.mass_unit(), MassUnit::Kilogram);
+        assert_eq!(UnitSystem::Imperial.mass(100.0), Mass::pounds(100.0));
+        assert_eq!(UnitSystem::Imperial.mass_unit().symbol(), "lb");
+        assert_eq!(UnitSystem::Metric.length(150.0), Length::centimeters(150.0));
+        assert_eq!(UnitSystem::Imperial.length_unit().symbol(), "in");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{:.1}", Mass::kilograms(12.345)), "12.3 kg");
+        assert_eq!(Duration::days(14.0).to_string(), "14 days");
+        assert_eq!(format!("{:.0}", Length::inches(10.0)), "25 cm");
+        assert_eq!(
+            format!("{:.2}", MassRate::kilograms_per_day(1.5)),
+            "1.50 kg/day"
+        );
+    }
+}